@@ -0,0 +1,74 @@
+use chinese_format::{bench_internals::*, currency::*, *};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn build_currency() -> RenminbiCurrency {
+    RenminbiCurrencyBuilder::new()
+        .with_yuan(1_234_567)
+        .with_dimes(8)
+        .with_cents(9)
+        .with_style(CurrencyStyle::Financial)
+        .build()
+        .unwrap()
+}
+
+type Conversion = Box<dyn Fn() -> Chinese>;
+
+fn report_allocations() {
+    let currency = build_currency();
+
+    let cases: [(&str, Conversion); 4] = [
+        (
+            "integer",
+            Box::new(|| 1_234_567_890i64.to_chinese(Variant::Simplified)),
+        ),
+        (
+            "count",
+            Box::new(|| Count(2_000_000).to_chinese(Variant::Traditional)),
+        ),
+        (
+            "chinese_vec",
+            Box::new(|| chinese_vec!(Variant::Simplified, ["你好", 38, Count(2)]).collect()),
+        ),
+        (
+            "currency",
+            Box::new(move || currency.to_chinese(Variant::Simplified)),
+        ),
+    ];
+
+    for (name, conversion) in cases {
+        let (_, allocations) = count_allocations(conversion);
+        println!("Allocations per conversion - {}: {}", name, allocations);
+    }
+}
+
+fn integers(c: &mut Criterion) {
+    report_allocations();
+
+    c.bench_function("integer", |b| {
+        b.iter(|| black_box(1_234_567_890i64).to_chinese(Variant::Simplified))
+    });
+
+    c.bench_function("count", |b| {
+        b.iter(|| Count(black_box(2_000_000)).to_chinese(Variant::Traditional))
+    });
+}
+
+fn chinese_vec(c: &mut Criterion) {
+    c.bench_function("chinese_vec", |b| {
+        b.iter(|| chinese_vec!(Variant::Simplified, ["你好", black_box(38), Count(2)]).collect())
+    });
+}
+
+fn currency(c: &mut Criterion) {
+    let currency = build_currency();
+
+    c.bench_function("currency", |b| {
+        b.iter(|| black_box(&currency).to_chinese(Variant::Simplified))
+    });
+}
+
+criterion_group!(benches, integers, chinese_vec, currency);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+use std::{error::Error, fmt::Display};
+
+/// Error for when a [PostalCode](crate::address::PostalCode)
+/// is not made of exactly 6 digits.
+///
+/// ```
+/// use chinese_format::address::*;
+///
+/// assert_eq!(
+///     InvalidPostalCode("1000".to_string()).to_string(),
+///     "Invalid postal code: 1000"
+/// );
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidPostalCode(pub String);
+
+impl Display for InvalidPostalCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid postal code: {}", self.0)
+    }
+}
+
+impl Error for InvalidPostalCode {}
+
+/// Error for when a [Room](crate::address::Room) number
+/// is empty or contains non-digit characters.
+///
+/// ```
+/// use chinese_format::address::*;
+///
+/// assert_eq!(
+///     InvalidRoomNumber("30A".to_string()).to_string(),
+///     "Invalid room number: 30A"
+/// );
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidRoomNumber(pub String);
+
+impl Display for InvalidRoomNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid room number: {}", self.0)
+    }
+}
+
+impl Error for InvalidRoomNumber {}
+
+/// Error for when a [PhoneNumber](crate::address::PhoneNumber) - or its extension -
+/// is empty or contains non-digit characters.
+///
+/// ```
+/// use chinese_format::address::*;
+///
+/// assert_eq!(
+///     InvalidPhoneNumber("123-A".to_string()).to_string(),
+///     "Invalid phone number: 123-A"
+/// );
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidPhoneNumber(pub String);
+
+impl Display for InvalidPhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid phone number: {}", self.0)
+    }
+}
+
+impl Error for InvalidPhoneNumber {}
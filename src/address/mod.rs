@@ -0,0 +1,324 @@
+//! Address and contact expressions - postal codes, floors, rooms and phone numbers.
+//!
+//! ```
+//! use chinese_format::{*, address::*};
+//!
+//! # fn main() -> GenericResult<()> {
+//! let postal_code = PostalCode::try_new("100080")?;
+//! let floor = Floor(3);
+//! let room = Room::try_new("305")?;
+//!
+//! let address = chinese_vec!(
+//!     Variant::Simplified,
+//!     [("邮编", "郵編"), postal_code, floor, room]
+//! ).collect();
+//!
+//! assert_eq!(address, Chinese {
+//!     logograms: "邮编一零零零八零三楼三零五室".into(),
+//!     omissible: false
+//! });
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Floors are plain [Measure](crate::Measure)s - whose value can be negative
+//! for underground levels, such as `B1`:
+//!
+//! ```
+//! use chinese_format::{*, address::*};
+//!
+//! assert_eq!(Floor(3).to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "三楼".into(),
+//!     omissible: false
+//! });
+//! assert_eq!(Floor(3).to_chinese(Variant::Traditional), "三樓");
+//!
+//! assert_eq!(Floor(-1).to_chinese(Variant::Simplified), "负一楼");
+//! assert_eq!(Floor(-1).to_chinese(Variant::Traditional), "負一樓");
+//!
+//! assert_eq!(Floor(12).to_chinese(Variant::Simplified), "十二楼");
+//! ```
+//!
+//! **REQUIRED FEATURE**: `digit-sequence`.
+mod errors;
+
+use crate::{
+    define_measure, Chinese, ChineseFormat, DigitGrouping, DigitSequenceFormat, ReadingStyle,
+    Variant,
+};
+use digit_sequence::DigitSequence;
+
+pub use errors::*;
+
+define_measure!(pub, Floor, pub, i16, ("楼", "樓"));
+
+/// Chinese postal code (`邮编`) - always made of 6 digits,
+/// which are read one by one.
+///
+/// ```
+/// use chinese_format::{*, address::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let postal_code = PostalCode::try_new("200030")?;
+///
+/// assert_eq!(postal_code.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "二零零零三零".into(),
+///     omissible: false
+/// });
+/// assert_eq!(postal_code.to_chinese(Variant::Traditional), "二零零零三零");
+///
+/// assert_eq!(postal_code.to_string(), "200030");
+///
+/// assert_eq!(
+///     PostalCode::try_new("20003"),
+///     Err(InvalidPostalCode("20003".to_string()))
+/// );
+/// assert_eq!(
+///     PostalCode::try_new("2000300"),
+///     Err(InvalidPostalCode("2000300".to_string()))
+/// );
+/// assert_eq!(
+///     PostalCode::try_new("20003X"),
+///     Err(InvalidPostalCode("20003X".to_string()))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PostalCode(DigitSequence);
+
+impl PostalCode {
+    const LENGTH: usize = 6;
+
+    pub fn try_new(value: &str) -> Result<PostalCode, InvalidPostalCode> {
+        match value.parse::<DigitSequence>() {
+            Ok(digits) if digits.iter().count() == Self::LENGTH => Ok(Self(digits)),
+            _ => Err(InvalidPostalCode(value.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for PostalCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ChineseFormat for PostalCode {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.0.to_chinese(variant)
+    }
+}
+
+/// Room number, read digit by digit and followed by `室`.
+///
+/// Leading zeros are preserved - as in `0801`.
+///
+/// ```
+/// use chinese_format::{*, address::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let room = Room::try_new("1203")?;
+///
+/// assert_eq!(room.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一二零三室".into(),
+///     omissible: false
+/// });
+/// assert_eq!(room.to_chinese(Variant::Traditional), "一二零三室");
+///
+/// let leading_zero = Room::try_new("0801")?;
+/// assert_eq!(leading_zero.to_chinese(Variant::Simplified), "零八零一室");
+///
+/// assert_eq!(Room::try_new(""), Err(InvalidRoomNumber("".to_string())));
+/// assert_eq!(Room::try_new("B12"), Err(InvalidRoomNumber("B12".to_string())));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Room(DigitSequence);
+
+impl Room {
+    const UNIT: &'static str = "室";
+
+    pub fn try_new(value: &str) -> Result<Room, InvalidRoomNumber> {
+        match value.parse::<DigitSequence>() {
+            Ok(digits) if !digits.is_empty() => Ok(Self(digits)),
+            _ => Err(InvalidRoomNumber(value.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for Room {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ChineseFormat for Room {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!("{}{}", self.0.to_chinese(variant).logograms, Self::UNIT).into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Options for reading a [PhoneNumber].
+///
+/// By default, digits are read via [ReadingStyle::Yao] - so that `1` is read as `幺`,
+/// to avoid confusion with `七` - and no pauses are inserted.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhoneNumberStyle {
+    /// How the digits are read - such as [ReadingStyle::Military].
+    pub reading_style: ReadingStyle,
+
+    /// How the digits of the main number are grouped - marking the pauses.
+    pub grouping: DigitGrouping<'static>,
+
+    /// The character marking a pause between groups.
+    pub separator: char,
+}
+
+impl Default for PhoneNumberStyle {
+    fn default() -> Self {
+        Self {
+            reading_style: ReadingStyle::Yao,
+            grouping: DigitGrouping::Every(0),
+            separator: ' ',
+        }
+    }
+}
+
+/// Phone number, read digit by digit - with an optional extension introduced by `转`.
+///
+/// ```
+/// use chinese_format::{*, address::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let office = PhoneNumber::try_new("12345678", Some("808"))?;
+///
+/// assert_eq!(office.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "幺二三四五六七八转八零八".into(),
+///     omissible: false
+/// });
+/// assert_eq!(office.to_chinese(Variant::Traditional), "幺二三四五六七八轉八零八");
+/// assert_eq!(office.to_string(), "12345678-808");
+///
+/// let mobile = PhoneNumber::try_new("13812345678", None)?.with_style(PhoneNumberStyle {
+///     grouping: DigitGrouping::MOBILE_PHONE,
+///     ..PhoneNumberStyle::default()
+/// });
+/// assert_eq!(mobile.to_chinese(Variant::Simplified), "幺三八 幺二三四 五六七八");
+///
+/// let plain = PhoneNumber::try_new("110", None)?.with_style(PhoneNumberStyle {
+///     reading_style: ReadingStyle::Standard,
+///     ..PhoneNumberStyle::default()
+/// });
+/// assert_eq!(plain.to_chinese(Variant::Simplified), "一一零");
+///
+/// let radio = PhoneNumber::try_new("12790", None)?.with_style(PhoneNumberStyle {
+///     reading_style: ReadingStyle::Military,
+///     ..PhoneNumberStyle::default()
+/// });
+/// assert_eq!(radio.to_chinese(Variant::Simplified), "幺两拐勾洞");
+/// assert_eq!(radio.to_chinese(Variant::Traditional), "幺兩拐勾洞");
+///
+/// assert_eq!(
+///     PhoneNumber::try_new("", None),
+///     Err(InvalidPhoneNumber("".to_string()))
+/// );
+/// assert_eq!(
+///     PhoneNumber::try_new("1234-5678", None),
+///     Err(InvalidPhoneNumber("1234-5678".to_string()))
+/// );
+/// assert_eq!(
+///     PhoneNumber::try_new("12345678", Some("")),
+///     Err(InvalidPhoneNumber("".to_string()))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhoneNumber {
+    number: DigitSequence,
+    extension: Option<DigitSequence>,
+    style: PhoneNumberStyle,
+}
+
+impl PhoneNumber {
+    const ZHUAN: (&'static str, &'static str) = ("转", "轉");
+
+    fn parse_digits(value: &str) -> Result<DigitSequence, InvalidPhoneNumber> {
+        match value.parse::<DigitSequence>() {
+            Ok(digits) if !digits.is_empty() => Ok(digits),
+            _ => Err(InvalidPhoneNumber(value.to_string())),
+        }
+    }
+
+    pub fn try_new(
+        number: &str,
+        extension: Option<&str>,
+    ) -> Result<PhoneNumber, InvalidPhoneNumber> {
+        Ok(Self {
+            number: Self::parse_digits(number)?,
+            extension: extension.map(Self::parse_digits).transpose()?,
+            style: PhoneNumberStyle::default(),
+        })
+    }
+
+    /// Sets the reading options.
+    pub fn with_style(mut self, style: PhoneNumberStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn read(&self, sequence: &DigitSequence, grouping: DigitGrouping, variant: Variant) -> String {
+        DigitSequenceFormat {
+            sequence,
+            grouping,
+            separator: self.style.separator,
+            glyphs: self.style.reading_style.glyphs(),
+        }
+        .to_chinese(variant)
+        .logograms
+        .into_owned()
+    }
+}
+
+impl std::fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.extension {
+            Some(extension) => write!(f, "{}-{}", self.number, extension),
+            None => write!(f, "{}", self.number),
+        }
+    }
+}
+
+impl ChineseFormat for PhoneNumber {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let number = self.read(&self.number, self.style.grouping, variant);
+
+        let logograms = match &self.extension {
+            Some(extension) => format!(
+                "{}{}{}",
+                number,
+                Self::ZHUAN.to_chinese(variant),
+                self.read(extension, DigitGrouping::Every(0), variant)
+            ),
+            None => number,
+        };
+
+        Chinese {
+            logograms: logograms.into(),
+            omissible: false,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{Chinese, ChineseFormat, Typography, Variant};
+
+/// How the integer digits of an [ArabicNumber] are grouped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ArabicGrouping {
+    /// No separator - as in `1234567`.
+    #[default]
+    None,
+
+    /// A comma every 3 digits - as in `1,234,567`.
+    Thousands,
+}
+
+/// Chinese unit following an [ArabicNumber] - which is divided accordingly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ArabicUnit {
+    /// No unit - the value being written as it is.
+    #[default]
+    None,
+
+    /// `万`/`萬` - as in `1234.5万`.
+    Wan,
+
+    /// `亿`/`億` - as in `3.5亿`.
+    Yi,
+
+    /// The largest among [Yi](Self::Yi) and [Wan](Self::Wan) not exceeding the value -
+    /// or no unit, for values less than `10000`.
+    Auto,
+}
+
+/// Integer written in Arabic digits - as required by mixed output, next to
+/// Chinese renderings - with optional [ArabicGrouping] and [ArabicUnit].
+///
+/// When a unit is applied, the quotient is rounded half away from zero
+/// to at most [max_fraction_digits](Self::max_fraction_digits) digits,
+/// the trailing zeros being dropped.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let plain = ArabicNumber::new(1_234_567);
+/// assert_eq!(plain.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "1234567".into(),
+///     omissible: false
+/// });
+///
+/// let grouped = ArabicNumber {
+///     grouping: ArabicGrouping::Thousands,
+///     ..ArabicNumber::new(-1_234_567)
+/// };
+/// assert_eq!(grouped.to_chinese(Variant::Simplified), "-1,234,567");
+///
+/// let wan = ArabicNumber {
+///     unit: ArabicUnit::Wan,
+///     ..ArabicNumber::new(12_345_000)
+/// };
+/// assert_eq!(wan.to_chinese(Variant::Simplified), "1234.5万");
+/// assert_eq!(wan.to_chinese(Variant::Traditional), "1234.5萬");
+///
+/// let grouped_wan = ArabicNumber {
+///     grouping: ArabicGrouping::Thousands,
+///     ..wan
+/// };
+/// assert_eq!(grouped_wan.to_chinese(Variant::Simplified), "1,234.5万");
+///
+/// let yi = ArabicNumber {
+///     unit: ArabicUnit::Yi,
+///     max_fraction_digits: 2,
+///     ..ArabicNumber::new(356_789_000)
+/// };
+/// assert_eq!(yi.to_chinese(Variant::Traditional), "3.57億");
+///
+/// let round = ArabicNumber {
+///     unit: ArabicUnit::Wan,
+///     ..ArabicNumber::new(20_000)
+/// };
+/// assert_eq!(round.to_chinese(Variant::Simplified), "2万");
+///
+/// let full_width = ArabicNumber {
+///     grouping: ArabicGrouping::Thousands,
+///     typography: Typography::FULL_WIDTH,
+///     ..ArabicNumber::new(1_234)
+/// };
+/// assert_eq!(full_width.to_chinese(Variant::Simplified), "１，２３４");
+///
+/// assert_eq!(ArabicNumber::new(0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "0".into(),
+///     omissible: true
+/// });
+/// ```
+///
+/// With [ArabicUnit::Auto], the unit depends on the value - moving to the
+/// larger unit when rounding reaches it:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let auto = |value| ArabicNumber {
+///     unit: ArabicUnit::Auto,
+///     ..ArabicNumber::new(value)
+/// };
+///
+/// assert_eq!(auto(9_999).to_chinese(Variant::Simplified), "9999");
+/// assert_eq!(auto(12_345).to_chinese(Variant::Simplified), "1.2万");
+/// assert_eq!(auto(-150_000_000).to_chinese(Variant::Simplified), "-1.5亿");
+/// assert_eq!(auto(99_999_600).to_chinese(Variant::Simplified), "1亿");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArabicNumber {
+    pub value: i128,
+
+    pub grouping: ArabicGrouping,
+
+    pub unit: ArabicUnit,
+
+    /// The maximum number of fractional digits, when dividing by the unit.
+    pub max_fraction_digits: usize,
+
+    /// Applied to the sign, digits and separators.
+    pub typography: Typography,
+}
+
+impl ArabicNumber {
+    /// The default value for [max_fraction_digits](Self::max_fraction_digits).
+    pub const DEFAULT_MAX_FRACTION_DIGITS: usize = 1;
+
+    const WAN: (u32, (&'static str, &'static str)) = (4, ("万", "萬"));
+
+    const YI: (u32, (&'static str, &'static str)) = (8, ("亿", "億"));
+
+    /// Creates an [ArabicNumber] without grouping and unit - and with
+    /// [DEFAULT_MAX_FRACTION_DIGITS](Self::DEFAULT_MAX_FRACTION_DIGITS).
+    pub fn new(value: i128) -> Self {
+        Self {
+            value,
+            grouping: ArabicGrouping::default(),
+            unit: ArabicUnit::default(),
+            max_fraction_digits: Self::DEFAULT_MAX_FRACTION_DIGITS,
+            typography: Typography::default(),
+        }
+    }
+
+    /// Divides the magnitude by 10 to the given power, returning the rounded
+    /// integer part and the fractional digits - without trailing zeros.
+    fn scale(&self, power: u32) -> (u128, String) {
+        let magnitude = self.value.unsigned_abs();
+
+        let fraction_digits = (self.max_fraction_digits as u32).min(power);
+        let step = 10u128.pow(power - fraction_digits);
+        let rounded = (magnitude / step) + u128::from(magnitude % step >= step.div_ceil(2));
+
+        let fraction_divisor = 10u128.pow(fraction_digits);
+
+        let fractional = format!(
+            "{:0width$}",
+            rounded % fraction_divisor,
+            width = fraction_digits as usize
+        );
+
+        (
+            rounded / fraction_divisor,
+            fractional.trim_end_matches('0').to_string(),
+        )
+    }
+
+    fn group(&self, integer: u128) -> String {
+        let digits = integer.to_string();
+
+        match self.grouping {
+            ArabicGrouping::None => digits,
+
+            ArabicGrouping::Thousands => {
+                let mut grouped = String::new();
+
+                for (index, digit) in digits.chars().enumerate() {
+                    if index > 0 && (digits.len() - index) % 3 == 0 {
+                        grouped.push(',');
+                    }
+
+                    grouped.push(digit);
+                }
+
+                grouped
+            }
+        }
+    }
+
+    /// The power of ten and the logograms of the unit actually applied.
+    fn resolve_unit(&self) -> Option<(u32, (&'static str, &'static str))> {
+        match self.unit {
+            ArabicUnit::None => None,
+            ArabicUnit::Wan => Some(Self::WAN),
+            ArabicUnit::Yi => Some(Self::YI),
+            ArabicUnit::Auto => {
+                let magnitude = self.value.unsigned_abs();
+
+                if magnitude >= 10u128.pow(Self::YI.0) {
+                    Some(Self::YI)
+                } else if magnitude >= 10u128.pow(Self::WAN.0) {
+                    if self.scale(Self::WAN.0).0 >= 10u128.pow(Self::YI.0 - Self::WAN.0) {
+                        Some(Self::YI)
+                    } else {
+                        Some(Self::WAN)
+                    }
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl From<i128> for ArabicNumber {
+    fn from(value: i128) -> Self {
+        Self::new(value)
+    }
+}
+
+impl ChineseFormat for ArabicNumber {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let (power, unit) = match self.resolve_unit() {
+            Some((power, (simplified, traditional))) => (
+                power,
+                match variant {
+                    Variant::Simplified => simplified,
+                    Variant::Traditional => traditional,
+                },
+            ),
+
+            None => (0, ""),
+        };
+
+        let (integer, fractional) = self.scale(power);
+
+        let is_zero = integer == 0 && fractional.is_empty();
+
+        let sign = if self.value < 0 && !is_zero { "-" } else { "" };
+
+        let separator = if fractional.is_empty() { "" } else { "." };
+
+        Chinese {
+            logograms: format!(
+                "{}{}",
+                self.typography.apply(&format!(
+                    "{}{}{}{}",
+                    sign,
+                    self.group(integer),
+                    separator,
+                    fractional
+                )),
+                unit
+            )
+            .into(),
+            omissible: is_zero,
+        }
+    }
+}
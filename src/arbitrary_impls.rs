@@ -0,0 +1,240 @@
+use crate::Fraction;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+#[cfg(feature = "digit-sequence")]
+use crate::Decimal;
+
+#[cfg(feature = "currency")]
+use crate::currency::{CurrencyStyle, RenminbiCurrency, RenminbiCurrencyBuilder};
+
+#[cfg(feature = "gregorian")]
+use crate::{
+    gregorian::{Date, DateBuilder, DeltaTime, LinearTime, WeekDay, WeekFormat, YearStyle},
+    DigitGlyphSet,
+};
+
+/// Only fractions having a non-zero denominator are generated.
+impl<'a> Arbitrary<'a> for Fraction {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let denominator = u.int_in_range(1..=u128::MAX)?;
+
+        Ok(Fraction::try_new(denominator, u.arbitrary()?).expect("The denominator is not 0"))
+    }
+}
+
+#[cfg(feature = "digit-sequence")]
+impl<'a> Arbitrary<'a> for Decimal {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let integer = u.arbitrary()?;
+
+        let fractional_length = u.int_in_range(0..=20)?;
+
+        let fractional_digits = (0..fractional_length)
+            .map(|_| u.int_in_range(0..=9))
+            .collect::<Result<Vec<u8>>>()?;
+
+        Ok(Decimal {
+            integer,
+            fractional: fractional_digits
+                .try_into()
+                .expect("Only digits are generated"),
+        })
+    }
+}
+
+#[cfg(feature = "currency")]
+impl<'a> Arbitrary<'a> for RenminbiCurrency {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let style = *u.choose(&[
+            CurrencyStyle::Everyday { formal: true },
+            CurrencyStyle::Everyday { formal: false },
+            CurrencyStyle::Financial,
+        ])?;
+
+        Ok(RenminbiCurrencyBuilder::new()
+            .with_yuan(u.arbitrary()?)
+            .with_dimes(u.int_in_range(0..=9)?)
+            .with_cents(u.int_in_range(0..=9)?)
+            .with_style(style)
+            .with_currency_name(u.arbitrary()?)
+            .with_formal_yuan(u.arbitrary()?)
+            .build()
+            .expect("Only valid components are generated"))
+    }
+}
+
+#[cfg(feature = "gregorian")]
+const WEEK_DAYS: [WeekDay; 7] = [
+    WeekDay::Sunday,
+    WeekDay::Monday,
+    WeekDay::Tuesday,
+    WeekDay::Wednesday,
+    WeekDay::Thursday,
+    WeekDay::Friday,
+    WeekDay::Saturday,
+];
+
+/// Only existing dates, having one of the valid [DatePattern](crate::gregorian::DatePattern)s, are generated.
+#[cfg(feature = "gregorian")]
+impl<'a> Arbitrary<'a> for Date {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        //Year, month, day, week day - as in the valid date patterns
+        let (has_year, has_month, has_day, has_week_day) = *u.choose(&[
+            (true, false, false, false),
+            (false, true, false, false),
+            (false, false, true, false),
+            (false, false, false, true),
+            (true, true, false, false),
+            (true, true, true, false),
+            (false, true, true, false),
+            (false, true, true, true),
+            (false, false, true, true),
+            (true, true, true, true),
+        ])?;
+
+        let year: u16 = u.int_in_range(1..=9999)?;
+        let month: u8 = u.int_in_range(1..=12)?;
+
+        let is_leap_year = !has_year || year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+        let max_day = match (has_month, month) {
+            (false, _) => 31,
+            (true, 4 | 6 | 9 | 11) => 30,
+            (true, 2) if is_leap_year => 29,
+            (true, 2) => 28,
+            (true, _) => 31,
+        };
+
+        let mut builder = DateBuilder::new()
+            .with_formal(u.arbitrary()?)
+            .with_week_format(*u.choose(&[
+                WeekFormat::XingQi,
+                WeekFormat::Zhou,
+                WeekFormat::LiBai,
+            ])?)
+            .with_year_glyphs(*u.choose(&[DigitGlyphSet::STANDARD, DigitGlyphSet::CIRCLE_ZERO])?)
+            .with_year_style(*u.choose(&[YearStyle::Digits, YearStyle::Spoken])?);
+
+        if has_year {
+            builder = builder.with_year(year);
+        }
+
+        if has_month {
+            builder = builder.with_month(month);
+        }
+
+        if has_day {
+            builder = builder.with_day(u.int_in_range(1..=max_day)?);
+        }
+
+        if has_week_day {
+            builder = builder.with_week_day(*u.choose(&WEEK_DAYS)?);
+        }
+
+        Ok(builder.build().expect("Only valid dates are generated"))
+    }
+}
+
+#[cfg(feature = "gregorian")]
+impl<'a> Arbitrary<'a> for LinearTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let second: Option<u8> = if u.arbitrary()? {
+            Some(u.int_in_range(0..=59)?)
+        } else {
+            None
+        };
+
+        Ok(LinearTime {
+            day_part: u.arbitrary()?,
+            hour: u.int_in_range(0..=23u8)?.try_into().expect("Valid hour"),
+            minute: u.int_in_range(0..=59u8)?.try_into().expect("Valid minute"),
+            second: second.map(|second| second.try_into().expect("Valid second")),
+        })
+    }
+}
+
+#[cfg(feature = "gregorian")]
+impl<'a> Arbitrary<'a> for DeltaTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let second: Option<u8> = if u.arbitrary()? {
+            Some(u.int_in_range(0..=59)?)
+        } else {
+            None
+        };
+
+        Ok(DeltaTime {
+            hour: u.int_in_range(1..=12u8)?.try_into().expect("Valid hour"),
+            minute: u.int_in_range(0..=59u8)?.try_into().expect("Valid minute"),
+            second: second.map(|second| second.try_into().expect("Valid second")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use speculate2::*;
+
+    /// Deterministic pseudo-random bytes, to keep the tests reproducible.
+    fn bytes(seed: u64) -> Vec<u8> {
+        let mut state = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+
+        (0..256)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    fn check_renders<T: for<'a> Arbitrary<'a> + ChineseFormat>() {
+        for seed in 0..500 {
+            let bytes = bytes(seed);
+            let mut u = Unstructured::new(&bytes);
+
+            let value: T = u.arbitrary().unwrap();
+
+            for variant in [Variant::Simplified, Variant::Traditional] {
+                assert!(!value.to_chinese(variant).logograms.is_empty());
+            }
+        }
+    }
+
+    speculate! {
+        describe "arbitrary values" {
+            it "should render fractions" {
+                check_renders::<Fraction>();
+            }
+
+            #[cfg(feature = "digit-sequence")]
+            it "should render decimals" {
+                check_renders::<Decimal>();
+            }
+
+            #[cfg(feature = "currency")]
+            it "should render renminbi amounts" {
+                check_renders::<RenminbiCurrency>();
+            }
+
+            #[cfg(feature = "gregorian")]
+            it "should render dates" {
+                check_renders::<gregorian::Date>();
+            }
+
+            #[cfg(feature = "gregorian")]
+            it "should render linear times" {
+                check_renders::<gregorian::LinearTime>();
+            }
+
+            #[cfg(feature = "gregorian")]
+            it "should render delta times" {
+                check_renders::<gregorian::DeltaTime>();
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Performance counters, to guide and verify allocation-reduction work.
+//!
+//! [CountingAllocator] wraps the [System] allocator, counting the allocations
+//! performed by the current thread; once it is installed as the global allocator,
+//! [count_allocations] returns the number of allocations required by a closure:
+//!
+//! ```
+//! use chinese_format::{*, bench_internals::*};
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//!
+//! let (chinese, allocations) = count_allocations(|| 90.to_chinese(Variant::Simplified));
+//! assert_eq!(chinese, "九十");
+//! assert!(allocations > 0);
+//!
+//! let (_, no_allocations) = count_allocations(|| 90 + 1);
+//! assert_eq!(no_allocations, 0);
+//! ```
+//!
+//! Without [CountingAllocator] as the global allocator, the count is always 0.
+//!
+//! **REQUIRED FEATURE**: `bench-internals`.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// [GlobalAlloc] delegating to [System], while counting the allocations of each thread.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn increment() {
+        //During thread teardown, the counter might be unavailable
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::increment();
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::increment();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::increment();
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// The number of allocations - including reallocations - performed so far by the current thread.
+pub fn allocation_count() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+/// Runs the given closure, returning its result and the number of allocations
+/// it performed on the current thread.
+pub fn count_allocations<R>(action: impl FnOnce() -> R) -> (R, usize) {
+    let before = allocation_count();
+
+    let result = action();
+
+    (result, allocation_count() - before)
+}
@@ -0,0 +1,132 @@
+//! Colloquial formats for people's height and weight.
+//!
+//! ```
+//! use chinese_format::{*, body::*};
+//!
+//! assert_eq!(Height(178).to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "一米七八".into(),
+//!     omissible: false
+//! });
+//!
+//! let weight = BodyWeight {
+//!     kilograms: 70,
+//!     unit: BodyWeightUnit::Jin
+//! };
+//! assert_eq!(weight.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "一百四十斤".into(),
+//!     omissible: false
+//! });
+//! ```
+use crate::{
+    length::Centimeter,
+    weight::{HalfKilogram, Kilogram},
+    Chinese, ChineseFormat, MultiUnitMeasure, MultiUnitStyle, TrailingUnit, Variant, ZeroPolicy,
+};
+
+/// Height of a person, in centimeters.
+///
+/// From 1 meter on, the centimeters are read as the digits of a fractional part,
+/// without unit - as in `一米七八`; below 1 meter, plain centimeters are used.
+///
+/// ```
+/// use chinese_format::{*, body::*};
+///
+/// assert_eq!(Height(178).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一米七八".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Height(178).to_chinese(Variant::Traditional), "一米七八");
+///
+/// assert_eq!(Height(160).to_chinese(Variant::Simplified), "一米六");
+/// assert_eq!(Height(105).to_chinese(Variant::Simplified), "一米零五");
+/// assert_eq!(Height(200).to_chinese(Variant::Simplified), "两米");
+/// assert_eq!(Height(202).to_chinese(Variant::Traditional), "兩米零二");
+///
+/// assert_eq!(Height(95).to_chinese(Variant::Simplified), "九十五厘米");
+/// assert_eq!(Height(95).to_chinese(Variant::Traditional), "九十五釐米");
+///
+/// assert_eq!(Height(0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零厘米".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Height(pub u16);
+
+impl From<Height> for u16 {
+    fn from(source: Height) -> Self {
+        source.0
+    }
+}
+
+impl ChineseFormat for Height {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.0 < 100 {
+            return Centimeter::new(self.0 as u128).to_chinese(variant);
+        }
+
+        MultiUnitMeasure {
+            value: self.0 as u128,
+            units: MultiUnitMeasure::METER_CENTIMETER,
+            style: MultiUnitStyle {
+                zero_policy: ZeroPolicy::Omit,
+                trailing_unit: TrailingUnit::Digits,
+            },
+        }
+        .to_chinese(variant)
+    }
+}
+
+/// The unit used to express a [BodyWeight].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BodyWeightUnit {
+    /// `公斤` - as in medical contexts.
+    Kilogram,
+
+    /// `斤` - as usual in everyday speech, in mainland China.
+    Jin,
+}
+
+/// Weight of a person, expressed in kilograms but rendered in the requested [BodyWeightUnit].
+///
+/// ```
+/// use chinese_format::{*, body::*};
+///
+/// let kilograms = BodyWeight {
+///     kilograms: 70,
+///     unit: BodyWeightUnit::Kilogram
+/// };
+/// assert_eq!(kilograms.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "七十公斤".into(),
+///     omissible: false
+/// });
+///
+/// let jin = BodyWeight {
+///     kilograms: 70,
+///     unit: BodyWeightUnit::Jin
+/// };
+/// assert_eq!(jin.to_chinese(Variant::Simplified), "一百四十斤");
+/// assert_eq!(jin.to_chinese(Variant::Traditional), "一百四十斤");
+///
+/// let newborn = BodyWeight {
+///     kilograms: 1,
+///     unit: BodyWeightUnit::Jin
+/// };
+/// assert_eq!(newborn.to_chinese(Variant::Simplified), "两斤");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BodyWeight {
+    pub kilograms: u16,
+    pub unit: BodyWeightUnit,
+}
+
+impl ChineseFormat for BodyWeight {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let kilograms = self.kilograms as u128;
+
+        match self.unit {
+            BodyWeightUnit::Kilogram => Kilogram::new(kilograms).to_chinese(variant),
+            BodyWeightUnit::Jin => HalfKilogram::new(kilograms * 2).to_chinese(variant),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::{error::Error, fmt::Display};
+
+/// Error for when a [Cheng](crate::Cheng) value is greater than 10.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     ChengOutOfRange(11).to_string(),
+///     "Cheng out of range: 11"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChengOutOfRange(pub u8);
+
+impl Display for ChengOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cheng out of range: {}", self.0)
+    }
+}
+
+impl Error for ChengOutOfRange {}
+
+/// Error for when the bounds of a [Cheng](crate::Cheng) range are invalid -
+/// that is, when either bound is greater than 10, or when the lower bound
+/// is not less than the upper bound.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     InvalidChengRange {
+///         low: 8,
+///         high: 7
+///     }.to_string(),
+///     "Invalid cheng range: 8 to 7"
+/// );
+///
+/// assert_eq!(
+///     InvalidChengRange {
+///         low: 9,
+///         high: 11
+///     }.to_string(),
+///     "Invalid cheng range: 9 to 11"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidChengRange {
+    pub low: u8,
+    pub high: u8,
+}
+
+impl Display for InvalidChengRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid cheng range: {} to {}", self.low, self.high)
+    }
+}
+
+impl Error for InvalidChengRange {}
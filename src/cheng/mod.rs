@@ -0,0 +1,154 @@
+mod errors;
+
+use crate::{chinese_vec, Chinese, ChineseFormat, Count, Percent, Variant};
+
+pub use errors::*;
+
+/// Colloquial expression of tenths - `成` - as in `三成` (30%)
+/// or `七八成` (70-80%).
+///
+/// It can describe either a single value, via [try_new](Self::try_new),
+/// or a range, via [try_new_range](Self::try_new_range); values go from 0 to 10,
+/// where `十成` means _completely_.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let three = Cheng::try_new(3)?;
+/// assert_eq!(three.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三成".into(),
+///     omissible: false
+/// });
+/// assert_eq!(three.low(), 3);
+/// assert_eq!(three.high(), 3);
+/// assert_eq!(three.low_percent(), Percent(30));
+/// assert_eq!(three.high_percent(), Percent(30));
+///
+/// let two = Cheng::try_new(2)?;
+/// assert_eq!(two.to_chinese(Variant::Simplified), "两成");
+/// assert_eq!(two.to_chinese(Variant::Traditional), "兩成");
+///
+/// let zero = Cheng::try_new(0)?;
+/// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零成".into(),
+///     omissible: true
+/// });
+///
+/// assert_eq!(Cheng::try_new(11), Err(ChengOutOfRange(11)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cheng {
+    low: u8,
+    high: u8,
+}
+
+impl Cheng {
+    const MAX: u8 = 10;
+
+    const UNIT: &'static str = "成";
+
+    const CONNECTOR: &'static str = "到";
+
+    pub fn try_new(value: u8) -> Result<Cheng, ChengOutOfRange> {
+        if value > Self::MAX {
+            return Err(ChengOutOfRange(value));
+        }
+
+        Ok(Self {
+            low: value,
+            high: value,
+        })
+    }
+
+    /// Creates a range of tenths.
+    ///
+    /// Adjacent values are simply juxtaposed - just like in spoken Chinese -
+    /// whereas farther values, as well as any range ending with `十`,
+    /// are joined by `到`; the construction fails with
+    /// [InvalidChengRange] when either bound is greater than 10, or when
+    /// the lower bound is not less than the upper bound:
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let seven_eight = Cheng::try_new_range(7, 8)?;
+    /// assert_eq!(seven_eight.to_chinese(Variant::Simplified), Chinese {
+    ///     logograms: "七八成".into(),
+    ///     omissible: false
+    /// });
+    /// assert_eq!(seven_eight.low_percent(), Percent(70));
+    /// assert_eq!(seven_eight.high_percent(), Percent(80));
+    ///
+    /// let two_three = Cheng::try_new_range(2, 3)?;
+    /// assert_eq!(two_three.to_chinese(Variant::Simplified), "两三成");
+    /// assert_eq!(two_three.to_chinese(Variant::Traditional), "兩三成");
+    ///
+    /// let three_to_five = Cheng::try_new_range(3, 5)?;
+    /// assert_eq!(three_to_five.to_chinese(Variant::Simplified), "三到五成");
+    ///
+    /// let nine_ten = Cheng::try_new_range(9, 10)?;
+    /// assert_eq!(nine_ten.to_chinese(Variant::Simplified), "九到十成");
+    /// assert_eq!(nine_ten.high_percent(), Percent(100));
+    ///
+    /// assert_eq!(Cheng::try_new_range(8, 7), Err(InvalidChengRange { low: 8, high: 7 }));
+    /// assert_eq!(Cheng::try_new_range(5, 5), Err(InvalidChengRange { low: 5, high: 5 }));
+    /// assert_eq!(Cheng::try_new_range(9, 11), Err(InvalidChengRange { low: 9, high: 11 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_new_range(low: u8, high: u8) -> Result<Cheng, InvalidChengRange> {
+        if low >= high || high > Self::MAX {
+            return Err(InvalidChengRange { low, high });
+        }
+
+        Ok(Self { low, high })
+    }
+
+    /// The lower bound, in tenths - equal to the [high](Self::high) bound
+    /// for a single value.
+    pub fn low(&self) -> u8 {
+        self.low
+    }
+
+    /// The upper bound, in tenths - equal to the [low](Self::low) bound
+    /// for a single value.
+    pub fn high(&self) -> u8 {
+        self.high
+    }
+
+    /// The lower bound, as a [Percent].
+    pub fn low_percent(&self) -> Percent {
+        Percent(self.low as i128 * 10)
+    }
+
+    /// The upper bound, as a [Percent].
+    pub fn high_percent(&self) -> Percent {
+        Percent(self.high as i128 * 10)
+    }
+}
+
+impl ChineseFormat for Cheng {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let low = Count(self.low as u128);
+        let high = Count(self.high as u128);
+
+        let logograms = if self.low == self.high {
+            chinese_vec!(variant, [low, Self::UNIT])
+        } else if self.high - self.low == 1 && self.high < Self::MAX {
+            chinese_vec!(variant, [low, high, Self::UNIT])
+        } else {
+            chinese_vec!(variant, [low, Self::CONNECTOR, high, Self::UNIT])
+        }
+        .collect()
+        .logograms;
+
+        Chinese {
+            logograms,
+            omissible: self.high == 0,
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use std::fmt::Display;
+use crate::{ChineseParts, CompositionStyle, DigitGlyphSet};
+use std::{borrow::Cow, fmt, fmt::Display, io};
 
 /// The two major Chinese variants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8,10 +9,28 @@ pub enum Variant {
 }
 
 /// Chinese expression.
+///
+/// Its logograms are a [Cow], so that constant expressions - such as units
+/// and connectors - can be borrowed instead of allocated:
+///
+/// ```
+/// use chinese_format::*;
+/// use std::borrow::Cow;
+///
+/// let unit = Chinese::from_static("元");
+/// assert!(matches!(unit.logograms, Cow::Borrowed("元")));
+/// assert_eq!(unit.omissible, false);
+///
+/// let owned = Chinese::new(format!("{}{}", "三", "元"), false);
+/// assert_eq!(owned, "三元");
+///
+/// let text: String = owned.into();
+/// assert_eq!(text, "三元");
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Chinese {
-    /// Contains the sinograms, owned as a String.
-    pub logograms: String,
+    /// Contains the sinograms - either borrowed, when constant, or owned.
+    pub logograms: Cow<'static, str>,
 
     /// Declares that a field can be omitted in a variety of contexts -
     /// for example, in [placeholders](crate::define_string_placeholder) or
@@ -19,13 +38,44 @@ pub struct Chinese {
     pub omissible: bool,
 }
 
+impl Chinese {
+    /// Creates a [Chinese] expression from any string type.
+    pub fn new(logograms: impl Into<Cow<'static, str>>, omissible: bool) -> Self {
+        Self {
+            logograms: logograms.into(),
+            omissible,
+        }
+    }
+
+    /// Creates a non-omissible [Chinese] expression borrowing constant logograms -
+    /// without allocating.
+    pub const fn from_static(logograms: &'static str) -> Self {
+        Self {
+            logograms: Cow::Borrowed(logograms),
+            omissible: false,
+        }
+    }
+
+    /// The logograms, as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.logograms
+    }
+}
+
+/// [Chinese] can be converted into its logograms.
+impl From<Chinese> for String {
+    fn from(chinese: Chinese) -> Self {
+        chinese.logograms.into_owned()
+    }
+}
+
 /// Converting [Chinese] to string returns its logograms:
 ///
 /// ```
 /// use chinese_format::Chinese;
 ///
 /// let chinese = Chinese {
-///     logograms: "苹果".to_string(),
+///     logograms: "苹果".into(),
 ///     omissible: false
 /// };
 ///
@@ -43,7 +93,7 @@ impl Display for Chinese {
 /// use chinese_format::Chinese;
 ///
 /// let chinese = Chinese {
-///     logograms: "小猫".to_string(),
+///     logograms: "小猫".into(),
 ///     omissible: false
 /// };
 ///
@@ -61,7 +111,7 @@ impl PartialEq<&str> for Chinese {
 /// use chinese_format::Chinese;
 ///
 /// let chinese = Chinese {
-///     logograms: "电脑".to_string(),
+///     logograms: "电脑".into(),
 ///     omissible: false
 /// };
 ///
@@ -76,4 +126,219 @@ impl PartialEq<String> for Chinese {
 /// Trait expressing support for infallible conversion to [Chinese].
 pub trait ChineseFormat {
     fn to_chinese(&self, variant: Variant) -> Chinese;
+
+    /// Converts to [Chinese], joining the components according to the given [CompositionStyle].
+    ///
+    /// By default, it simply calls [to_chinese](Self::to_chinese) - because most types
+    /// are atomic; composites, such as dates, times and currency amounts, override it.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// assert_eq!(
+    ///     90.to_chinese_composed(Variant::Simplified, CompositionStyle::Spaced),
+    ///     "九十"
+    /// );
+    /// ```
+    fn to_chinese_composed(&self, variant: Variant, _style: CompositionStyle) -> Chinese {
+        self.to_chinese(variant)
+    }
+
+    /// Converts to [Chinese], writing the numbers via the given [DigitGlyphSet].
+    ///
+    /// By default, it simply calls [to_chinese](Self::to_chinese) - because most types
+    /// contain no numbers; numeric types - such as integers, [Count](crate::Count)
+    /// and [DigitReading](crate::DigitReading) - and measures override it.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// assert_eq!(
+    ///     3017.to_chinese_with_glyphs(Variant::Traditional, DigitGlyphSet::FINANCIAL),
+    ///     "參仟零壹拾柒"
+    /// );
+    /// assert_eq!(
+    ///     "一等奖".to_chinese_with_glyphs(Variant::Simplified, DigitGlyphSet::FINANCIAL),
+    ///     "一等奖"
+    /// );
+    /// ```
+    fn to_chinese_with_glyphs(&self, variant: Variant, _glyphs: DigitGlyphSet) -> Chinese {
+        self.to_chinese(variant)
+    }
+
+    /// Converts to a sequence of logogram segments whose concatenation equals the
+    /// logograms returned by [to_chinese](Self::to_chinese) - so that they can be
+    /// streamed to a writer without intermediate joins.
+    ///
+    /// By default, it returns the logograms of [to_chinese](Self::to_chinese)
+    /// as a single segment; composites - such as measures and [crate::ChineseVec] -
+    /// override it, returning the segments of their components.
+    ///
+    /// ```
+    /// use chinese_format::{*, length::*};
+    ///
+    /// let parts: Vec<_> = 90.to_chinese_parts(Variant::Simplified).collect();
+    /// assert_eq!(parts, vec!["九十"]);
+    ///
+    /// let parts: Vec<_> = Kilometer::new(3).to_chinese_parts(Variant::Simplified).collect();
+    /// assert_eq!(parts, vec!["三", "公里"]);
+    ///
+    /// let sentence = chinese_vec!(Variant::Simplified, ["我", "", "有", Count(2), "个"]);
+    /// let parts: Vec<_> = sentence.to_chinese_parts(Variant::Simplified).collect();
+    /// assert_eq!(parts, vec!["我", "有", "两", "个"]);
+    /// assert_eq!(parts.concat(), sentence.to_chinese(Variant::Simplified).logograms);
+    /// ```
+    fn to_chinese_parts(&self, variant: Variant) -> ChineseParts {
+        self.to_chinese(variant).into()
+    }
+
+    /// Converts to a sequence of [Token](crate::pinyin::Token) - one per logogram -
+    /// whose [role](crate::pinyin::TokenRole) is assigned where the logogram is produced.
+    ///
+    /// By default, the roles are inferred from the logograms returned by
+    /// [to_chinese](Self::to_chinese); numbers, measures, fractions and decimals -
+    /// as well as the wrappers around them - override it.
+    ///
+    /// ```
+    /// use chinese_format::{*, pinyin::*};
+    ///
+    /// let roles: Vec<TokenRole> = Count(2)
+    ///     .to_chinese_tokens(Variant::Simplified)
+    ///     .into_iter()
+    ///     .map(|token| token.role)
+    ///     .collect();
+    /// assert_eq!(roles, vec![TokenRole::Digit]);
+    /// ```
+    ///
+    /// **REQUIRED FEATURE**: `pinyin`.
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        crate::pinyin::inferred_tokens(&self.to_chinese(variant).logograms)
+    }
+
+    /// Writes the logograms to the given [fmt::Write], segment by segment -
+    /// as returned by [to_chinese_parts](Self::to_chinese_parts) - so that
+    /// large texts can be streamed without building intermediate strings.
+    ///
+    /// ```
+    /// use chinese_format::{*, length::*};
+    /// use std::fmt::Write;
+    ///
+    /// let mut report = String::new();
+    ///
+    /// Kilometer::new(3).to_chinese_fmt(&mut report, Variant::Simplified).unwrap();
+    /// write!(report, "，").unwrap();
+    /// Kilometer::new(2).to_chinese_fmt(&mut report, Variant::Traditional).unwrap();
+    ///
+    /// assert_eq!(report, "三公里，兩公里");
+    /// ```
+    fn to_chinese_fmt(&self, writer: &mut impl fmt::Write, variant: Variant) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        self.to_chinese_parts(variant)
+            .try_for_each(|segment| writer.write_str(&segment))
+    }
+
+    /// Like [to_chinese_fmt](Self::to_chinese_fmt), but writing the UTF-8 bytes
+    /// of the logograms to the given [io::Write] - such as a file or a socket.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let mut output: Vec<u8> = Vec::new();
+    ///
+    /// let items: Vec<DynChinese> = vec![Box::new("共"), Box::new(Count(2)), Box::new("项")];
+    /// for item in &items {
+    ///     item.to_chinese_io(&mut output, Variant::Simplified)?;
+    /// }
+    ///
+    /// assert_eq!(String::from_utf8(output)?, "共两项");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn to_chinese_io(&self, writer: &mut impl io::Write, variant: Variant) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        self.to_chinese_parts(variant)
+            .try_for_each(|segment| writer.write_all(segment.as_bytes()))
+    }
+}
+
+/// Trait expressing support for _fallible_ conversion to [Chinese] -
+/// for values that cannot always be expressed, such as a [CountedInteger](crate::CountedInteger).
+///
+/// Every [ChineseFormat] is also a [TryChineseFormat] that never fails:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(i128::MIN.try_to_chinese(Variant::Simplified), Ok(i128::MIN.to_chinese(Variant::Simplified)));
+/// assert_eq!(Count(2).try_to_chinese(Variant::Traditional).unwrap(), "兩");
+/// ```
+pub trait TryChineseFormat {
+    type Error;
+
+    fn try_to_chinese(&self, variant: Variant) -> Result<Chinese, Self::Error>;
+}
+
+impl<T: ChineseFormat + ?Sized> TryChineseFormat for T {
+    type Error = std::convert::Infallible;
+
+    fn try_to_chinese(&self, variant: Variant) -> Result<Chinese, Self::Error> {
+        Ok(self.to_chinese(variant))
+    }
+}
+
+macro_rules! impl_chinese_format_for_boxed_dyn {
+    ($($dyn_type: ty),+) => {
+        $(
+            /// Boxed [ChineseFormat] trait objects - including [DynChinese](crate::DynChinese) -
+            /// can be converted to [Chinese] just like the values they contain,
+            /// every method being forwarded by the very same implementation:
+            ///
+            /// ```
+            /// use chinese_format::*;
+            ///
+            /// let boxed: Box<dyn ChineseFormat> = Box::new(Count(2));
+            ///
+            /// assert_eq!(boxed.to_chinese(Variant::Simplified), Chinese {
+            ///     logograms: "两".into(),
+            ///     omissible: false
+            /// });
+            ///
+            /// let financial: Box<dyn ChineseFormat> = Box::new(Count(25));
+            /// assert_eq!(
+            ///     financial.to_chinese_with_glyphs(Variant::Simplified, DigitGlyphSet::FINANCIAL),
+            ///     "贰拾伍"
+            /// );
+            /// ```
+            impl<'a> ChineseFormat for Box<$dyn_type> {
+                fn to_chinese(&self, variant: Variant) -> Chinese {
+                    self.as_ref().to_chinese(variant)
+                }
+
+                fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+                    self.as_ref().to_chinese_composed(variant, style)
+                }
+
+                fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+                    self.as_ref().to_chinese_with_glyphs(variant, glyphs)
+                }
+
+                fn to_chinese_parts(&self, variant: Variant) -> ChineseParts {
+                    self.as_ref().to_chinese_parts(variant)
+                }
+
+                #[cfg(feature = "pinyin")]
+                fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+                    self.as_ref().to_chinese_tokens(variant)
+                }
+            }
+        )+
+    };
 }
+
+impl_chinese_format_for_boxed_dyn!(dyn ChineseFormat + 'a, dyn ChineseFormat + Send + Sync + 'a);
@@ -0,0 +1,228 @@
+//! Interoperability with [ICU4X](https://github.com/unicode-org/icu4x) - and, through it,
+//! with the [CLDR](https://cldr.unicode.org/) data.
+//!
+//! It maps [Variant] to and from ICU4X [Locale] identifiers, and ICU4X ISO dates to
+//! [DateBuilder] and [WeekDay] - thus enabling hybrid pipelines, where CLDR-formatted
+//! parts, obtained via [format_cldr_date], are combined with this crate's readings:
+//!
+//! ```
+//! use chinese_format::{*, cldr::*, gregorian::*};
+//! use icu_calendar::Date as IcuDate;
+//! use icu_datetime::options::length;
+//! use icu_locid::locale;
+//!
+//! # fn main() -> GenericResult<()> {
+//! let icu_date = IcuDate::try_new_iso_date(2024, 3, 5).unwrap();
+//!
+//! let spoken = DateBuilder::try_from(&icu_date)?
+//!     .with_year_style(YearStyle::Spoken)
+//!     .build()?;
+//! assert_eq!(spoken.to_chinese(Variant::Simplified), "两千零二十四年三月五号");
+//!
+//! let cldr = format_cldr_date(&icu_date, Variant::Simplified, length::Date::Long);
+//! assert_eq!(cldr, "2024年3月5日");
+//!
+//! assert_eq!(Locale::from(Variant::Traditional), locale!("zh-Hant"));
+//! assert_eq!(Variant::try_from(&locale!("zh-TW"))?, Variant::Traditional);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! It also supports data-driven consistency tests against CLDR - for example, showing that
+//! CLDR agrees on the week days, except Sunday - where it prefers `星期日` to `星期天`:
+//!
+//! ```
+//! use chinese_format::{*, cldr::*, gregorian::*};
+//! use icu_calendar::Date as IcuDate;
+//! use icu_datetime::options::length;
+//!
+//! # fn main() -> GenericResult<()> {
+//! //From Monday to Saturday
+//! for day in 1..=6 {
+//!     let icu_date = IcuDate::try_new_iso_date(2024, 4, day).unwrap();
+//!
+//!     let week_day = DateBuilder::new()
+//!         .with_week_day(icu_date.day_of_week().into())
+//!         .build()?;
+//!
+//!     for variant in [Variant::Simplified, Variant::Traditional] {
+//!         let cldr = format_cldr_date(&icu_date, variant, length::Date::Full);
+//!
+//!         assert!(cldr.ends_with(week_day.to_chinese(variant).as_str()));
+//!     }
+//! }
+//!
+//! let sunday = IcuDate::try_new_iso_date(2024, 4, 7).unwrap();
+//! assert_eq!(format_cldr_date(&sunday, Variant::Simplified, length::Date::Full), "2024年4月7日星期日");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **REQUIRED FEATURE**: `icu`.
+use crate::{
+    gregorian::{DateBuilder, WeekDay},
+    Variant,
+};
+use icu_calendar::{types::IsoWeekday, Date as IcuDate, Gregorian, Iso};
+use icu_datetime::{options::length, TypedDateFormatter};
+pub use icu_locid::Locale;
+use icu_locid::{locale, subtags::language};
+use std::{error::Error, fmt::Display};
+
+/// Error for when a [Locale] does not denote a Chinese [Variant].
+///
+/// ```
+/// use chinese_format::cldr::*;
+///
+/// assert_eq!(
+///     UnsupportedLocale("it-IT".to_string()).to_string(),
+///     "Unsupported locale: it-IT"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnsupportedLocale(pub String);
+
+impl Display for UnsupportedLocale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unsupported locale: {}", self.0)
+    }
+}
+
+impl Error for UnsupportedLocale {}
+
+/// Error for when the year of an ICU4X date cannot be expressed by a
+/// [Date](crate::gregorian::Date).
+///
+/// ```
+/// use chinese_format::cldr::*;
+///
+/// assert_eq!(
+///     UnsupportedYear(-44).to_string(),
+///     "Unsupported year: -44"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnsupportedYear(pub i32);
+
+impl Display for UnsupportedYear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unsupported year: {}", self.0)
+    }
+}
+
+impl Error for UnsupportedYear {}
+
+/// Each [Variant] corresponds to a script-based [Locale] - `zh-Hans` or `zh-Hant`.
+impl From<Variant> for Locale {
+    fn from(variant: Variant) -> Self {
+        match variant {
+            Variant::Simplified => locale!("zh-Hans"),
+            Variant::Traditional => locale!("zh-Hant"),
+        }
+    }
+}
+
+/// A Chinese [Locale] can be converted to [Variant] - via its script or,
+/// when missing, via its region; the other locales are rejected.
+///
+/// ```
+/// use chinese_format::{*, cldr::*};
+/// use icu_locid::locale;
+///
+/// assert_eq!(Variant::try_from(&locale!("zh")), Ok(Variant::Simplified));
+/// assert_eq!(Variant::try_from(&locale!("zh-CN")), Ok(Variant::Simplified));
+/// assert_eq!(Variant::try_from(&locale!("zh-Hant-CN")), Ok(Variant::Traditional));
+/// assert_eq!(Variant::try_from(&locale!("zh-HK")), Ok(Variant::Traditional));
+///
+/// assert_eq!(
+///     Variant::try_from(&locale!("ja-JP")),
+///     Err(UnsupportedLocale("ja-JP".to_string()))
+/// );
+/// ```
+impl TryFrom<&Locale> for Variant {
+    type Error = UnsupportedLocale;
+
+    fn try_from(locale: &Locale) -> Result<Self, Self::Error> {
+        let id = &locale.id;
+
+        if id.language != language!("zh") {
+            return Err(UnsupportedLocale(locale.to_string()));
+        }
+
+        if let Some(script) = id.script {
+            return match script.as_str() {
+                "Hans" => Ok(Variant::Simplified),
+                "Hant" => Ok(Variant::Traditional),
+                _ => Err(UnsupportedLocale(locale.to_string())),
+            };
+        }
+
+        match id.region.as_ref().map(|region| region.as_str()) {
+            Some("TW" | "HK" | "MO") => Ok(Variant::Traditional),
+            _ => Ok(Variant::Simplified),
+        }
+    }
+}
+
+/// [IsoWeekday] can be infallibly converted to [WeekDay].
+impl From<IsoWeekday> for WeekDay {
+    fn from(week_day: IsoWeekday) -> Self {
+        match week_day {
+            IsoWeekday::Monday => WeekDay::Monday,
+            IsoWeekday::Tuesday => WeekDay::Tuesday,
+            IsoWeekday::Wednesday => WeekDay::Wednesday,
+            IsoWeekday::Thursday => WeekDay::Thursday,
+            IsoWeekday::Friday => WeekDay::Friday,
+            IsoWeekday::Saturday => WeekDay::Saturday,
+            IsoWeekday::Sunday => WeekDay::Sunday,
+        }
+    }
+}
+
+/// An ICU4X ISO date can be converted to a [DateBuilder] having year, month and day -
+/// that can be further customized before [building](DateBuilder::build);
+/// years outside the [u16] range are rejected.
+///
+/// ```
+/// use chinese_format::{*, cldr::*, gregorian::*};
+/// use icu_calendar::Date as IcuDate;
+///
+/// # fn main() -> GenericResult<()> {
+/// let icu_date = IcuDate::try_new_iso_date(1998, 6, 13).unwrap();
+///
+/// let date = DateBuilder::try_from(&icu_date)?
+///     .with_week_day(icu_date.day_of_week().into())
+///     .build()?;
+/// assert_eq!(date.to_chinese(Variant::Simplified), "一九九八年六月十三号星期六");
+///
+/// let ancient_date = IcuDate::try_new_iso_date(-44, 3, 15).unwrap();
+/// assert_eq!(DateBuilder::try_from(&ancient_date).err(), Some(UnsupportedYear(-44)));
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<&IcuDate<Iso>> for DateBuilder {
+    type Error = UnsupportedYear;
+
+    fn try_from(date: &IcuDate<Iso>) -> Result<Self, Self::Error> {
+        let year_number = date.year().number;
+
+        let year: u16 = year_number
+            .try_into()
+            .map_err(|_| UnsupportedYear(year_number))?;
+
+        Ok(DateBuilder::new()
+            .with_year(year)
+            .with_month(date.month().ordinal as u8)
+            .with_day(date.day_of_month().0 as u8))
+    }
+}
+
+/// Formats an ICU4X ISO date via the CLDR Gregorian patterns of the
+/// [Locale] related to the given [Variant].
+pub fn format_cldr_date(date: &IcuDate<Iso>, variant: Variant, length: length::Date) -> String {
+    let formatter =
+        TypedDateFormatter::<Gregorian>::try_new_with_length(&Locale::from(variant).into(), length)
+            .expect("The CLDR data of both variants is compiled into ICU4X");
+
+    formatter.format_to_string(&date.to_calendar(Gregorian))
+}
@@ -0,0 +1,40 @@
+use crate::CompositionStyle;
+
+/// Options for [ChineseVec::collect_with](crate::ChineseVec::collect_with),
+/// turning a [ChineseVec](crate::ChineseVec) into a sentence.
+///
+/// By default, items are simply concatenated - as in [ChineseVec::collect](crate::ChineseVec::collect).
+///
+/// A [CompositionStyle] can be converted into the equivalent options:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let options: CollectOptions = CompositionStyle::EnumerationComma.into();
+///
+/// assert_eq!(options, CollectOptions {
+///     separator: "、",
+///     ..CollectOptions::default()
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CollectOptions<'a> {
+    /// Inserted between consecutive items.
+    pub separator: &'a str,
+
+    /// When present, inserted between the last two items instead of the separator -
+    /// as `和` in `苹果、香蕉和橙子`.
+    pub final_conjunction: Option<&'a str>,
+
+    /// When present, returned in lieu of the logograms if no item has logograms.
+    pub empty_fallback: Option<&'a str>,
+}
+
+impl<'a> From<CompositionStyle> for CollectOptions<'a> {
+    fn from(style: CompositionStyle) -> Self {
+        Self {
+            separator: style.separator(),
+            ..Self::default()
+        }
+    }
+}
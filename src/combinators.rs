@@ -0,0 +1,266 @@
+use crate::{Chinese, ChineseFormat, CompositionStyle, Variant};
+use std::fmt::Display;
+
+/// Renders its first value - unless it is [omissible](Chinese::omissible),
+/// in which case the second value is rendered instead.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(OrElse(Count(3), "没有").to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三".into(),
+///     omissible: false
+/// });
+///
+/// assert_eq!(OrElse(Count(0), "没有").to_chinese(Variant::Simplified), Chinese {
+///     logograms: "没有".into(),
+///     omissible: false
+/// });
+///
+/// assert_eq!(OrElse(Count(0), "").to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OrElse<A: ChineseFormat, B: ChineseFormat>(pub A, pub B);
+
+impl<A: ChineseFormat, B: ChineseFormat> ChineseFormat for OrElse<A, B> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let first = self.0.to_chinese_composed(variant, style);
+
+        if first.omissible {
+            self.1.to_chinese_composed(variant, style)
+        } else {
+            first
+        }
+    }
+}
+
+/// Renders its inner value between a prefix and a suffix - dropping both of them
+/// when the inner value is [omissible](Chinese::omissible).
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let remark = Surround("（", Count(2), ("个人）", "個人）"));
+/// assert_eq!(remark.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "（两个人）".into(),
+///     omissible: false
+/// });
+/// assert_eq!(remark.to_chinese(Variant::Traditional), "（兩個人）");
+///
+/// let empty_remark = Surround("（", Count(0), ("个人）", "個人）"));
+/// assert_eq!(empty_remark.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+///
+/// //Combined with a placeholder, the omissible value can disappear altogether
+/// let hidden_remark = Surround("（", Placeholder::empty(Count(0)), "）");
+/// assert_eq!(hidden_remark.to_chinese(Variant::Simplified), "");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Surround<P: ChineseFormat, I: ChineseFormat, S: ChineseFormat>(pub P, pub I, pub S);
+
+impl<P: ChineseFormat, I: ChineseFormat, S: ChineseFormat> ChineseFormat for Surround<P, I, S> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let inner = self.1.to_chinese_composed(variant, style);
+
+        if inner.omissible {
+            return inner;
+        }
+
+        Chinese {
+            logograms: format!(
+                "{}{}{}",
+                self.0.to_chinese(variant),
+                inner.logograms,
+                self.2.to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Post-processes the logograms of the wrapped value via a function,
+/// preserving its [omissible](Chinese::omissible) property.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let emphasized = MapLogograms::new(Count(3), |logograms| format!("「{}」", logograms));
+/// assert_eq!(emphasized.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "「三」".into(),
+///     omissible: false
+/// });
+///
+/// let repeated = MapLogograms::new(0, |logograms| logograms.repeat(2));
+/// assert_eq!(repeated.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零零".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MapLogograms<T: ChineseFormat, F: Fn(String) -> String> {
+    pub value: T,
+    pub mapper: F,
+}
+
+impl<T: ChineseFormat, F: Fn(String) -> String> MapLogograms<T, F> {
+    pub fn new(value: T, mapper: F) -> Self {
+        Self { value, mapper }
+    }
+}
+
+impl<T: ChineseFormat, F: Fn(String) -> String> ChineseFormat for MapLogograms<T, F> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let chinese = self.value.to_chinese_composed(variant, style);
+
+        Chinese {
+            logograms: (self.mapper)(chinese.logograms.into_owned()).into(),
+            omissible: chinese.omissible,
+        }
+    }
+}
+
+/// Renders a field as `label：value` - with the full-width colon - eliding the
+/// whole field when the value is [omissible](Chinese::omissible).
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let name = Labeled("姓名", "王小明");
+/// assert_eq!(name.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "姓名：王小明".into(),
+///     omissible: false
+/// });
+///
+/// let amount = Labeled(("金额", "金額"), Surround("", Count(35), "元"));
+/// assert_eq!(amount.to_chinese(Variant::Simplified), "金额：三十五元");
+/// assert_eq!(amount.to_chinese(Variant::Traditional), "金額：三十五元");
+///
+/// let missing = Labeled("备注", "");
+/// assert_eq!(missing.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+/// ```
+///
+/// Fields can therefore be assembled into reports, the omissible ones disappearing:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let fields: Vec<Box<dyn ChineseFormat>> = vec![
+///     Box::new(Labeled("姓名", "王小明")),
+///     Box::new(Labeled(("年龄", "年齡"), Placeholder::empty(Count(0)))),
+///     Box::new(Labeled(("金额", "金額"), Surround("", Count(35), "元"))),
+/// ];
+///
+/// let report: Vec<String> = fields
+///     .iter()
+///     .map(|field| field.to_chinese(Variant::Simplified))
+///     .filter(|field| !field.omissible)
+///     .map(String::from)
+///     .collect();
+///
+/// assert_eq!(report.join("\n"), "姓名：王小明\n金额：三十五元");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Labeled<L: ChineseFormat, V: ChineseFormat>(pub L, pub V);
+
+impl<L: ChineseFormat, V: ChineseFormat> Labeled<L, V> {
+    const COLON: &'static str = "：";
+}
+
+impl<L: ChineseFormat, V: ChineseFormat> ChineseFormat for Labeled<L, V> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let value = self.1.to_chinese_composed(variant, style);
+
+        if value.omissible {
+            return Chinese {
+                logograms: "".into(),
+                omissible: true,
+            };
+        }
+
+        Chinese {
+            logograms: format!(
+                "{}{}{}",
+                self.0.to_chinese(variant),
+                Self::COLON,
+                value.logograms
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Renders a Chinese expression alongside another representation -
+/// such as an Arabic numeral or a different language - as in bilingual signage.
+///
+/// The other representation is omitted when empty, and the result is
+/// [omissible](Chinese::omissible) just like the Chinese expression.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let line = Bilingual(("三号线", "三號線"), "Line 3", " ");
+/// assert_eq!(line.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三号线 Line 3".into(),
+///     omissible: false
+/// });
+/// assert_eq!(line.to_chinese(Variant::Traditional), "三號線 Line 3");
+///
+/// let floor = Bilingual(Count(12), 12, " / ");
+/// assert_eq!(floor.to_chinese(Variant::Simplified), "十二 / 12");
+///
+/// let exit = Bilingual("出口", "", " ");
+/// assert_eq!(exit.to_chinese(Variant::Simplified), "出口");
+///
+/// assert_eq!(Bilingual(Count(0), 0, " ").to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零 0".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bilingual<C: ChineseFormat, O: Display>(pub C, pub O, pub &'static str);
+
+impl<C: ChineseFormat, O: Display> ChineseFormat for Bilingual<C, O> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let chinese = self.0.to_chinese_composed(variant, style);
+        let other = self.1.to_string();
+
+        if other.is_empty() {
+            return chinese;
+        }
+
+        Chinese {
+            logograms: format!("{}{}{}", chinese.logograms, self.2, other).into(),
+            omissible: chinese.omissible,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Chinese, ChineseFormat, Variant};
+
+/// The adjective of a [Comparison] - stating how the subject differs from the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ComparisonDirection {
+    /// `多`
+    More,
+
+    /// `少`
+    Less,
+
+    /// `高`
+    Higher,
+
+    /// `低`
+    Lower,
+
+    /// `大`
+    Bigger,
+
+    /// `小`
+    Smaller,
+
+    /// `长`/`長`
+    Longer,
+
+    /// `短`
+    Shorter,
+
+    /// `贵`/`貴`
+    Pricier,
+
+    /// `便宜`
+    Cheaper,
+}
+
+impl ChineseFormat for ComparisonDirection {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = match self {
+            Self::More => ("多", "多"),
+            Self::Less => ("少", "少"),
+            Self::Higher => ("高", "高"),
+            Self::Lower => ("低", "低"),
+            Self::Bigger => ("大", "大"),
+            Self::Smaller => ("小", "小"),
+            Self::Longer => ("长", "長"),
+            Self::Shorter => ("短", "短"),
+            Self::Pricier => ("贵", "貴"),
+            Self::Cheaper => ("便宜", "便宜"),
+        };
+
+        logograms.to_chinese(variant)
+    }
+}
+
+/// Comparison with a baseline - as in `比去年多百分之十五` or `比A贵三十块` -
+/// for analytics summaries.
+///
+/// When the amount is [omissible](Chinese::omissible), the subject is
+/// on a par with the baseline - as in `与去年持平`.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let growth = Comparison::new(&"去年", ComparisonDirection::More, &Percent(15));
+/// assert_eq!(growth.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "比去年多百分之十五".into(),
+///     omissible: false
+/// });
+///
+/// let price = Surround("", Count(30), ("块", "塊"));
+/// let pricier = Comparison::new(&"A", ComparisonDirection::Pricier, &price);
+/// assert_eq!(pricier.to_chinese(Variant::Simplified), "比A贵三十块");
+/// assert_eq!(pricier.to_chinese(Variant::Traditional), "比A貴三十塊");
+///
+/// let shorter = Comparison::new(&("上个月", "上個月"), ComparisonDirection::Shorter, &"两天");
+/// assert_eq!(shorter.to_chinese(Variant::Simplified), "比上个月短两天");
+///
+/// let steady = Comparison::new(&"去年", ComparisonDirection::More, &Percent(0));
+/// assert_eq!(steady.to_chinese(Variant::Simplified), "与去年持平");
+/// assert_eq!(steady.to_chinese(Variant::Traditional), "與去年持平");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Comparison<'a> {
+    pub baseline: &'a dyn ChineseFormat,
+    pub direction: ComparisonDirection,
+    pub amount: &'a dyn ChineseFormat,
+}
+
+impl<'a> Comparison<'a> {
+    const BI: &'static str = "比";
+
+    const YU: (&'static str, &'static str) = ("与", "與");
+
+    const CHIPING: &'static str = "持平";
+
+    pub fn new(
+        baseline: &'a dyn ChineseFormat,
+        direction: ComparisonDirection,
+        amount: &'a dyn ChineseFormat,
+    ) -> Self {
+        Self {
+            baseline,
+            direction,
+            amount,
+        }
+    }
+}
+
+impl<'a> ChineseFormat for Comparison<'a> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let baseline = self.baseline.to_chinese(variant);
+        let amount = self.amount.to_chinese(variant);
+
+        let logograms = if amount.omissible {
+            format!(
+                "{}{}{}",
+                Self::YU.to_chinese(variant),
+                baseline,
+                Self::CHIPING
+            )
+        } else {
+            format!(
+                "{}{}{}{}",
+                Self::BI,
+                baseline,
+                self.direction.to_chinese(variant),
+                amount
+            )
+        };
+
+        Chinese {
+            logograms: logograms.into(),
+            omissible: false,
+        }
+    }
+}
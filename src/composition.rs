@@ -0,0 +1,38 @@
+/// How the components of a composite expression are joined.
+///
+/// It is applied by [ChineseVec::collect_composed](crate::ChineseVec::collect_composed)
+/// and by the composites overriding [ChineseFormat::to_chinese_composed](crate::ChineseFormat::to_chinese_composed) -
+/// such as dates, times and currency amounts.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(CompositionStyle::default(), CompositionStyle::Compact);
+///
+/// assert_eq!(CompositionStyle::Compact.separator(), "");
+/// assert_eq!(CompositionStyle::EnumerationComma.separator(), "、");
+/// assert_eq!(CompositionStyle::Spaced.separator(), " ");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum CompositionStyle {
+    /// Components are simply concatenated - as usual in Chinese.
+    #[default]
+    Compact,
+
+    /// Components are joined by the enumeration comma `、`.
+    EnumerationComma,
+
+    /// Components are joined by a space - as needed by transliterations such as pinyin.
+    Spaced,
+}
+
+impl CompositionStyle {
+    /// The string inserted between two adjacent components.
+    pub fn separator(&self) -> &'static str {
+        match self {
+            Self::Compact => "",
+            Self::EnumerationComma => "、",
+            Self::Spaced => " ",
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Chinese, ChineseFormat, Variant};
+use crate::{Chinese, ChineseFormat, DigitGlyphSet, Variant};
 use std::cmp::Ordering;
 
 /// The integer type on which [Count] is based.
@@ -63,14 +63,14 @@ impl PartialOrd<CountBase> for Count {
 ///
 /// //Positive numbers
 /// assert_eq!(Count(7).to_chinese(Variant::Simplified), Chinese {
-///     logograms: "七".to_string(),
+///     logograms: "七".into(),
 ///     omissible: false
 /// });
 /// assert_eq!(Count(42).to_chinese(Variant::Traditional), "四十二");
 ///
 /// //Zero
 /// assert_eq!(Count(0).to_chinese(Variant::Simplified), Chinese {
-///     logograms: "零".to_string(),
+///     logograms: "零".into(),
 ///     omissible: true
 /// });
 /// assert_eq!(Count(0).to_chinese(Variant::Traditional), "零");
@@ -82,9 +82,148 @@ impl PartialOrd<CountBase> for Count {
 impl ChineseFormat for Count {
     fn to_chinese(&self, variant: Variant) -> Chinese {
         if self.0 == 2 {
-            ("两", "兩").to_chinese(variant)
+            Chinese::from_static(match variant {
+                Variant::Simplified => "两",
+                Variant::Traditional => "兩",
+            })
         } else {
             self.0.to_chinese(variant)
         }
     }
+
+    /// Like the standard conversion, but 2 is written via the
+    /// [counted_two](DigitGlyphSet::counted_two) of the glyph set.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// assert_eq!(Count(2).to_chinese_with_glyphs(Variant::Traditional, DigitGlyphSet::FINANCIAL), "貳");
+    /// assert_eq!(Count(2).to_chinese_with_glyphs(Variant::Simplified, DigitGlyphSet::PLAIN_TWO), "二");
+    /// assert_eq!(Count(12).to_chinese_with_glyphs(Variant::Simplified, DigitGlyphSet::SUZHOU), "〸〢");
+    /// ```
+    fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+        if self.0 == 2 {
+            Chinese {
+                logograms: glyphs.counted_two(variant).to_string().into(),
+                omissible: false,
+            }
+        } else {
+            self.0.to_chinese_with_glyphs(variant, glyphs)
+        }
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        crate::pinyin::number_tokens(
+            &self.to_chinese(variant).logograms,
+            variant,
+            DigitGlyphSet::STANDARD,
+        )
+    }
+}
+
+impl Count {
+    /// Like the conversion via the given [DigitGlyphSet], but also reading a leading `2`
+    /// as [counted_two](DigitGlyphSet::counted_two) before `百` and `千` -
+    /// as in spoken `两百` or `两千零二十四`.
+    pub(crate) fn to_spoken_chinese(self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+        let count = self.to_chinese_with_glyphs(variant, glyphs);
+
+        let two = glyphs.digit(2, variant);
+        let hundred_or_thousand = [glyphs.small_unit(1, variant), glyphs.small_unit(2, variant)];
+
+        let mut chars = count.logograms.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(first), Some(unit)) if first == two && hundred_or_thousand.contains(&unit) => {
+                Chinese {
+                    logograms: format!(
+                        "{}{}",
+                        glyphs.counted_two(variant),
+                        &count.logograms[two.len_utf8()..]
+                    )
+                    .into(),
+                    ..count
+                }
+            }
+            _ => count,
+        }
+    }
+}
+
+/// How a [StyledCount] is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum CountStyle {
+    /// Just like [Count].
+    #[default]
+    Standard,
+
+    /// Like [Standard](Self::Standard), but with the informal `俩`/`倆` for 2
+    /// and `仨` for 3 - which already include the classifier, as in `我们俩` or `仨人`.
+    Colloquial,
+}
+
+/// [Count] read according to a [CountStyle] - especially when counting people informally.
+///
+/// Like [Count], it is [omissible](Chinese::omissible) only when zero.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let two = StyledCount {
+///     count: Count(2),
+///     style: CountStyle::Colloquial
+/// };
+/// assert_eq!(two.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "俩".into(),
+///     omissible: false
+/// });
+/// assert_eq!(two.to_chinese(Variant::Traditional), "倆");
+///
+/// let we_three = chinese_vec!(
+///     Variant::Simplified,
+///     [("我们", "我們"), StyledCount { count: Count(3), style: CountStyle::Colloquial }]
+/// ).collect();
+/// assert_eq!(we_three, "我们仨");
+///
+/// let four = StyledCount {
+///     count: Count(4),
+///     style: CountStyle::Colloquial
+/// };
+/// assert_eq!(four.to_chinese(Variant::Simplified), "四");
+///
+/// let standard = StyledCount {
+///     count: Count(2),
+///     style: CountStyle::Standard
+/// };
+/// assert_eq!(standard.to_chinese(Variant::Traditional), "兩");
+///
+/// let none = StyledCount {
+///     count: Count(0),
+///     style: CountStyle::Colloquial
+/// };
+/// assert_eq!(none.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StyledCount {
+    pub count: Count,
+    pub style: CountStyle,
+}
+
+impl ChineseFormat for StyledCount {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match (self.style, self.count.0) {
+            (CountStyle::Colloquial, 2) => Chinese::from_static(match variant {
+                Variant::Simplified => "俩",
+                Variant::Traditional => "倆",
+            }),
+
+            (CountStyle::Colloquial, 3) => Chinese::from_static("仨"),
+
+            _ => self.count.to_chinese(variant),
+        }
+    }
 }
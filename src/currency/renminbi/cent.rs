@@ -91,12 +91,12 @@ mod tests {
                     };
 
                     eq!(two_formal.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "两分".to_string(),
+                        logograms: "两分".into(),
                         omissible: false
                     });
 
                     eq!(two_formal.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "兩分".to_string(),
+                        logograms: "兩分".into(),
                         omissible: false
                     });
                 }
@@ -110,12 +110,12 @@ mod tests {
                     };
 
                     eq!(two_informal.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "两分".to_string(),
+                        logograms: "两分".into(),
                         omissible: false
                     });
 
                     eq!(two_informal.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "兩分".to_string(),
+                        logograms: "兩分".into(),
                         omissible: false
                     });
                 }
@@ -129,12 +129,12 @@ mod tests {
                     };
 
                     eq!(two_financial.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "贰分".to_string(),
+                        logograms: "贰分".into(),
                         omissible: false
                     });
 
                     eq!(two_financial.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "貳分".to_string(),
+                        logograms: "貳分".into(),
                         omissible: false
                     });
                 }
@@ -149,7 +149,7 @@ mod tests {
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零分".to_string(),
+                            logograms: "零分".into(),
                             omissible: true
                         }
                     );
@@ -161,7 +161,7 @@ mod tests {
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零分".to_string(),
+                            logograms: "零分".into(),
                             omissible: true
                         }
                     );
@@ -173,7 +173,7 @@ mod tests {
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零分".to_string(),
+                            logograms: "零分".into(),
                             omissible: true
                         }
                     );
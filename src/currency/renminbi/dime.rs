@@ -93,12 +93,12 @@ mod tests {
                     };
 
                     eq!(two_formal.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "两角".to_string(),
+                        logograms: "两角".into(),
                         omissible: false
                     });
 
                     eq!(two_formal.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "兩角".to_string(),
+                        logograms: "兩角".into(),
                         omissible: false
                     });
                 }
@@ -112,12 +112,12 @@ mod tests {
                     };
 
                     eq!(two_informal.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "两毛".to_string(),
+                        logograms: "两毛".into(),
                         omissible: false
                     });
 
                     eq!(two_informal.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "兩毛".to_string(),
+                        logograms: "兩毛".into(),
                         omissible: false
                     });
                 }
@@ -131,12 +131,12 @@ mod tests {
                     };
 
                     eq!(two_financial.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "贰角".to_string(),
+                        logograms: "贰角".into(),
                         omissible: false
                     });
 
                     eq!(two_financial.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "貳角".to_string(),
+                        logograms: "貳角".into(),
                         omissible: false
                     });
                 }
@@ -151,7 +151,7 @@ mod tests {
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零角".to_string(),
+                            logograms: "零角".into(),
                             omissible: true
                         }
                     );
@@ -163,7 +163,7 @@ mod tests {
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零毛".to_string(),
+                            logograms: "零毛".into(),
                             omissible: true
                         }
                     );
@@ -175,7 +175,7 @@ mod tests {
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零角".to_string(),
+                            logograms: "零角".into(),
                             omissible: true
                         }
                     );
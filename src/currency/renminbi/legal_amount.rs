@@ -0,0 +1,206 @@
+use super::{cent::Cent, dime::Dime, yuan::Yuan, RenminbiCurrency};
+use crate::{
+    chinese_vec, currency::CurrencyStyle, ArabicGrouping, ArabicNumber, Chinese, ChineseFormat,
+    Variant,
+};
+
+/// Amount phrased as in contracts - that is, written in Arabic digits
+/// grouped by thousands, followed by its financial reading:
+/// `人民币1,234.56元（大写：壹仟贰佰叁拾肆元伍角陆分）`.
+///
+/// Only the yuan, dimes and cents of the [RenminbiCurrency] are considered -
+/// as well as [formal_yuan](RenminbiCurrency::formal_yuan), in the financial reading;
+/// `整` ends the financial reading only when there are no cents,
+/// whereas `零` replaces the missing dimes between yuan and cents.
+///
+/// ```
+/// use chinese_format::{*, currency::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let price = RenminbiCurrencyBuilder::new()
+///     .with_yuan(1234)
+///     .with_dimes(5)
+///     .with_cents(6)
+///     .build()?;
+///
+/// let amount = LegalAmount::new(price);
+/// assert_eq!(amount.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "人民币1,234.56元（大写：壹仟贰佰叁拾肆元伍角陆分）".into(),
+///     omissible: false
+/// });
+/// assert_eq!(
+///     amount.to_chinese(Variant::Traditional),
+///     "人民幣1,234.56元（大寫：壹仟貳佰參拾肆元伍角陸分）"
+/// );
+///
+/// let round = RenminbiCurrencyBuilder::new().with_yuan(3_000_000).build()?;
+/// assert_eq!(
+///     LegalAmount::new(round).to_chinese(Variant::Simplified),
+///     "人民币3,000,000.00元（大写：叁佰万元整）"
+/// );
+///
+/// let without_dimes = RenminbiCurrencyBuilder::new()
+///     .with_yuan(7)
+///     .with_cents(8)
+///     .with_formal_yuan(true)
+///     .build()?;
+/// assert_eq!(
+///     LegalAmount::new(without_dimes).to_chinese(Variant::Simplified),
+///     "人民币7.08元（大写：柒圆零捌分）"
+/// );
+///
+/// let only_dimes = RenminbiCurrencyBuilder::new().with_dimes(4).build()?;
+/// assert_eq!(
+///     LegalAmount::new(only_dimes).to_chinese(Variant::Simplified),
+///     "人民币0.40元（大写：肆角整）"
+/// );
+///
+/// let zero = RenminbiCurrencyBuilder::new().build()?;
+/// assert_eq!(
+///     LegalAmount::new(zero).to_chinese(Variant::Simplified),
+///     "人民币0.00元（大写：零元整）"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Both the parentheses and the label are configurable - an empty label
+/// also removing the colon:
+///
+/// ```
+/// use chinese_format::{*, currency::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let price = RenminbiCurrencyBuilder::new()
+///     .with_yuan(50)
+///     .with_dimes(5)
+///     .build()?;
+///
+/// let custom = LegalAmount {
+///     parentheses: ("(", ")"),
+///     label: &("金额大写", "金額大寫"),
+///     ..LegalAmount::new(price)
+/// };
+/// assert_eq!(
+///     custom.to_chinese(Variant::Traditional),
+///     "人民幣50.50元(金額大寫：伍拾元伍角整)"
+/// );
+///
+/// let unlabeled = LegalAmount {
+///     label: &"",
+///     ..LegalAmount::new(price)
+/// };
+/// assert_eq!(
+///     unlabeled.to_chinese(Variant::Simplified),
+///     "人民币50.50元（伍拾元伍角整）"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `currency`.
+#[derive(Clone, Copy)]
+pub struct LegalAmount<'a> {
+    pub currency: RenminbiCurrency,
+
+    /// The opening and closing parentheses around the financial reading.
+    pub parentheses: (&'static str, &'static str),
+
+    /// The label introducing the financial reading - such as `大写`.
+    pub label: &'a dyn ChineseFormat,
+}
+
+impl<'a> LegalAmount<'a> {
+    const LABEL_SEPARATOR: &'static str = "：";
+
+    /// Creates a [LegalAmount] with full-width parentheses and the `大写`/`大寫` label.
+    pub fn new(currency: RenminbiCurrency) -> Self {
+        Self {
+            currency,
+            parentheses: ("（", "）"),
+            label: &("大写", "大寫"),
+        }
+    }
+
+    /// The amount in Arabic digits - with comma-separated thousands and 2 decimals.
+    fn figures(&self) -> String {
+        let yuan = ArabicNumber {
+            grouping: ArabicGrouping::Thousands,
+            ..ArabicNumber::new(self.currency.yuan() as i128)
+        };
+
+        format!(
+            "{}.{}{}",
+            yuan.to_chinese(Variant::Simplified).logograms,
+            self.currency.dimes(),
+            self.currency.cents()
+        )
+    }
+
+    fn financial_reading(&self, variant: Variant) -> Chinese {
+        let yuan = self.currency.yuan();
+        let dimes = self.currency.dimes();
+        let cents = self.currency.cents();
+
+        let yuan_reading: Box<dyn ChineseFormat> = if yuan > 0 || (dimes == 0 && cents == 0) {
+            Box::new(Yuan {
+                value: yuan,
+                style: CurrencyStyle::Financial,
+                formal_yuan: self.currency.formal_yuan(),
+            })
+        } else {
+            Box::new("")
+        };
+
+        let dimes_reading: Box<dyn ChineseFormat> = if dimes > 0 {
+            Box::new(
+                Dime::try_new(dimes, CurrencyStyle::Financial)
+                    .expect("The dimes come from a valid currency"),
+            )
+        } else if yuan > 0 && cents > 0 {
+            Box::new("零")
+        } else {
+            Box::new("")
+        };
+
+        let cents_reading: Box<dyn ChineseFormat> = if cents > 0 {
+            Box::new(
+                Cent::try_new(cents, CurrencyStyle::Financial)
+                    .expect("The cents come from a valid currency"),
+            )
+        } else {
+            Box::new(RenminbiCurrency::FINANCIAL_TERMINATOR)
+        };
+
+        chinese_vec!(variant, [yuan_reading, dimes_reading, cents_reading]).collect()
+    }
+}
+
+impl<'a> ChineseFormat for LegalAmount<'a> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let (opening, closing) = self.parentheses;
+
+        let label = self.label.to_chinese(variant);
+
+        let separator = if label.logograms.is_empty() {
+            ""
+        } else {
+            Self::LABEL_SEPARATOR
+        };
+
+        chinese_vec!(
+            variant,
+            [
+                RenminbiCurrency::CURRENCY_NAME,
+                self.figures(),
+                "元",
+                opening,
+                label.logograms,
+                separator,
+                self.financial_reading(variant).logograms,
+                closing
+            ]
+        )
+        .collect()
+    }
+}
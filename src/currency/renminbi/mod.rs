@@ -1,12 +1,15 @@
 mod cent;
 mod dime;
+mod legal_amount;
 mod yuan;
 
+pub use self::legal_amount::*;
+
 use self::{cent::Cent, dime::Dime, yuan::Yuan};
 use super::CurrencyStyle;
 use crate::{
-    chinese_vec, Chinese, ChineseFormat, EmptyPlaceholder, FinancialBase, GenericResult,
-    LingPlaceholder, Variant,
+    chinese_vec, Chinese, ChineseFormat, CompositionStyle, EmptyPlaceholder, FinancialBase,
+    GenericResult, LingPlaceholder, Variant,
 };
 
 /// Builds instances of [RenminbiCurrency] in a simple and consistent way.
@@ -25,7 +28,7 @@ use crate::{
 ///         .build()?;
 ///
 /// assert_eq!(formal.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "九元三角八分".to_string(),
+///     logograms: "九元三角八分".into(),
 ///     omissible: false
 /// });
 ///
@@ -38,7 +41,7 @@ use crate::{
 ///         .build()?;
 ///
 /// assert_eq!(informal.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "七块四毛五分".to_string(),
+///     logograms: "七块四毛五分".into(),
 ///     omissible: false
 /// });
 ///    
@@ -51,7 +54,7 @@ use crate::{
 ///         .build()?;
 ///
 /// assert_eq!(financial.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "贰元陆角壹分整".to_string(),
+///     logograms: "贰元陆角壹分整".into(),
 ///     omissible: false
 /// });
 ///  
@@ -63,6 +66,8 @@ pub struct RenminbiCurrencyBuilder {
     dimes: u8,
     cents: u8,
     style: CurrencyStyle,
+    currency_name: bool,
+    formal_yuan: bool,
 }
 
 impl RenminbiCurrencyBuilder {
@@ -79,7 +84,7 @@ impl RenminbiCurrencyBuilder {
     ///     RenminbiCurrencyBuilder::new().build()?;
     ///
     /// assert_eq!(default.to_chinese(Variant::Simplified), Chinese {
-    ///     logograms: "零元".to_string(),
+    ///     logograms: "零元".into(),
     ///     omissible: true
     /// });
     ///
@@ -120,6 +125,70 @@ impl RenminbiCurrencyBuilder {
         self
     }
 
+    /// Sets whether the amount should be prefixed by the name
+    /// of the currency - `人民币`/`人民幣` - as required on invoices.
+    ///
+    /// ```
+    /// use chinese_format::{*, currency::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let currency = RenminbiCurrencyBuilder::new()
+    ///     .with_yuan(300)
+    ///     .with_style(CurrencyStyle::Financial)
+    ///     .with_currency_name(true)
+    ///     .build()?;
+    ///
+    /// assert_eq!(currency.to_chinese(Variant::Simplified), "人民币叁佰元整");
+    /// assert_eq!(currency.to_chinese(Variant::Traditional), "人民幣參佰元整");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_currency_name(mut self, currency_name: bool) -> Self {
+        self.currency_name = currency_name;
+        self
+    }
+
+    /// Sets whether the 元 unit should be written as `圆`/`圓` - its formal variant.
+    ///
+    /// It has no effect on the *informal* [CurrencyStyle::Everyday], which uses `块`.
+    ///
+    /// ```
+    /// use chinese_format::{*, currency::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let financial = RenminbiCurrencyBuilder::new()
+    ///     .with_yuan(300)
+    ///     .with_style(CurrencyStyle::Financial)
+    ///     .with_currency_name(true)
+    ///     .with_formal_yuan(true)
+    ///     .build()?;
+    /// assert_eq!(financial.to_chinese(Variant::Simplified), "人民币叁佰圆整");
+    /// assert_eq!(financial.to_chinese(Variant::Traditional), "人民幣參佰圓整");
+    ///
+    /// let everyday = RenminbiCurrencyBuilder::new()
+    ///     .with_yuan(5)
+    ///     .with_dimes(2)
+    ///     .with_formal_yuan(true)
+    ///     .build()?;
+    /// assert_eq!(everyday.to_chinese(Variant::Simplified), "五圆两角");
+    ///
+    /// let informal = RenminbiCurrencyBuilder::new()
+    ///     .with_yuan(5)
+    ///     .with_dimes(2)
+    ///     .with_style(CurrencyStyle::Everyday { formal: false })
+    ///     .with_formal_yuan(true)
+    ///     .build()?;
+    /// assert_eq!(informal.to_chinese(Variant::Simplified), "五块两毛");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_formal_yuan(mut self, formal_yuan: bool) -> Self {
+        self.formal_yuan = formal_yuan;
+        self
+    }
+
     /// Builds an instance of [RenminbiCurrency] based on the provided settings.
     ///
     /// ```
@@ -159,6 +228,7 @@ impl RenminbiCurrencyBuilder {
             yuan: Yuan {
                 value: self.yuan,
                 style: self.style,
+                formal_yuan: self.formal_yuan,
             },
 
             dimes: Dime::try_new(self.dimes, self.style)?,
@@ -166,12 +236,15 @@ impl RenminbiCurrencyBuilder {
             cents: Cent::try_new(self.cents, self.style)?,
 
             style: self.style,
+
+            currency_name: self.currency_name,
         })
     }
 }
 
 /// The default value contains only 0s,
-/// with a *formal* [CurrencyStyle::Everyday].
+/// with a *formal* [CurrencyStyle::Everyday], no currency name
+/// and the plain `元` unit.
 impl Default for RenminbiCurrencyBuilder {
     fn default() -> Self {
         Self {
@@ -179,6 +252,8 @@ impl Default for RenminbiCurrencyBuilder {
             dimes: 0,
             cents: 0,
             style: CurrencyStyle::Everyday { formal: true },
+            currency_name: false,
+            formal_yuan: false,
         }
     }
 }
@@ -213,11 +288,14 @@ pub struct RenminbiCurrency {
     dimes: Dime,
     cents: Cent,
     style: CurrencyStyle,
+    currency_name: bool,
 }
 
 impl RenminbiCurrency {
     const FINANCIAL_TERMINATOR: &'static str = "整";
 
+    const CURRENCY_NAME: (&'static str, &'static str) = ("人民币", "人民幣");
+
     /// Returns the numeric value of the yuan (元) unit.
     pub fn yuan(&self) -> FinancialBase {
         self.yuan.into()
@@ -237,6 +315,16 @@ impl RenminbiCurrency {
     pub fn style(&self) -> CurrencyStyle {
         self.style
     }
+
+    /// Returns whether the amount is prefixed by the currency name.
+    pub fn currency_name(&self) -> bool {
+        self.currency_name
+    }
+
+    /// Returns whether the 元 unit is written as `圆`/`圓`.
+    pub fn formal_yuan(&self) -> bool {
+        self.yuan.formal_yuan
+    }
 }
 
 /// [RenminbiCurrency] supports conversion to [Chinese].
@@ -354,8 +442,32 @@ impl RenminbiCurrency {
 /// # Ok(())
 /// # }
 /// ```
+///
+/// The components can also be joined via a [CompositionStyle](crate::CompositionStyle):
+///
+/// ```
+/// use chinese_format::{*, currency::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// assert_eq!(
+///     RenminbiCurrencyBuilder::new()
+///         .with_yuan(7)
+///         .with_dimes(4)
+///         .with_cents(8)
+///         .build()?
+///         .to_chinese_composed(Variant::Simplified, CompositionStyle::Spaced),
+///     "七元 四角 八分"
+/// );
+///
+/// # Ok(())
+/// # }
+/// ```
 impl ChineseFormat for RenminbiCurrency {
     fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
         let dimes_box: Box<dyn ChineseFormat> = match self.style {
             CurrencyStyle::Everyday { formal: false } => {
                 Box::new(LingPlaceholder::new(&self.dimes))
@@ -373,7 +485,7 @@ impl ChineseFormat for RenminbiCurrency {
             ]
         )
         .trim_start()
-        .collect();
+        .collect_composed(style);
 
         let coalesced_result = if concatenated_components.omissible {
             self.yuan.to_chinese(variant)
@@ -381,14 +493,28 @@ impl ChineseFormat for RenminbiCurrency {
             concatenated_components
         };
 
-        match self.style {
+        let terminated_result = match self.style {
             CurrencyStyle::Financial => chinese_vec!(
                 variant,
                 [coalesced_result.logograms, Self::FINANCIAL_TERMINATOR]
             )
-            .collect(),
+            .collect_composed(style),
 
             _ => coalesced_result,
+        };
+
+        if self.currency_name {
+            Chinese {
+                logograms: chinese_vec!(
+                    variant,
+                    [Self::CURRENCY_NAME, terminated_result.logograms]
+                )
+                .collect_composed(style)
+                .logograms,
+                omissible: terminated_result.omissible,
+            }
+        } else {
+            terminated_result
         }
     }
 }
@@ -3,14 +3,19 @@ use crate::{
     Count, Financial, FinancialBase, Variant,
 };
 
-define_multi_register_measure!(EverydayYuan, pub, Count, "元", "块");
+define_multi_register_measure!(EverydayYuan, pub, Count, "元", ("块", "塊"));
+
+define_measure!(EverydayFormalYuan, pub, Count, ("圆", "圓"));
 
 define_measure!(FinancialYuan, pub, Financial, "元");
 
+define_measure!(FinancialFormalYuan, pub, Financial, ("圆", "圓"));
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Yuan {
     pub value: FinancialBase,
     pub style: CurrencyStyle,
+    pub formal_yuan: bool,
 }
 
 impl From<Yuan> for FinancialBase {
@@ -22,12 +27,20 @@ impl From<Yuan> for FinancialBase {
 impl ChineseFormat for Yuan {
     fn to_chinese(&self, variant: Variant) -> Chinese {
         match self.style {
+            CurrencyStyle::Everyday { formal: true } if self.formal_yuan => {
+                EverydayFormalYuan(Count(self.value as u128)).to_chinese(variant)
+            }
+
             CurrencyStyle::Everyday { formal } => EverydayYuan {
                 value: Count(self.value as u128),
                 formal,
             }
             .to_chinese(variant),
 
+            CurrencyStyle::Financial if self.formal_yuan => {
+                FinancialFormalYuan(Financial(self.value)).to_chinese(variant)
+            }
+
             CurrencyStyle::Financial => FinancialYuan(Financial(self.value)).to_chinese(variant),
         }
     }
@@ -45,7 +58,8 @@ mod tests {
                 it "should work" {
                     let converted: FinancialBase = Yuan {
                         value: 90,
-                        style: CurrencyStyle::Financial
+                        style: CurrencyStyle::Financial,
+                        formal_yuan: false
                     }.into();
 
                     eq!(converted, 90);
@@ -56,16 +70,17 @@ mod tests {
                 it "should work" {
                     let two_formal = Yuan {
                         value: 2,
-                        style: CurrencyStyle::Everyday { formal: true }
+                        style: CurrencyStyle::Everyday { formal: true },
+                        formal_yuan: false
                     };
 
                     eq!(two_formal.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "两元".to_string(),
+                        logograms: "两元".into(),
                         omissible: false
                     });
 
                     eq!(two_formal.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "兩元".to_string(),
+                        logograms: "兩元".into(),
                         omissible: false
                     });
                 }
@@ -75,16 +90,17 @@ mod tests {
                 it "should work" {
                     let two_informal = Yuan {
                         value: 2,
-                        style: CurrencyStyle::Everyday { formal: false }
+                        style: CurrencyStyle::Everyday { formal: false },
+                        formal_yuan: false
                     };
 
                     eq!(two_informal.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "两块".to_string(),
+                        logograms: "两块".into(),
                         omissible: false
                     });
 
                     eq!(two_informal.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "兩块".to_string(),
+                        logograms: "兩塊".into(),
                         omissible: false
                     });
                 }
@@ -94,31 +110,67 @@ mod tests {
                 it "should work" {
                     let two_financial = Yuan {
                         value: 2,
-                        style: CurrencyStyle::Financial
+                        style: CurrencyStyle::Financial,
+                        formal_yuan: false
                     };
 
                     eq!(two_financial.to_chinese(Variant::Simplified), Chinese {
-                        logograms: "贰元".to_string(),
+                        logograms: "贰元".into(),
                         omissible: false
                     });
 
                     eq!(two_financial.to_chinese(Variant::Traditional), Chinese {
-                        logograms: "貳元".to_string(),
+                        logograms: "貳元".into(),
                         omissible: false
                     });
                 }
             }
 
+            describe "when requesting the formal yuan" {
+                it "should use 圆 in everyday formal style" {
+                    let formal_yuan = Yuan {
+                        value: 2,
+                        style: CurrencyStyle::Everyday { formal: true },
+                        formal_yuan: true
+                    };
+
+                    eq!(formal_yuan.to_chinese(Variant::Simplified), "两圆");
+                    eq!(formal_yuan.to_chinese(Variant::Traditional), "兩圓");
+                }
+
+                it "should use 圆 in financial style" {
+                    let formal_yuan = Yuan {
+                        value: 2,
+                        style: CurrencyStyle::Financial,
+                        formal_yuan: true
+                    };
+
+                    eq!(formal_yuan.to_chinese(Variant::Simplified), "贰圆");
+                    eq!(formal_yuan.to_chinese(Variant::Traditional), "貳圓");
+                }
+
+                it "should still use 块 in everyday informal style" {
+                    let formal_yuan = Yuan {
+                        value: 2,
+                        style: CurrencyStyle::Everyday { formal: false },
+                        formal_yuan: true
+                    };
+
+                    eq!(formal_yuan.to_chinese(Variant::Simplified), "两块");
+                }
+            }
+
             describe "when converting zero" {
                 it "should work" {
                     eq!(
                         Yuan {
                             value: 0,
-                            style: CurrencyStyle::Everyday { formal: true }
+                            style: CurrencyStyle::Everyday { formal: true },
+                            formal_yuan: false
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零元".to_string(),
+                            logograms: "零元".into(),
                             omissible: true
                         }
                     );
@@ -126,11 +178,12 @@ mod tests {
                     eq!(
                         Yuan {
                             value: 0,
-                            style: CurrencyStyle::Everyday { formal: false }
+                            style: CurrencyStyle::Everyday { formal: false },
+                            formal_yuan: false
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零块".to_string(),
+                            logograms: "零块".into(),
                             omissible: true
                         }
                     );
@@ -138,11 +191,12 @@ mod tests {
                     eq!(
                         Yuan {
                             value: 0,
-                            style: CurrencyStyle::Financial
+                            style: CurrencyStyle::Financial,
+                            formal_yuan: false
                         }.to_chinese(Variant::Simplified),
 
                         Chinese {
-                            logograms: "零元".to_string(),
+                            logograms: "零元".into(),
                             omissible: true
                         }
                     );
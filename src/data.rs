@@ -0,0 +1,259 @@
+//! Data-size measures - such as `两百兆` or `三点五个G`.
+//!
+//! ```
+//! use chinese_format::{*, data::*};
+//!
+//! let download = DataSize {
+//!     value: 200,
+//!     unit: DataUnit::Megabyte,
+//!     style: DataStyle::Colloquial
+//! };
+//! assert_eq!(download.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "两百兆".into(),
+//!     omissible: false
+//! });
+//!
+//! let file = DataSize {
+//!     value: 1000,
+//!     unit: DataUnit::Byte,
+//!     style: DataStyle::Formal
+//! };
+//! assert_eq!(file.to_chinese(Variant::Simplified), "一千字节");
+//! assert_eq!(file.to_chinese(Variant::Traditional), "一千字節");
+//!
+//! let movie = DataSize {
+//!     value: 3584,
+//!     unit: DataUnit::Megabyte,
+//!     style: DataStyle::Colloquial
+//! };
+//! assert_eq!(movie.humanize().to_chinese(Variant::Simplified), "三点五个G");
+//! ```
+use crate::{Chinese, ChineseFormat, Count, CountBase, DigitGlyphSet, Glyphs, Variant};
+
+/// Binary unit of a [DataSize] - each unit being 1024 times the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DataUnit {
+    Byte,
+    Kilobyte,
+    Megabyte,
+    Gigabyte,
+    Terabyte,
+}
+
+const UNITS: [DataUnit; 5] = [
+    DataUnit::Byte,
+    DataUnit::Kilobyte,
+    DataUnit::Megabyte,
+    DataUnit::Gigabyte,
+    DataUnit::Terabyte,
+];
+
+impl DataUnit {
+    /// The number of bytes in one unit.
+    ///
+    /// ```
+    /// use chinese_format::data::*;
+    ///
+    /// assert_eq!(DataUnit::Byte.bytes(), 1);
+    /// assert_eq!(DataUnit::Megabyte.bytes(), 1_048_576);
+    /// ```
+    pub fn bytes(self) -> CountBase {
+        1024u128.pow(self as u32)
+    }
+
+    fn logograms(self, style: DataStyle) -> (&'static str, &'static str) {
+        match (self, style) {
+            (DataUnit::Byte, _) => ("字节", "字節"),
+            (DataUnit::Kilobyte, DataStyle::Formal) => ("千字节", "千字節"),
+            (DataUnit::Megabyte, DataStyle::Formal) => ("兆字节", "兆字節"),
+            (DataUnit::Gigabyte, DataStyle::Formal) => ("吉字节", "吉字節"),
+            (DataUnit::Terabyte, DataStyle::Formal) => ("太字节", "太字節"),
+            (DataUnit::Kilobyte, DataStyle::Colloquial) => ("K", "K"),
+            (DataUnit::Megabyte, DataStyle::Colloquial) => ("兆", "兆"),
+            (DataUnit::Gigabyte, DataStyle::Colloquial) => ("个G", "個G"),
+            (DataUnit::Terabyte, DataStyle::Colloquial) => ("个T", "個T"),
+        }
+    }
+}
+
+/// The register used for the units of a [DataSize].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DataStyle {
+    /// Standard units - such as `兆字节` - and plain numbers.
+    Formal,
+
+    /// Spoken units - such as `兆` and `个G` - with `两` before `百` and `千`.
+    #[default]
+    Colloquial,
+}
+
+/// Data size expressed in a given [DataUnit].
+///
+/// ```
+/// use chinese_format::{*, data::*};
+///
+/// let formal = DataSize {
+///     value: 200,
+///     unit: DataUnit::Megabyte,
+///     style: DataStyle::Formal
+/// };
+/// assert_eq!(formal.to_chinese(Variant::Simplified), "二百兆字节");
+///
+/// let gigabytes = DataSize {
+///     value: 2,
+///     unit: DataUnit::Gigabyte,
+///     style: DataStyle::Colloquial
+/// };
+/// assert_eq!(gigabytes.to_chinese(Variant::Simplified), "两个G");
+/// assert_eq!(gigabytes.to_chinese(Variant::Traditional), "兩個G");
+/// assert_eq!(gigabytes.bytes(), 2_147_483_648);
+///
+/// let zero = DataSize {
+///     value: 0,
+///     unit: DataUnit::Kilobyte,
+///     style: DataStyle::Colloquial
+/// };
+/// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零K".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DataSize {
+    pub value: CountBase,
+    pub unit: DataUnit,
+    pub style: DataStyle,
+}
+
+impl DataSize {
+    /// The total number of bytes.
+    pub fn bytes(&self) -> CountBase {
+        self.value * self.unit.bytes()
+    }
+
+    /// Expresses the size via the largest [DataUnit] it reaches,
+    /// rounded to one fractional digit.
+    ///
+    /// ```
+    /// use chinese_format::{*, data::*};
+    ///
+    /// let size = DataSize {
+    ///     value: 3_758_096_384,
+    ///     unit: DataUnit::Byte,
+    ///     style: DataStyle::Colloquial
+    /// };
+    /// assert_eq!(size.humanize().to_chinese(Variant::Simplified), Chinese {
+    ///     logograms: "三点五个G".into(),
+    ///     omissible: false
+    /// });
+    /// assert_eq!(size.humanize().to_chinese(Variant::Traditional), "三點五個G");
+    ///
+    /// let formal = DataSize {
+    ///     style: DataStyle::Formal,
+    ///     ..size
+    /// };
+    /// assert_eq!(formal.humanize().to_chinese(Variant::Simplified), "三点五吉字节");
+    ///
+    /// let exact = DataSize {
+    ///     value: 204_800,
+    ///     unit: DataUnit::Kilobyte,
+    ///     style: DataStyle::Colloquial
+    /// };
+    /// assert_eq!(exact.humanize().to_chinese(Variant::Simplified), "两百兆");
+    ///
+    /// //Rounding can reach the next unit
+    /// let almost = DataSize {
+    ///     value: 1_048_575,
+    ///     unit: DataUnit::Kilobyte,
+    ///     style: DataStyle::Colloquial
+    /// };
+    /// assert_eq!(almost.humanize().to_chinese(Variant::Simplified), "一个G");
+    ///
+    /// let small = DataSize {
+    ///     value: 500,
+    ///     unit: DataUnit::Byte,
+    ///     style: DataStyle::Colloquial
+    /// };
+    /// assert_eq!(small.humanize().to_chinese(Variant::Simplified), "五百字节");
+    /// ```
+    pub fn humanize(&self) -> HumanizedDataSize {
+        let bytes = self.bytes();
+
+        let (unit, tenths) = UNITS
+            .iter()
+            .rev()
+            .map(|unit| {
+                let factor = unit.bytes();
+                (*unit, (bytes * 10 + factor / 2) / factor)
+            })
+            .find(|(_, tenths)| *tenths >= 10)
+            .unwrap_or((DataUnit::Byte, bytes * 10));
+
+        HumanizedDataSize {
+            tenths,
+            unit,
+            style: self.style,
+        }
+    }
+}
+
+fn value_to_chinese(value: CountBase, style: DataStyle, variant: Variant) -> Chinese {
+    match style {
+        DataStyle::Formal => Count(value).to_chinese(variant),
+        DataStyle::Colloquial => Count(value).to_spoken_chinese(variant, DigitGlyphSet::STANDARD),
+    }
+}
+
+impl ChineseFormat for DataSize {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let value = value_to_chinese(self.value, self.style, variant);
+
+        Chinese {
+            logograms: format!(
+                "{}{}",
+                value.logograms,
+                self.unit.logograms(self.style).to_chinese(variant)
+            )
+            .into(),
+            omissible: value.omissible,
+        }
+    }
+}
+
+/// [DataSize] returned by [DataSize::humanize].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanizedDataSize {
+    tenths: CountBase,
+    unit: DataUnit,
+    style: DataStyle,
+}
+
+impl ChineseFormat for HumanizedDataSize {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let integer = self.tenths / 10;
+        let fractional = self.tenths % 10;
+
+        let unit = self.unit.logograms(self.style).to_chinese(variant);
+
+        if fractional == 0 {
+            let value = value_to_chinese(integer, self.style, variant);
+
+            return Chinese {
+                logograms: format!("{}{}", value.logograms, unit).into(),
+                omissible: value.omissible,
+            };
+        }
+
+        Chinese {
+            logograms: format!(
+                "{}{}{}{}",
+                integer.to_chinese(variant),
+                Glyphs::DIAN.to_chinese(variant),
+                fractional.to_chinese(variant),
+                unit
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::IntegerPart;
+use std::{error::Error, fmt::Display};
+
+/// Error for when the integer part of a [Decimal](crate::Decimal)
+/// cannot be expressed via the requested [CountMethod](crate::CountMethod).
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     IntegerPartOutOfRange(-90).to_string(),
+///     "Integer part out of range: -90"
+/// );
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IntegerPartOutOfRange(pub IntegerPart);
+
+impl Display for IntegerPartOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Integer part out of range: {}", self.0)
+    }
+}
+
+impl Error for IntegerPartOutOfRange {}
@@ -0,0 +1,573 @@
+mod errors;
+
+use crate::{
+    chinese_vec, integer_to_logograms, Chinese, ChineseFormat, CountMethod, DigitGlyphSet, Glyphs,
+    Sign, Variant,
+};
+use digit_sequence::DigitSequence;
+
+pub use errors::*;
+
+/// The integer part of a [Decimal].
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+pub type IntegerPart = i128;
+
+/// Accurate real number.
+///
+/// It supports *unbounded* **fractional** precision and length,
+/// but the **integer** part must be within the range of the
+/// [IntegerPart] type alias; negative numbers are supported.
+///
+/// ```
+/// use chinese_format::*;
+/// use digit_sequence::*;
+///
+/// let decimal = Decimal {
+///     integer: 96,
+///     fractional: 753u16.into()
+/// };
+/// assert_eq!(decimal.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "九十六点七五三".into(),
+///     omissible: false
+/// });
+/// assert_eq!(decimal.to_chinese(Variant::Traditional), Chinese {
+///     logograms: "九十六點七五三".into(),
+///     omissible: false
+/// });
+/// ```
+///
+/// It is worth noting that [Decimal] supports both equality and ordering:
+///
+/// ```
+/// use chinese_format::*;
+/// use digit_sequence::DigitSequence;
+///
+/// let ninety = Decimal {
+///     integer: 90,
+///     fractional: DigitSequence::new()
+/// };
+///
+///
+/// let ninety_dot_five = Decimal {
+///     integer: 90,
+///     fractional: 5u8.into()
+/// };
+///
+///
+/// let ninety_dot_five_seven = Decimal {
+///     integer: 90,
+///     fractional: 57u8.into()
+/// };
+///
+/// let ninety_two = Decimal {
+///     integer: 92,
+///     fractional: DigitSequence::new()
+/// };
+///
+/// assert_eq!(ninety, ninety);
+/// assert_ne!(ninety, ninety_dot_five);
+///
+/// assert!(ninety < ninety_dot_five);
+/// assert!(ninety_dot_five < ninety_dot_five_seven);
+/// assert!(ninety_dot_five_seven < ninety_two);
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal {
+    /// The digits *before* the decimal separator.
+    pub integer: IntegerPart,
+
+    /// The digits *after* the decimal separator.
+    pub fractional: DigitSequence,
+}
+
+impl Decimal {
+    /// Returns a copy of the decimal having *at most* the given number
+    /// of fractional digits - the others being simply dropped.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    /// use digit_sequence::DigitSequence;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let decimal = Decimal {
+    ///     integer: -3,
+    ///     fractional: 14159u16.into()
+    /// };
+    ///
+    /// assert_eq!(decimal.truncate(2), Decimal {
+    ///     integer: -3,
+    ///     fractional: 14u8.into()
+    /// });
+    ///
+    /// assert_eq!(decimal.truncate(0), Decimal {
+    ///     integer: -3,
+    ///     fractional: DigitSequence::new()
+    /// });
+    ///
+    /// assert_eq!(decimal.truncate(9), decimal);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn truncate(&self, fraction_digits: usize) -> Decimal {
+        let digits: Vec<u8> = self
+            .fractional
+            .iter()
+            .take(fraction_digits)
+            .copied()
+            .collect();
+
+        Decimal {
+            integer: self.integer,
+            fractional: digits
+                .try_into()
+                .expect("The digits come from a valid sequence"),
+        }
+    }
+
+    /// Returns a copy of the decimal having *at most* the given number
+    /// of fractional digits, rounding half away from zero; the carry
+    /// can propagate to the integer part.
+    ///
+    /// The kept fractional digits are not trimmed, even when they are zeros.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    /// use digit_sequence::DigitSequence;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let pi = Decimal {
+    ///     integer: 3,
+    ///     fractional: 14159u16.into()
+    /// };
+    /// assert_eq!(pi.round(2)?, Decimal {
+    ///     integer: 3,
+    ///     fractional: 14u8.into()
+    /// });
+    /// assert_eq!(pi.round(3)?, Decimal {
+    ///     integer: 3,
+    ///     fractional: 142u8.into()
+    /// });
+    ///
+    /// let almost_ten = Decimal {
+    ///     integer: -9,
+    ///     fractional: 96u8.into()
+    /// };
+    /// assert_eq!(almost_ten.round(1)?, Decimal {
+    ///     integer: -10,
+    ///     fractional: "0".parse()?
+    /// });
+    /// assert_eq!(almost_ten.round(0)?, Decimal {
+    ///     integer: -10,
+    ///     fractional: DigitSequence::new()
+    /// });
+    ///
+    /// let short = Decimal {
+    ///     integer: 7,
+    ///     fractional: 5u8.into()
+    /// };
+    /// assert_eq!(short.round(4)?, short);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// It fails only when the carry would overflow the integer part:
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// let max = Decimal {
+    ///     integer: IntegerPart::MAX,
+    ///     fractional: 5u8.into()
+    /// };
+    ///
+    /// assert_eq!(max.round(0), Err(IntegerPartOutOfRange(IntegerPart::MAX)));
+    /// ```
+    pub fn round(&self, fraction_digits: usize) -> Result<Decimal, IntegerPartOutOfRange> {
+        let round_up = self
+            .fractional
+            .iter()
+            .nth(fraction_digits)
+            .is_some_and(|digit| *digit >= 5);
+
+        let truncated = self.truncate(fraction_digits);
+
+        if !round_up {
+            return Ok(truncated);
+        }
+
+        let mut digits: Vec<u8> = truncated.fractional.iter().copied().collect();
+        let mut carry = true;
+
+        for digit in digits.iter_mut().rev() {
+            if *digit == 9 {
+                *digit = 0;
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+
+        let integer = if carry {
+            if self.integer < 0 {
+                self.integer.checked_sub(1)
+            } else {
+                self.integer.checked_add(1)
+            }
+            .ok_or(IntegerPartOutOfRange(self.integer))?
+        } else {
+            self.integer
+        };
+
+        Ok(Decimal {
+            integer,
+            fractional: digits
+                .try_into()
+                .expect("The digits come from a valid sequence"),
+        })
+    }
+}
+
+const COMMA: Glyphs = Glyphs::DIAN;
+
+impl Decimal {
+    /// Whether the value is 0 - whatever the number of fractional digits.
+    pub(crate) fn is_zero(&self) -> bool {
+        self.integer == 0 && self.fractional.iter().all(|digit| *digit == 0)
+    }
+
+    /// Converts the absolute value to [Chinese] - without negating the integer part,
+    /// which would overflow for [IntegerPart::MIN].
+    pub(crate) fn magnitude_to_chinese(&self, variant: Variant) -> Chinese {
+        let magnitude = self.integer.unsigned_abs();
+
+        if self.fractional.is_empty() {
+            magnitude.to_chinese(variant)
+        } else {
+            chinese_vec!(variant, [magnitude, COMMA, self.fractional]).collect()
+        }
+    }
+}
+
+/// [Decimal] can be translated to [Chinese].
+///
+/// ```
+/// use chinese_format::*;
+/// use digit_sequence::*;
+///
+/// let integer_only = Decimal {
+///     integer: 90,
+///     fractional: DigitSequence::new()
+/// };
+/// assert_eq!(integer_only.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "九十".into(),
+///     omissible: false
+/// });
+/// assert_eq!(integer_only.to_chinese(Variant::Traditional), Chinese {
+///     logograms: "九十".into(),
+///     omissible: false
+/// });
+///
+/// let zero = Decimal {
+///     integer: 0,
+///     fractional: DigitSequence::new()
+/// };
+/// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+/// assert_eq!(zero.to_chinese(Variant::Traditional), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+///
+/// let integer_and_decimal = Decimal {
+///     integer: 35,
+///     fractional: 28039u16.into()
+/// };
+/// assert_eq!(integer_and_decimal.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三十五点二八零三九".into(),
+///     omissible: false
+/// });
+/// assert_eq!(integer_and_decimal.to_chinese(Variant::Traditional), Chinese {
+///     logograms: "三十五點二八零三九".into(),
+///     omissible: false
+/// });
+///
+/// let zero_comma = Decimal {
+///     integer: 0,
+///     fractional: 9052u16.into()
+/// };
+/// assert_eq!(zero_comma.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零点九零五二".into(),
+///     omissible: false
+/// });
+/// assert_eq!(zero_comma.to_chinese(Variant::Traditional), Chinese {
+///     logograms: "零點九零五二".into(),
+///     omissible: false
+/// });
+///
+/// let negative = Decimal {
+///     integer: -487,
+///     fractional: 309u16.into()
+/// };
+/// assert_eq!(negative.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "负四百八十七点三零九".into(),
+///     omissible: false
+/// });
+/// assert_eq!(negative.to_chinese(Variant::Traditional), Chinese {
+///     logograms: "負四百八十七點三零九".into(),
+///     omissible: false
+/// });
+/// ```
+impl ChineseFormat for Decimal {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_with_glyphs(variant, DigitGlyphSet::STANDARD)
+    }
+
+    fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+        let integer = self.integer.to_chinese_with_glyphs(variant, glyphs);
+
+        if self.fractional.is_empty() {
+            integer
+        } else {
+            chinese_vec!(
+                variant,
+                [
+                    integer.logograms,
+                    COMMA,
+                    self.fractional
+                        .to_chinese_with_glyphs(variant, glyphs)
+                        .logograms
+                ]
+            )
+            .collect()
+        }
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        crate::pinyin::number_tokens(
+            &self.to_chinese(variant).logograms,
+            variant,
+            DigitGlyphSet::STANDARD,
+        )
+    }
+}
+
+/// How the integer part of a [Decimal] is read.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IntegerReading {
+    /// As a number, grouping the digits according to the given [CountMethod].
+    Number(CountMethod),
+
+    /// Digit by digit - as in codes and years.
+    DigitByDigit,
+}
+
+/// Options affecting the conversion of a [Decimal] to [Chinese],
+/// applied via [StyledDecimal].
+///
+/// The default style reads the integer part as a number,
+/// with [CountMethod::TenThousand], and keeps all the fractional digits -
+/// exactly like [Decimal] itself.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecimalStyle {
+    /// How the digits *before* the decimal separator are read.
+    pub integer_reading: IntegerReading,
+
+    /// If set, the decimal is [rounded](Decimal::round) to the given number of fractional digits.
+    pub max_fraction_digits: Option<usize>,
+}
+
+impl Default for DecimalStyle {
+    fn default() -> Self {
+        Self {
+            integer_reading: IntegerReading::Number(CountMethod::TenThousand),
+            max_fraction_digits: None,
+        }
+    }
+}
+
+/// A [Decimal] combined with a [DecimalStyle].
+///
+/// Must be created via [try_new](Self::try_new), which ensures that the
+/// integer part can be expressed in the requested style.
+///
+/// ```
+/// use chinese_format::*;
+/// use digit_sequence::DigitSequence;
+///
+/// # fn main() -> GenericResult<()> {
+/// let year_like = Decimal {
+///     integer: 2024,
+///     fractional: 5u8.into()
+/// };
+///
+/// let digit_by_digit = StyledDecimal::try_new(&year_like, DecimalStyle {
+///     integer_reading: IntegerReading::DigitByDigit,
+///     ..DecimalStyle::default()
+/// })?;
+/// assert_eq!(digit_by_digit.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "二零二四点五".into(),
+///     omissible: false
+/// });
+/// assert_eq!(digit_by_digit.to_chinese(Variant::Traditional), "二零二四點五");
+///
+/// let negative = Decimal {
+///     integer: -305,
+///     fractional: DigitSequence::new()
+/// };
+/// let negative_digits = StyledDecimal::try_new(&negative, DecimalStyle {
+///     integer_reading: IntegerReading::DigitByDigit,
+///     ..DecimalStyle::default()
+/// })?;
+/// assert_eq!(negative_digits.to_chinese(Variant::Simplified), "负三零五");
+///
+/// let large = Decimal {
+///     integer: 1_000_000,
+///     fractional: 7u8.into()
+/// };
+///
+/// let ten_thousand = StyledDecimal::try_new(&large, DecimalStyle::default())?;
+/// assert_eq!(ten_thousand.to_chinese(Variant::Simplified), "一百万点七");
+///
+/// let low = StyledDecimal::try_new(&large, DecimalStyle {
+///     integer_reading: IntegerReading::Number(CountMethod::Low),
+///     ..DecimalStyle::default()
+/// })?;
+/// assert_eq!(low.to_chinese(Variant::Simplified), "一兆点七");
+///
+/// let zero = Decimal {
+///     integer: 0,
+///     fractional: DigitSequence::new()
+/// };
+/// let styled_zero = StyledDecimal::try_new(&zero, DecimalStyle {
+///     integer_reading: IntegerReading::DigitByDigit,
+///     ..DecimalStyle::default()
+/// })?;
+/// assert_eq!(styled_zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+///
+/// let from_float = Decimal {
+///     integer: 0,
+///     fractional: "30000000000000004".parse()?
+/// };
+/// let rounded = StyledDecimal::try_new(&from_float, DecimalStyle {
+///     max_fraction_digits: Some(2),
+///     ..DecimalStyle::default()
+/// })?;
+/// assert_eq!(rounded.to_chinese(Variant::Simplified), "零点三零");
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StyledDecimal {
+    decimal: Decimal,
+    style: DecimalStyle,
+}
+
+impl StyledDecimal {
+    /// Tries to apply the given style to a [Decimal] - failing with
+    /// [IntegerPartOutOfRange] if the integer part cannot be read
+    /// via the requested [CountMethod].
+    ///
+    /// If [max_fraction_digits](DecimalStyle::max_fraction_digits) is set,
+    /// the decimal is [rounded](Decimal::round) right away.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    /// use digit_sequence::*;
+    ///
+    /// let huge = Decimal {
+    ///     integer: 10_000_000_000_000_000,
+    ///     fractional: DigitSequence::new()
+    /// };
+    ///
+    /// let low_result = StyledDecimal::try_new(&huge, DecimalStyle {
+    ///     integer_reading: IntegerReading::Number(CountMethod::Low),
+    ///     ..DecimalStyle::default()
+    /// });
+    /// assert_eq!(low_result, Err(IntegerPartOutOfRange(10_000_000_000_000_000)));
+    ///
+    /// let ten_thousand_result = StyledDecimal::try_new(&huge, DecimalStyle::default());
+    /// assert!(ten_thousand_result.is_ok());
+    /// ```
+    pub fn try_new(
+        decimal: &Decimal,
+        style: DecimalStyle,
+    ) -> Result<StyledDecimal, IntegerPartOutOfRange> {
+        let decimal = match style.max_fraction_digits {
+            Some(max_fraction_digits) => decimal.round(max_fraction_digits)?,
+            None => decimal.clone(),
+        };
+
+        if let IntegerReading::Number(count_method) = style.integer_reading {
+            integer_to_logograms(decimal.integer, Variant::Simplified, count_method)
+                .ok_or(IntegerPartOutOfRange(decimal.integer))?;
+        }
+
+        Ok(Self { decimal, style })
+    }
+
+    /// The [Decimal] that will be converted - already rounded according to the style.
+    pub fn decimal(&self) -> &Decimal {
+        &self.decimal
+    }
+
+    /// The [DecimalStyle] applied to the [decimal](Self::decimal).
+    pub fn style(&self) -> DecimalStyle {
+        self.style
+    }
+
+    fn integer_to_chinese(&self, variant: Variant) -> Chinese {
+        let integer = self.decimal.integer;
+
+        match self.style.integer_reading {
+            IntegerReading::Number(count_method) => Chinese {
+                logograms: integer_to_logograms(integer, variant, count_method)
+                    .expect("The integer part is validated on construction")
+                    .into(),
+                omissible: integer == 0,
+            },
+
+            IntegerReading::DigitByDigit => {
+                let digits: DigitSequence = integer.unsigned_abs().into();
+
+                Chinese {
+                    omissible: integer == 0,
+                    ..chinese_vec!(variant, [Sign(integer), digits]).collect()
+                }
+            }
+        }
+    }
+}
+
+impl ChineseFormat for StyledDecimal {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let integer_chinese = self.integer_to_chinese(variant);
+
+        if self.decimal.fractional.is_empty() {
+            integer_chinese
+        } else {
+            chinese_vec!(
+                variant,
+                [integer_chinese.as_str(), COMMA, self.decimal.fractional]
+            )
+            .collect()
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{Chinese, ChineseFormat, Variant};
+
+/// The logograms used to write numbers - consulted by the integers, [Count](crate::Count),
+/// [Financial](crate::Financial) and digit-by-digit readings via
+/// [to_chinese_with_glyphs](ChineseFormat::to_chinese_with_glyphs).
+///
+/// Each logogram is a `(simplified, traditional)` pair.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(DigitGlyphSet::STANDARD.digit(2, Variant::Simplified), '二');
+/// assert_eq!(DigitGlyphSet::STANDARD.counted_two(Variant::Traditional), '兩');
+///
+/// assert_eq!(DigitGlyphSet::FINANCIAL.digit(6, Variant::Simplified), '陆');
+/// assert_eq!(DigitGlyphSet::FINANCIAL.digit(6, Variant::Traditional), '陸');
+///
+/// assert_eq!(DigitGlyphSet::CIRCLE_ZERO.digit(0, Variant::Simplified), '〇');
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DigitGlyphSet {
+    /// The digits from 0 to 9.
+    pub digits: [(char, char); 10],
+
+    /// The units for 10, 100 and 1000.
+    pub small_units: [(char, char); 3],
+
+    /// The logogram for the quantity 2 - as in `两个`.
+    pub counted_two: (char, char),
+}
+
+impl DigitGlyphSet {
+    /// The standard set: `零`, `一`, ... `九`, with `两` for quantities.
+    pub const STANDARD: DigitGlyphSet = DigitGlyphSet {
+        digits: [
+            ('零', '零'),
+            ('一', '一'),
+            ('二', '二'),
+            ('三', '三'),
+            ('四', '四'),
+            ('五', '五'),
+            ('六', '六'),
+            ('七', '七'),
+            ('八', '八'),
+            ('九', '九'),
+        ],
+        small_units: [('十', '十'), ('百', '百'), ('千', '千')],
+        counted_two: ('两', '兩'),
+    };
+
+    /// Like [STANDARD](Self::STANDARD), but with `〇` for zero - as widely used in print.
+    pub const CIRCLE_ZERO: DigitGlyphSet = DigitGlyphSet {
+        digits: [
+            ('〇', '〇'),
+            ('一', '一'),
+            ('二', '二'),
+            ('三', '三'),
+            ('四', '四'),
+            ('五', '五'),
+            ('六', '六'),
+            ('七', '七'),
+            ('八', '八'),
+            ('九', '九'),
+        ],
+        ..Self::STANDARD
+    };
+
+    /// Like [STANDARD](Self::STANDARD), but with `二` for quantities, too.
+    pub const PLAIN_TWO: DigitGlyphSet = DigitGlyphSet {
+        counted_two: ('二', '二'),
+        ..Self::STANDARD
+    };
+
+    /// The military reading - also adopted in aviation and radio communications -
+    /// avoiding the digits that sound alike: `洞` for `0`, `幺` for `1`, `两`/`兩` for `2`,
+    /// `拐` for `7` and `勾` for `9`.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let digits = DigitReading::try_from("1234567890")?;
+    /// assert_eq!(
+    ///     digits.to_chinese_with_glyphs(Variant::Simplified, DigitGlyphSet::MILITARY),
+    ///     "幺两三四五六拐八勾洞"
+    /// );
+    /// assert_eq!(DigitGlyphSet::MILITARY.digit(2, Variant::Traditional), '兩');
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const MILITARY: DigitGlyphSet = DigitGlyphSet {
+        digits: [
+            ('洞', '洞'),
+            ('幺', '幺'),
+            ('两', '兩'),
+            ('三', '三'),
+            ('四', '四'),
+            ('五', '五'),
+            ('六', '六'),
+            ('拐', '拐'),
+            ('八', '八'),
+            ('勾', '勾'),
+        ],
+        ..Self::STANDARD
+    };
+
+    /// Like [STANDARD](Self::STANDARD), but with the `幺` of [MILITARY](Self::MILITARY)
+    /// for `1` - as usual when reading phone numbers.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// assert_eq!(DigitGlyphSet::YAO.digit(1, Variant::Simplified), '幺');
+    /// assert_eq!(DigitGlyphSet::YAO.digit(7, Variant::Simplified), '七');
+    /// ```
+    pub const YAO: DigitGlyphSet = {
+        let mut digits = Self::STANDARD.digits;
+        digits[1] = Self::MILITARY.digits[1];
+
+        DigitGlyphSet {
+            digits,
+            ..Self::STANDARD
+        }
+    };
+
+    /// Suzhou numerals (`苏州码子`) - `〇`, `〡`, `〢`, ... `〩`, with `〸` for ten -
+    /// still found in traditional bookkeeping and market displays.
+    ///
+    /// They fit digit-by-digit readings best, but can also write integers:
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let price = WithGlyphs {
+    ///     value: DigitReading::try_from("1805")?,
+    ///     glyphs: DigitGlyphSet::SUZHOU
+    /// };
+    /// assert_eq!(price.to_chinese(Variant::Simplified), "〡〨〇〥");
+    /// assert_eq!(price.to_chinese(Variant::Traditional), "〡〨〇〥");
+    ///
+    /// let quantity = WithGlyphs {
+    ///     value: 312,
+    ///     glyphs: DigitGlyphSet::SUZHOU
+    /// };
+    /// assert_eq!(quantity.to_chinese(Variant::Simplified), "〣百〡〸〢");
+    ///
+    /// let distance = WithGlyphs {
+    ///     value: length::Kilometer::new(2),
+    ///     glyphs: DigitGlyphSet::SUZHOU
+    /// };
+    /// assert_eq!(distance.to_chinese(Variant::Traditional), "〢公里");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const SUZHOU: DigitGlyphSet = DigitGlyphSet {
+        digits: [
+            ('〇', '〇'),
+            ('〡', '〡'),
+            ('〢', '〢'),
+            ('〣', '〣'),
+            ('〤', '〤'),
+            ('〥', '〥'),
+            ('〦', '〦'),
+            ('〧', '〧'),
+            ('〨', '〨'),
+            ('〩', '〩'),
+        ],
+        small_units: [('〸', '〸'), ('百', '百'), ('千', '千')],
+        counted_two: ('〢', '〢'),
+    };
+
+    /// The financial set - `零`, `壹`, `贰`, ... `玖`, with `拾`, `佰` and `仟` -
+    /// designed to prevent falsification.
+    pub const FINANCIAL: DigitGlyphSet = DigitGlyphSet {
+        digits: [
+            ('零', '零'),
+            ('壹', '壹'),
+            ('贰', '貳'),
+            ('叁', '參'),
+            ('肆', '肆'),
+            ('伍', '伍'),
+            ('陆', '陸'),
+            ('柒', '柒'),
+            ('捌', '捌'),
+            ('玖', '玖'),
+        ],
+        small_units: [('拾', '拾'), ('佰', '佰'), ('仟', '仟')],
+        counted_two: ('贰', '貳'),
+    };
+
+    fn pick((simplified, traditional): (char, char), variant: Variant) -> char {
+        match variant {
+            Variant::Simplified => simplified,
+            Variant::Traditional => traditional,
+        }
+    }
+
+    /// The logogram of the given digit.
+    ///
+    /// **Panics** if the digit is greater than 9.
+    pub fn digit(&self, digit: u8, variant: Variant) -> char {
+        Self::pick(self.digits[digit as usize], variant)
+    }
+
+    /// The logogram of the unit for 10, 100 or 1000 - at index 0, 1 or 2.
+    ///
+    /// **Panics** if the index is greater than 2.
+    pub fn small_unit(&self, index: usize, variant: Variant) -> char {
+        Self::pick(self.small_units[index], variant)
+    }
+
+    /// The logogram for the quantity 2.
+    pub fn counted_two(&self, variant: Variant) -> char {
+        Self::pick(self.counted_two, variant)
+    }
+}
+
+/// The default [DigitGlyphSet] is [DigitGlyphSet::STANDARD].
+impl Default for DigitGlyphSet {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// Renders the wrapped value via the given [DigitGlyphSet] - by calling its
+/// [to_chinese_with_glyphs](ChineseFormat::to_chinese_with_glyphs) - preserving
+/// its [omissible](Chinese::omissible) property.
+///
+/// Only the numbers are affected - any other text being left unchanged.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let printed = WithGlyphs {
+///     value: 105,
+///     glyphs: DigitGlyphSet::CIRCLE_ZERO
+/// };
+/// assert_eq!(printed.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一百〇五".into(),
+///     omissible: false
+/// });
+///
+/// let receipt = WithGlyphs {
+///     value: ("三个一等奖", "三個一等獎"),
+///     glyphs: DigitGlyphSet::FINANCIAL
+/// };
+/// assert_eq!(receipt.to_chinese(Variant::Simplified), "三个一等奖");
+///
+/// let radio = WithGlyphs {
+///     glyphs: DigitGlyphSet::MILITARY,
+///     ..receipt
+/// };
+/// assert_eq!(radio.to_chinese(Variant::Traditional), "三個一等獎");
+///
+/// let prizes = WithGlyphs {
+///     value: Count(2),
+///     glyphs: DigitGlyphSet::FINANCIAL
+/// };
+/// assert_eq!(prizes.to_chinese(Variant::Traditional), "貳");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WithGlyphs<T: ChineseFormat> {
+    /// The value to render.
+    pub value: T,
+
+    /// The glyphs used to write the numbers of the value.
+    pub glyphs: DigitGlyphSet,
+}
+
+impl<T: ChineseFormat> ChineseFormat for WithGlyphs<T> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.value.to_chinese_with_glyphs(variant, self.glyphs)
+    }
+}
@@ -0,0 +1,429 @@
+use crate::{Chinese, ChineseFormat, DigitGlyphSet, Variant};
+use std::{error::Error, fmt::Display};
+
+/// Error for when a character is not an ASCII digit.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     InvalidDigit('x').to_string(),
+///     "Invalid digit: x"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidDigit(pub char);
+
+impl Display for InvalidDigit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid digit: {}", self.0)
+    }
+}
+
+impl Error for InvalidDigit {}
+
+/// Strict digit-by-digit reading - as required by verification codes,
+/// lottery numbers and dice - guaranteed to only use `零`, `一`, `二`, ... `九`:
+/// in particular, `2` is always read as `二`, never as `两`/`兩`.
+///
+/// It can be obtained from a string of ASCII digits - keeping the leading zeros -
+/// or from any unsigned integer:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let code = DigitReading::try_from("022")?;
+/// assert_eq!(code.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零二二".into(),
+///     omissible: false
+/// });
+/// assert_eq!(code.to_chinese(Variant::Traditional), "零二二");
+/// assert_eq!(code.digits(), &[0, 2, 2]);
+///
+/// let lottery = DigitReading::from(2_222u32).with_separator(' ');
+/// assert_eq!(lottery.to_chinese(Variant::Traditional), "二 二 二 二");
+///
+/// assert_eq!(DigitReading::try_from("12a4"), Err(InvalidDigit('a')));
+///
+/// let empty = DigitReading::try_from("")?;
+/// assert_eq!(empty.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DigitReading {
+    digits: Vec<u8>,
+    separator: Option<char>,
+}
+
+impl DigitReading {
+    /// Sets the character inserted between two digits.
+    pub fn with_separator(self, separator: char) -> Self {
+        Self {
+            separator: Some(separator),
+            ..self
+        }
+    }
+
+    /// The digits - each in the 0..=9 range.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    /// Concatenates the glyphs of the digits - interleaved by the separator, if any.
+    fn read_digits(&self, glyph: impl Fn(u8) -> char) -> Chinese {
+        let mut logograms = String::new();
+
+        for (index, digit) in self.digits.iter().enumerate() {
+            if index > 0 {
+                if let Some(separator) = self.separator {
+                    logograms.push(separator);
+                }
+            }
+
+            logograms.push(glyph(*digit));
+        }
+
+        Chinese {
+            omissible: logograms.is_empty(),
+            logograms: logograms.into(),
+        }
+    }
+}
+
+/// [DigitReading] can be obtained from a [str] containing ASCII digits only.
+impl TryFrom<&str> for DigitReading {
+    type Error = InvalidDigit;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits = value
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or(InvalidDigit(c))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            digits,
+            separator: None,
+        })
+    }
+}
+
+macro_rules! impl_digit_reading_from_unsigned {
+    ($($type: ty),+) => {
+        $(
+            impl From<$type> for DigitReading {
+                fn from(value: $type) -> Self {
+                    Self::try_from(value.to_string().as_str())
+                        .expect("Unsigned integers only contain digits")
+                }
+            }
+        )+
+    };
+}
+
+impl_digit_reading_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl ChineseFormat for DigitReading {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_with_glyphs(variant, DigitGlyphSet::STANDARD)
+    }
+
+    fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+        self.read_digits(|digit| glyphs.digit(digit, variant))
+    }
+}
+
+/// How an [OtpReading] - or any other digit-by-digit reading - reads its digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ReadingStyle {
+    /// `零`, `一`, `二`, ... `九` - just like [DigitReading].
+    #[default]
+    Standard,
+
+    /// Like [Standard](Self::Standard), but with `幺` for `1` - as usual on the phone -
+    /// via [DigitGlyphSet::YAO].
+    Yao,
+
+    /// The military reading, avoiding the digits that sound alike -
+    /// via [DigitGlyphSet::MILITARY].
+    Military,
+}
+
+impl ReadingStyle {
+    /// The glyphs of the digits.
+    pub fn glyphs(&self) -> DigitGlyphSet {
+        match self {
+            Self::Standard => DigitGlyphSet::STANDARD,
+            Self::Yao => DigitGlyphSet::YAO,
+            Self::Military => DigitGlyphSet::MILITARY,
+        }
+    }
+
+    pub(crate) fn digit(&self, digit: u8, variant: Variant) -> char {
+        self.glyphs().digit(digit, variant)
+    }
+}
+
+/// Verification code - or one-time password - read digit by digit,
+/// optionally replacing the digits that sound alike according to its [ReadingStyle].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let code = DigitReading::try_from("170892")?;
+///
+/// let standard = OtpReading {
+///     code: code.clone(),
+///     style: ReadingStyle::Standard
+/// };
+/// assert_eq!(standard.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一七零八九二".into(),
+///     omissible: false
+/// });
+///
+/// let yao = OtpReading {
+///     code: code.clone(),
+///     style: ReadingStyle::Yao
+/// };
+/// assert_eq!(yao.to_chinese(Variant::Simplified), "幺七零八九二");
+///
+/// let military = OtpReading {
+///     code: code.with_separator(' '),
+///     style: ReadingStyle::Military
+/// };
+/// assert_eq!(military.to_chinese(Variant::Simplified), "幺 拐 洞 八 勾 两");
+/// assert_eq!(military.to_chinese(Variant::Traditional), "幺 拐 洞 八 勾 兩");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OtpReading {
+    pub code: DigitReading,
+    pub style: ReadingStyle,
+}
+
+impl ChineseFormat for OtpReading {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.code
+            .read_digits(|digit| self.style.digit(digit, variant))
+    }
+}
+
+/// How the masked digits of a [MaskedNumber] are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum MaskStyle {
+    /// Each run of masked digits - within a group - is read as `星号`/`星號`.
+    #[default]
+    Stars,
+
+    /// Only the digits after the last masked one are read, introduced by `尾号`/`尾號`.
+    ///
+    /// When no digit follows the last masked one, the number is read as in [MaskStyle::Stars].
+    Skipped,
+}
+
+/// Bank card or account number - possibly masked via `*` or `X` - read in groups of 4 digits,
+/// as in customer-service phrasing.
+///
+/// Spaces and dashes in the source string are ignored.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let card = MaskedNumber::try_from("6222 **** **** 4321")?;
+/// assert_eq!(card.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "六二二二 星号 星号 四三二一".into(),
+///     omissible: false
+/// });
+/// assert_eq!(card.to_chinese(Variant::Traditional), "六二二二 星號 星號 四三二一");
+///
+/// let tail = card.clone().with_mask_style(MaskStyle::Skipped);
+/// assert_eq!(tail.to_chinese(Variant::Simplified), "尾号四三二一");
+/// assert_eq!(tail.to_chinese(Variant::Traditional), "尾號四三二一");
+///
+/// let spoken = card.with_reading_style(ReadingStyle::Yao).with_separator('，');
+/// assert_eq!(spoken.to_chinese(Variant::Simplified), "六二二二，星号，星号，四三二幺");
+///
+/// let partial = MaskedNumber::try_from("1234-56XX-XXXX-7890")?;
+/// assert_eq!(partial.to_chinese(Variant::Simplified), "一二三四 五六星号 星号 七八九零");
+///
+/// let account = MaskedNumber::try_from("12345678")?;
+/// assert_eq!(account.to_chinese(Variant::Simplified), "一二三四 五六七八");
+/// assert_eq!(
+///     account.with_mask_style(MaskStyle::Skipped).to_chinese(Variant::Simplified),
+///     "一二三四 五六七八"
+/// );
+///
+/// assert_eq!(MaskedNumber::try_from("6222#4321"), Err(InvalidDigit('#')));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaskedNumber {
+    digits: Vec<Option<u8>>,
+    mask_style: MaskStyle,
+    reading_style: ReadingStyle,
+    separator: char,
+}
+
+impl MaskedNumber {
+    const GROUP_SIZE: usize = 4;
+
+    const STARS: (&'static str, &'static str) = ("星号", "星號");
+
+    const TAIL: (&'static str, &'static str) = ("尾号", "尾號");
+
+    /// Sets how the masked digits are read.
+    pub fn with_mask_style(self, mask_style: MaskStyle) -> Self {
+        Self { mask_style, ..self }
+    }
+
+    /// Sets how the visible digits are read.
+    pub fn with_reading_style(self, reading_style: ReadingStyle) -> Self {
+        Self {
+            reading_style,
+            ..self
+        }
+    }
+
+    /// Sets the character inserted between two groups - a space by default.
+    pub fn with_separator(self, separator: char) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// The digits - [None] for the masked ones.
+    pub fn digits(&self) -> &[Option<u8>] {
+        &self.digits
+    }
+
+    fn read_group(&self, group: &[Option<u8>], variant: Variant) -> String {
+        let mut logograms = String::new();
+        let mut previous_masked = false;
+
+        for digit in group {
+            match digit {
+                Some(digit) => {
+                    logograms.push(self.reading_style.digit(*digit, variant));
+                    previous_masked = false;
+                }
+
+                None if !previous_masked => {
+                    logograms.push_str(&Self::STARS.to_chinese(variant).logograms);
+                    previous_masked = true;
+                }
+
+                None => {}
+            }
+        }
+
+        logograms
+    }
+
+    fn read_groups(&self, digits: &[Option<u8>], variant: Variant) -> String {
+        digits
+            .chunks(Self::GROUP_SIZE)
+            .map(|group| self.read_group(group, variant))
+            .collect::<Vec<_>>()
+            .join(&self.separator.to_string())
+    }
+}
+
+/// [MaskedNumber] can be obtained from a [str] containing ASCII digits and masks - `*`, `X` or `x`.
+impl TryFrom<&str> for MaskedNumber {
+    type Error = InvalidDigit;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits = value
+            .chars()
+            .filter(|c| *c != ' ' && *c != '-')
+            .map(|c| match c {
+                '*' | 'X' | 'x' => Ok(None),
+                _ => c
+                    .to_digit(10)
+                    .map(|digit| Some(digit as u8))
+                    .ok_or(InvalidDigit(c)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            digits,
+            mask_style: MaskStyle::default(),
+            reading_style: ReadingStyle::default(),
+            separator: ' ',
+        })
+    }
+}
+
+impl ChineseFormat for MaskedNumber {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let last_masked = self.digits.iter().rposition(Option::is_none);
+
+        let logograms = match (self.mask_style, last_masked) {
+            (MaskStyle::Skipped, Some(last_masked)) if last_masked + 1 < self.digits.len() => {
+                format!(
+                    "{}{}",
+                    Self::TAIL.to_chinese(variant),
+                    self.read_group(&self.digits[last_masked + 1..], variant)
+                )
+            }
+
+            _ => self.read_groups(&self.digits, variant),
+        };
+
+        Chinese {
+            omissible: self.digits.is_empty(),
+            logograms: logograms.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Reading digits strictly" {
+            it "should never use 两" {
+                let reading = DigitReading::from(1_234_567_890u64);
+
+                eq!(reading.to_chinese(Variant::Simplified), "一二三四五六七八九零");
+                eq!(reading.to_chinese(Variant::Traditional), "一二三四五六七八九零");
+            }
+
+            it "should reject non-ASCII digits" {
+                eq!(DigitReading::try_from("１２"), Err(InvalidDigit('１')));
+            }
+        }
+
+        describe "Reading a masked number with skipped masks" {
+            it "should read the mask when no digit follows it" {
+                let number = MaskedNumber::try_from("6222 4321 ****")
+                    .unwrap()
+                    .with_mask_style(MaskStyle::Skipped);
+
+                eq!(number.to_chinese(Variant::Simplified), "六二二二 四三二一 星号");
+                eq!(number.to_chinese(Variant::Traditional), "六二二二 四三二一 星號");
+            }
+
+            it "should read the mask when every digit is masked" {
+                let number = MaskedNumber::try_from("****")
+                    .unwrap()
+                    .with_mask_style(MaskStyle::Skipped);
+
+                eq!(number.to_chinese(Variant::Simplified), "星号");
+            }
+        }
+    }
+}
@@ -1,22 +1,5 @@
-use crate::{Chinese, ChineseFormat, Variant};
+use crate::{Chinese, ChineseFormat, DigitGlyphSet, Variant};
 use digit_sequence::DigitSequence;
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-lazy_static! {
-    static ref CHINESE_DIGITS: HashMap<u8, char> = HashMap::from([
-        (0, '零'),
-        (1, '一'),
-        (2, '二'),
-        (3, '三'),
-        (4, '四'),
-        (5, '五'),
-        (6, '六'),
-        (7, '七'),
-        (8, '八'),
-        (9, '九'),
-    ]);
-}
 
 /// Any [DigitSequence] is infallibly convertible to a sequence of Chinese digits from 零 to 九.
 ///
@@ -31,7 +14,7 @@ lazy_static! {
 /// //Non-empty sequence
 /// let sequence: DigitSequence = "9876543210123456789".parse()?;
 /// assert_eq!(sequence.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "九八七六五四三二一零一二三四五六七八九".to_string(),
+///     logograms: "九八七六五四三二一零一二三四五六七八九".into(),
 ///     omissible: false
 /// });
 /// assert_eq!(sequence.to_chinese(Variant::Traditional), "九八七六五四三二一零一二三四五六七八九");
@@ -39,7 +22,7 @@ lazy_static! {
 /// //Empty sequence
 /// let empty_sequence = DigitSequence::new();
 /// assert_eq!(empty_sequence.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "".to_string(),
+///     logograms: "".into(),
 ///     omissible: true
 /// });
 /// assert_eq!(empty_sequence.to_chinese(Variant::Traditional), "");
@@ -47,11 +30,11 @@ lazy_static! {
 /// //Sequence containing 0
 /// let zero_sequence = "0".parse::<DigitSequence>()?;
 /// assert_eq!(zero_sequence.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "零".to_string(),
+///     logograms: "零".into(),
 ///     omissible: false
 /// });
 /// assert_eq!(zero_sequence.to_chinese(Variant::Traditional), Chinese {
-///     logograms: "零".to_string(),
+///     logograms: "零".into(),
 ///     omissible: false
 /// });
 ///
@@ -61,15 +44,228 @@ lazy_static! {
 ///
 /// **REQUIRED FEATURE**: `digit-sequence`.
 impl ChineseFormat for DigitSequence {
-    fn to_chinese(&self, _variant: Variant) -> Chinese {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_with_glyphs(variant, DigitGlyphSet::STANDARD)
+    }
+
+    fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
         let logograms: String = self
             .iter()
-            .map(|digit| CHINESE_DIGITS[digit])
+            .map(|digit| glyphs.digit(*digit, variant))
             .collect::<String>();
 
         Chinese {
             omissible: logograms.is_empty(),
-            logograms,
+            logograms: logograms.into(),
+        }
+    }
+}
+
+/// How the digits of a [DigitSequenceFormat] are split into groups.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DigitGrouping<'a> {
+    /// Groups of the given size, starting from the left; `0` disables grouping.
+    Every(usize),
+
+    /// Groups of the given sizes, starting from the left; any digits
+    /// exceeding the pattern are kept together in a final group.
+    Pattern(&'a [usize]),
+}
+
+impl DigitGrouping<'static> {
+    /// The 3-4-4 pattern of Chinese mobile phone numbers.
+    pub const MOBILE_PHONE: DigitGrouping<'static> = DigitGrouping::Pattern(&[3, 4, 4]);
+
+    /// Groups of 4 digits - the usual choice for long codes and card numbers.
+    pub const FOUR_BY_FOUR: DigitGrouping<'static> = DigitGrouping::Every(4);
+}
+
+impl<'a> DigitGrouping<'a> {
+    fn group_lengths(&self, digit_count: usize) -> Vec<usize> {
+        let mut result = vec![];
+        let mut remaining = digit_count;
+
+        match self {
+            Self::Every(0) => {}
+
+            Self::Every(size) => {
+                while remaining > *size {
+                    result.push(*size);
+                    remaining -= size;
+                }
+            }
+
+            Self::Pattern(sizes) => {
+                for size in sizes.iter().filter(|size| **size > 0) {
+                    if remaining <= *size {
+                        break;
+                    }
+
+                    result.push(*size);
+                    remaining -= size;
+                }
+            }
+        }
+
+        if remaining > 0 {
+            result.push(remaining);
+        }
+
+        result
+    }
+}
+
+/// Reads a [DigitSequence] digit by digit - just like its own conversion
+/// to Chinese, but via the given [DigitGlyphSet] - inserting a separator
+/// between groups of digits, to make long sequences more readable.
+///
+/// The result is [omissible](Chinese::omissible) only when the sequence is empty.
+///
+/// ```
+/// use chinese_format::*;
+/// use digit_sequence::DigitSequence;
+///
+/// # fn main() -> GenericResult<()> {
+/// let phone: DigitSequence = "13812345678".parse()?;
+///
+/// let phone_format = DigitSequenceFormat {
+///     sequence: &phone,
+///     grouping: DigitGrouping::MOBILE_PHONE,
+///     separator: ' ',
+///     glyphs: DigitGlyphSet::STANDARD
+/// };
+/// assert_eq!(phone_format.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一三八 一二三四 五六七八".into(),
+///     omissible: false
+/// });
+///
+/// let code: DigitSequence = "6222020200112233".parse()?;
+///
+/// let code_format = DigitSequenceFormat {
+///     sequence: &code,
+///     grouping: DigitGrouping::Every(4),
+///     separator: '，',
+///     glyphs: DigitGlyphSet::STANDARD
+/// };
+/// assert_eq!(code_format.to_chinese(Variant::Traditional), "六二二二，零二零二，零零一一，二二三三");
+///
+/// //Digits exceeding the pattern are kept together
+/// let long_code: DigitSequence = "1234567".parse()?;
+///
+/// let long_code_format = DigitSequenceFormat {
+///     sequence: &long_code,
+///     grouping: DigitGrouping::Pattern(&[2, 2]),
+///     separator: '-',
+///     glyphs: DigitGlyphSet::STANDARD
+/// };
+/// assert_eq!(long_code_format.to_chinese(Variant::Simplified), "一二-三四-五六七");
+///
+/// //〇 is widely used in print
+/// let zip_code: DigitSequence = "100080".parse()?;
+///
+/// let zip_code_format = DigitSequenceFormat {
+///     sequence: &zip_code,
+///     grouping: DigitGrouping::Every(0),
+///     separator: ' ',
+///     glyphs: DigitGlyphSet::CIRCLE_ZERO
+/// };
+/// assert_eq!(zip_code_format.to_chinese(Variant::Simplified), "一〇〇〇八〇");
+///
+/// //Empty sequence
+/// let empty = DigitSequence::new();
+///
+/// let empty_format = DigitSequenceFormat {
+///     sequence: &empty,
+///     grouping: DigitGrouping::Every(4),
+///     separator: ' ',
+///     glyphs: DigitGlyphSet::STANDARD
+/// };
+/// assert_eq!(empty_format.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DigitSequenceFormat<'a> {
+    /// The digits to read.
+    pub sequence: &'a DigitSequence,
+
+    /// How digits are grouped.
+    pub grouping: DigitGrouping<'a>,
+
+    /// The character inserted between two groups.
+    pub separator: char,
+
+    /// The logograms of the digits - such as [DigitGlyphSet::CIRCLE_ZERO].
+    pub glyphs: DigitGlyphSet,
+}
+
+impl<'a> ChineseFormat for DigitSequenceFormat<'a> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let digits: Vec<char> = self
+            .sequence
+            .iter()
+            .map(|digit| self.glyphs.digit(*digit, variant))
+            .collect();
+
+        let mut groups: Vec<String> = vec![];
+        let mut start = 0;
+
+        for length in self.grouping.group_lengths(digits.len()) {
+            groups.push(digits[start..start + length].iter().collect());
+            start += length;
+        }
+
+        Chinese {
+            logograms: groups.join(&self.separator.to_string()).into(),
+            omissible: digits.is_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Digit grouping" {
+            describe "when splitting by fixed size" {
+                it "should keep the remainder in the last group" {
+                    eq!(DigitGrouping::Every(4).group_lengths(10), vec![4, 4, 2]);
+                }
+
+                it "should not create empty groups" {
+                    eq!(DigitGrouping::Every(4).group_lengths(8), vec![4, 4]);
+                    eq!(DigitGrouping::Every(4).group_lengths(0), Vec::<usize>::new());
+                }
+
+                it "should disable grouping for size 0" {
+                    eq!(DigitGrouping::Every(0).group_lengths(5), vec![5]);
+                }
+            }
+
+            describe "when splitting by pattern" {
+                it "should follow the pattern" {
+                    eq!(DigitGrouping::MOBILE_PHONE.group_lengths(11), vec![3, 4, 4]);
+                }
+
+                it "should stop early for short sequences" {
+                    eq!(DigitGrouping::MOBILE_PHONE.group_lengths(5), vec![3, 2]);
+                }
+
+                it "should skip zero-sized groups" {
+                    eq!(DigitGrouping::Pattern(&[0, 2]).group_lengths(3), vec![2, 1]);
+                }
+            }
         }
     }
 }
@@ -0,0 +1,132 @@
+//! Numbering of official documents - such as `国发〔2024〕12号` - issued by
+//! government agencies and enterprises.
+//!
+//! As prescribed by the standard layout of official documents, the year and the
+//! serial number are written in Arabic digits - without leading zeros.
+//!
+//! ```
+//! use chinese_format::{*, documents::*};
+//!
+//! let number = DocumentNumber::new(&("国发", "國發"), 2024, 12);
+//!
+//! assert_eq!(number.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "国发〔2024〕12号".into(),
+//!     omissible: false
+//! });
+//! assert_eq!(number.to_chinese(Variant::Traditional), "國發〔2024〕12號");
+//! ```
+use crate::{chinese_vec, Chinese, ChineseFormat, Glyphs, Variant};
+
+/// Brackets surrounding the year of a [DocumentNumber].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DocumentBrackets {
+    /// `〔〕` - the standard choice.
+    #[default]
+    Tortoiseshell,
+
+    /// `【】`
+    Lenticular,
+
+    /// `（）`
+    Parentheses,
+
+    /// `［］`
+    Square,
+}
+
+impl DocumentBrackets {
+    /// The opening and closing brackets.
+    pub fn pair(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Tortoiseshell => ("〔", "〕"),
+            Self::Lenticular => ("【", "】"),
+            Self::Parentheses => ("（", "）"),
+            Self::Square => ("［", "］"),
+        }
+    }
+}
+
+/// Document number - made of the issuer code, the bracketed year
+/// and the serial number followed by `号`/`號`.
+///
+/// The serial number can also be introduced by `第`, as in `第12号`:
+///
+/// ```
+/// use chinese_format::{*, documents::*};
+///
+/// let number = DocumentNumber {
+///     ordinal: true,
+///     ..DocumentNumber::new(&"京政办发", 2023, 7)
+/// };
+/// assert_eq!(number.to_chinese(Variant::Simplified), "京政办发〔2023〕第7号");
+///
+/// let lenticular = DocumentNumber {
+///     brackets: DocumentBrackets::Lenticular,
+///     ..number
+/// };
+/// assert_eq!(lenticular.to_chinese(Variant::Traditional), "京政办发【2023】第7號");
+///
+/// let parentheses = DocumentNumber {
+///     brackets: DocumentBrackets::Parentheses,
+///     ..DocumentNumber::new(&"财办", 2024, 105)
+/// };
+/// assert_eq!(parentheses.to_chinese(Variant::Simplified), "财办（2024）105号");
+///
+/// let square = DocumentNumber {
+///     brackets: DocumentBrackets::Square,
+///     ..DocumentNumber::new(&"", 2022, 1)
+/// };
+/// assert_eq!(square.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "［2022］1号".into(),
+///     omissible: false
+/// });
+/// ```
+#[derive(Clone, Copy)]
+pub struct DocumentNumber<'a> {
+    /// The code of the issuing body - such as `国发`.
+    pub issuer: &'a dyn ChineseFormat,
+
+    pub year: u16,
+
+    pub serial: u32,
+
+    pub brackets: DocumentBrackets,
+
+    /// Whether the serial number is introduced by `第`.
+    pub ordinal: bool,
+}
+
+impl<'a> DocumentNumber<'a> {
+    /// Creates a [DocumentNumber] with the default [DocumentBrackets]
+    /// and no `第` before the serial number.
+    pub fn new(issuer: &'a dyn ChineseFormat, year: u16, serial: u32) -> Self {
+        Self {
+            issuer,
+            year,
+            serial,
+            brackets: DocumentBrackets::default(),
+            ordinal: false,
+        }
+    }
+}
+
+impl<'a> ChineseFormat for DocumentNumber<'a> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let (opening, closing) = self.brackets.pair();
+
+        let serial = format!("{}{}", if self.ordinal { "第" } else { "" }, self.serial);
+
+        chinese_vec!(
+            variant,
+            [
+                self.issuer,
+                opening,
+                self.year.to_string(),
+                closing,
+                serial,
+                Glyphs::HAO
+            ]
+        )
+        .collect()
+    }
+}
@@ -0,0 +1,577 @@
+//! Time spans - such as `三个月` - including the half-units of everyday speech,
+//! like `半小时`, `一个半月` or `三年半`.
+//!
+//! ```
+//! use chinese_format::{*, duration::*};
+//!
+//! let wait = TimeDuration::half(2, DurationUnit::Hour);
+//! assert_eq!(wait.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "两个半小时".into(),
+//!     omissible: false
+//! });
+//!
+//! assert_eq!(Age::half(3).to_chinese(Variant::Simplified), "三岁半");
+//!
+//! let uptime: SpokenDuration = std::time::Duration::from_secs(12_000).into();
+//! assert_eq!(uptime.to_chinese(Variant::Simplified), "三小时二十分钟");
+//!
+//! assert_eq!(RelativeTime::new(-3 * 86_400).to_chinese(Variant::Simplified), "三天前");
+//! ```
+use crate::{chinese_vec, Chinese, ChineseFormat, Count, CountBase, Variant};
+use std::time::Duration;
+
+const BAN: &str = "半";
+
+const GE: (&str, &str) = ("个", "個");
+
+/// The unit of a [TimeDuration].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DurationUnit {
+    /// `年`
+    Year,
+
+    /// `个月`/`個月`
+    Month,
+
+    /// `个星期`/`個星期`
+    Week,
+
+    /// `天`
+    Day,
+
+    /// `个小时`/`個小時`
+    Hour,
+
+    /// `刻钟`/`刻鐘` - a quarter of an hour.
+    QuarterHour,
+
+    /// `分钟`/`分鐘`
+    Minute,
+
+    /// `秒`
+    Second,
+}
+
+impl DurationUnit {
+    /// The units that can be selected by [RelativeTime], from the largest.
+    const RELATIVE_UNITS: [Self; 7] = [
+        Self::Year,
+        Self::Month,
+        Self::Week,
+        Self::Day,
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+    ];
+
+    /// The length of the unit in seconds - approximated for years (365 days)
+    /// and months (30 days).
+    pub fn seconds(self) -> u64 {
+        match self {
+            Self::Year => 365 * 86_400,
+            Self::Month => 30 * 86_400,
+            Self::Week => 7 * 86_400,
+            Self::Day => 86_400,
+            Self::Hour => 3_600,
+            Self::QuarterHour => 900,
+            Self::Minute => 60,
+            Self::Second => 1,
+        }
+    }
+
+    /// The units that can be selected by [SpokenDuration], from the largest,
+    /// each with its length in seconds.
+    const EXACT_UNITS: [(Self, u64); 4] = [
+        (Self::Day, 86_400),
+        (Self::Hour, 3_600),
+        (Self::Minute, 60),
+        (Self::Second, 1),
+    ];
+
+    /// The unit split around the position of `半` - as in `个半月` or `分半钟`.
+    fn parts(self) -> ((&'static str, &'static str), (&'static str, &'static str)) {
+        match self {
+            Self::Year => (("年", "年"), ("", "")),
+            Self::Month => (GE, ("月", "月")),
+            Self::Week => (GE, ("星期", "星期")),
+            Self::Day => (("天", "天"), ("", "")),
+            Self::Hour => (GE, ("小时", "小時")),
+            Self::QuarterHour => (("刻", "刻"), ("钟", "鐘")),
+            Self::Minute => (("分", "分"), ("钟", "鐘")),
+            Self::Second => (("秒", "秒"), ("", "")),
+        }
+    }
+}
+
+/// Amount of time in a given [DurationUnit], possibly followed by a half unit.
+///
+/// `半` is placed between the classifier and the noun - as in `一个半月` or `两分半钟` -
+/// and after units without classifier - as in `三年半`; a lone half unit becomes
+/// `半个月`, `半天` or, idiomatically, `半小时`.
+///
+/// ```
+/// use chinese_format::{*, duration::*};
+///
+/// let months = TimeDuration::new(3, DurationUnit::Month);
+/// assert_eq!(months.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三个月".into(),
+///     omissible: false
+/// });
+/// assert_eq!(months.to_chinese(Variant::Traditional), "三個月");
+///
+/// let one_and_a_half = TimeDuration::half(1, DurationUnit::Month);
+/// assert_eq!(one_and_a_half.to_chinese(Variant::Simplified), "一个半月");
+///
+/// let hours = TimeDuration::half(2, DurationUnit::Hour);
+/// assert_eq!(hours.to_chinese(Variant::Simplified), "两个半小时");
+/// assert_eq!(hours.to_chinese(Variant::Traditional), "兩個半小時");
+///
+/// assert_eq!(TimeDuration::half(3, DurationUnit::Year).to_chinese(Variant::Simplified), "三年半");
+/// assert_eq!(TimeDuration::half(2, DurationUnit::Minute).to_chinese(Variant::Simplified), "两分半钟");
+/// assert_eq!(TimeDuration::new(3, DurationUnit::QuarterHour).to_chinese(Variant::Traditional), "三刻鐘");
+/// assert_eq!(TimeDuration::half(4, DurationUnit::Day).to_chinese(Variant::Simplified), "四天半");
+///
+/// assert_eq!(TimeDuration::half(0, DurationUnit::Hour).to_chinese(Variant::Simplified), "半小时");
+/// assert_eq!(TimeDuration::half(0, DurationUnit::Month).to_chinese(Variant::Simplified), "半个月");
+/// assert_eq!(TimeDuration::half(0, DurationUnit::Year).to_chinese(Variant::Simplified), "半年");
+/// assert_eq!(TimeDuration::half(0, DurationUnit::Minute).to_chinese(Variant::Traditional), "半分鐘");
+///
+/// assert_eq!(TimeDuration::new(0, DurationUnit::Day).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零天".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeDuration {
+    pub value: CountBase,
+    pub unit: DurationUnit,
+
+    /// Whether half a unit is added to the value.
+    pub half: bool,
+}
+
+impl TimeDuration {
+    /// Duration consisting of whole units only.
+    pub fn new(value: CountBase, unit: DurationUnit) -> Self {
+        Self {
+            value,
+            unit,
+            half: false,
+        }
+    }
+
+    /// Duration consisting of the given whole units plus half a unit.
+    pub fn half(value: CountBase, unit: DurationUnit) -> Self {
+        Self {
+            value,
+            unit,
+            half: true,
+        }
+    }
+}
+
+impl ChineseFormat for TimeDuration {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let (head, tail) = self.unit.parts();
+
+        //半小时 is far more common than 半个小时
+        let lone_half_head = match self.unit {
+            DurationUnit::Hour => ("", ""),
+            _ => head,
+        };
+
+        with_half(self.value, self.half, variant, (head, tail), lone_half_head)
+    }
+}
+
+/// [TimeDuration] automatically selected for a [std::time::Duration] -
+/// for human-friendly uptime and ETA displays.
+///
+/// The largest unit among days, hours, minutes and seconds is chosen; the remainder,
+/// if any, is expressed as `半` when it is exactly half a unit - as in `两天半` -
+/// or otherwise via the next smaller unit, truncating the rest; the seconds are rounded,
+/// saturating at [u64::MAX]:
+///
+/// ```
+/// use chinese_format::{*, duration::*};
+/// use std::time::Duration;
+///
+/// let eta: SpokenDuration = Duration::from_secs(3 * 3600 + 20 * 60 + 15).into();
+/// assert_eq!(eta.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三小时二十分钟".into(),
+///     omissible: false
+/// });
+/// assert_eq!(eta.to_chinese(Variant::Traditional), "三小時二十分鐘");
+/// assert_eq!(eta.major, TimeDuration::new(3, DurationUnit::Hour));
+/// assert_eq!(eta.minor, Some(TimeDuration::new(20, DurationUnit::Minute)));
+///
+/// let days: SpokenDuration = Duration::from_secs(2 * 86_400 + 12 * 3600).into();
+/// assert_eq!(days.to_chinese(Variant::Simplified), "两天半");
+///
+/// let hours: SpokenDuration = Duration::from_secs(5400).into();
+/// assert_eq!(hours.to_chinese(Variant::Simplified), "一个半小时");
+///
+/// let whole: SpokenDuration = Duration::from_secs(7200).into();
+/// assert_eq!(whole.to_chinese(Variant::Simplified), "两个小时");
+///
+/// let seconds: SpokenDuration = Duration::from_millis(42_600).into();
+/// assert_eq!(seconds.to_chinese(Variant::Simplified), "四十三秒");
+///
+/// let longest: SpokenDuration = Duration::MAX.into();
+/// assert_eq!(longest.major, TimeDuration::new(213_503_982_334_601, DurationUnit::Day));
+/// assert_eq!(longest.minor, Some(TimeDuration::new(7, DurationUnit::Hour)));
+///
+/// let zero: SpokenDuration = Duration::ZERO.into();
+/// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零秒".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SpokenDuration {
+    /// The amount in the largest unit - possibly including a half unit.
+    pub major: TimeDuration,
+
+    /// The remainder, in the next smaller unit.
+    pub minor: Option<TimeDuration>,
+}
+
+impl From<Duration> for SpokenDuration {
+    fn from(duration: Duration) -> Self {
+        let seconds = duration
+            .as_secs()
+            .saturating_add(u64::from(duration.subsec_millis() >= 500));
+
+        let (index, (unit, unit_seconds)) = DurationUnit::EXACT_UNITS
+            .into_iter()
+            .enumerate()
+            .find(|(_, (_, unit_seconds))| seconds >= *unit_seconds)
+            .unwrap_or((
+                DurationUnit::EXACT_UNITS.len() - 1,
+                (DurationUnit::Second, 1),
+            ));
+
+        let value = (seconds / unit_seconds) as CountBase;
+        let remainder = seconds % unit_seconds;
+
+        if remainder > 0 && remainder * 2 == unit_seconds {
+            return Self {
+                major: TimeDuration::half(value, unit),
+                minor: None,
+            };
+        }
+
+        let minor = DurationUnit::EXACT_UNITS
+            .get(index + 1)
+            .map(|(minor_unit, minor_seconds)| {
+                TimeDuration::new((remainder / minor_seconds) as CountBase, *minor_unit)
+            })
+            .filter(|minor| minor.value > 0);
+
+        Self {
+            major: TimeDuration::new(value, unit),
+            minor,
+        }
+    }
+}
+
+/// A [chrono::TimeDelta] is read by its magnitude - so negative deltas are spoken
+/// just like positive ones - via the same unit selection as [Duration]:
+///
+/// ```
+/// use chinese_format::{*, duration::*};
+/// use chrono::TimeDelta;
+///
+/// let eta: SpokenDuration = TimeDelta::minutes(200).into();
+/// assert_eq!(eta.to_chinese(Variant::Simplified), "三小时二十分钟");
+///
+/// let elapsed: SpokenDuration = TimeDelta::hours(-60).into();
+/// assert_eq!(elapsed.to_chinese(Variant::Simplified), "两天半");
+///
+/// let longest: SpokenDuration = TimeDelta::MIN.into();
+/// assert_eq!(longest, TimeDelta::MAX.into());
+/// ```
+#[cfg(feature = "chrono")]
+impl From<chrono::TimeDelta> for SpokenDuration {
+    fn from(delta: chrono::TimeDelta) -> Self {
+        delta
+            .abs()
+            .to_std()
+            .expect("The magnitude of a TimeDelta is never negative")
+            .into()
+    }
+}
+
+impl ChineseFormat for SpokenDuration {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self.minor {
+            None => self.major.to_chinese(variant),
+
+            Some(minor) => {
+                //三小时二十分钟 is more common than 三个小时二十分钟
+                let major = match self.major.unit {
+                    DurationUnit::Hour => Chinese {
+                        logograms: format!(
+                            "{}{}",
+                            Count(self.major.value).to_chinese(variant),
+                            ("小时", "小時").to_chinese(variant)
+                        )
+                        .into(),
+                        omissible: false,
+                    },
+                    _ => self.major.to_chinese(variant),
+                };
+
+                chinese_vec!(variant, [major.logograms, minor]).collect()
+            }
+        }
+    }
+}
+
+/// How a [RelativeTime] in the future is expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FutureStyle {
+    /// `…后`/`…後` - as in `两个小时后`.
+    #[default]
+    After,
+
+    /// `还有…`/`還有…` - as in the countdown `还有三天`.
+    Remaining,
+}
+
+/// Options for a [RelativeTime].
+///
+/// By default, the smallest unit is the minute, and any offset shorter than
+/// a minute is expressed as `刚刚` or `马上`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelativeTimeStyle {
+    /// The smallest unit that can be selected; shorter offsets are expressed as
+    /// `刚刚`/`马上`. [DurationUnit::QuarterHour] is never selected, behaving like [DurationUnit::Hour].
+    pub granularity: DurationUnit,
+
+    /// Offsets whose absolute value, in seconds, is less than this threshold
+    /// are expressed as `刚刚`/`马上`.
+    pub just_now_threshold: u64,
+
+    pub future: FutureStyle,
+}
+
+impl Default for RelativeTimeStyle {
+    fn default() -> Self {
+        Self {
+            granularity: DurationUnit::Minute,
+            just_now_threshold: 60,
+            future: FutureStyle::After,
+        }
+    }
+}
+
+/// Time relative to now, from a signed offset in seconds - negative in the past -
+/// as in the classic "time ago" widget.
+///
+/// The largest fitting unit is selected - truncating the value - and the result
+/// is followed by `前` in the past, or by `后`/`後` in the future:
+///
+/// ```
+/// use chinese_format::{*, duration::*};
+///
+/// let past = RelativeTime::new(-3 * 86_400 - 7200);
+/// assert_eq!(past.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三天前".into(),
+///     omissible: false
+/// });
+///
+/// let future = RelativeTime::new(2 * 3600 + 59);
+/// assert_eq!(future.to_chinese(Variant::Simplified), "两个小时后");
+/// assert_eq!(future.to_chinese(Variant::Traditional), "兩個小時後");
+///
+/// assert_eq!(RelativeTime::new(-5 * 60).to_chinese(Variant::Simplified), "五分钟前");
+/// assert_eq!(RelativeTime::new(-40 * 86_400).to_chinese(Variant::Simplified), "一个月前");
+/// assert_eq!(RelativeTime::new(-800 * 86_400).to_chinese(Variant::Traditional), "兩年前");
+///
+/// assert_eq!(RelativeTime::new(-20).to_chinese(Variant::Simplified), "刚刚");
+/// assert_eq!(RelativeTime::new(0).to_chinese(Variant::Traditional), "剛剛");
+/// assert_eq!(RelativeTime::new(20).to_chinese(Variant::Simplified), "马上");
+/// assert_eq!(RelativeTime::new(20).to_chinese(Variant::Traditional), "馬上");
+/// ```
+///
+/// Granularity, thresholds and the future phrasing can be customized via [RelativeTimeStyle]:
+///
+/// ```
+/// use chinese_format::{*, duration::*};
+/// use std::time::Duration;
+///
+/// let countdown = RelativeTime::future(Duration::from_secs(3 * 86_400 + 5)).with_style(RelativeTimeStyle {
+///     future: FutureStyle::Remaining,
+///     ..RelativeTimeStyle::default()
+/// });
+/// assert_eq!(countdown.to_chinese(Variant::Simplified), "还有三天");
+/// assert_eq!(countdown.to_chinese(Variant::Traditional), "還有三天");
+///
+/// let by_day = RelativeTimeStyle {
+///     granularity: DurationUnit::Day,
+///     ..RelativeTimeStyle::default()
+/// };
+/// assert_eq!(RelativeTime::past(Duration::from_secs(5 * 3600)).with_style(by_day).to_chinese(Variant::Simplified), "刚刚");
+/// assert_eq!(RelativeTime::past(Duration::from_secs(86_400)).with_style(by_day).to_chinese(Variant::Simplified), "一天前");
+///
+/// let precise = RelativeTimeStyle {
+///     granularity: DurationUnit::Second,
+///     just_now_threshold: 10,
+///     ..RelativeTimeStyle::default()
+/// };
+/// assert_eq!(RelativeTime::new(-42).with_style(precise).to_chinese(Variant::Simplified), "四十二秒前");
+/// assert_eq!(RelativeTime::new(-9).with_style(precise).to_chinese(Variant::Simplified), "刚刚");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelativeTime {
+    /// The offset from now, in seconds - negative in the past.
+    pub seconds: i64,
+
+    pub style: RelativeTimeStyle,
+}
+
+impl RelativeTime {
+    /// Creates a [RelativeTime] with the default [RelativeTimeStyle].
+    pub fn new(seconds: i64) -> Self {
+        Self {
+            seconds,
+            style: RelativeTimeStyle::default(),
+        }
+    }
+
+    /// The given [Duration] ago - saturating at [i64::MIN] seconds.
+    pub fn past(duration: Duration) -> Self {
+        Self::new(i64::try_from(duration.as_secs()).map_or(i64::MIN, |seconds| -seconds))
+    }
+
+    /// The given [Duration] from now - saturating at [i64::MAX] seconds.
+    pub fn future(duration: Duration) -> Self {
+        Self::new(i64::try_from(duration.as_secs()).unwrap_or(i64::MAX))
+    }
+
+    pub fn with_style(self, style: RelativeTimeStyle) -> Self {
+        Self { style, ..self }
+    }
+
+    /// The selected whole amount, if the offset is not close enough to now.
+    fn amount(&self) -> Option<TimeDuration> {
+        let magnitude = self.seconds.unsigned_abs();
+
+        if magnitude < self.style.just_now_threshold {
+            return None;
+        }
+
+        DurationUnit::RELATIVE_UNITS
+            .into_iter()
+            .filter(|unit| unit.seconds() >= self.style.granularity.seconds())
+            .find(|unit| magnitude >= unit.seconds())
+            .map(|unit| TimeDuration::new((magnitude / unit.seconds()) as CountBase, unit))
+    }
+}
+
+impl ChineseFormat for RelativeTime {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let is_future = self.seconds > 0;
+
+        match (self.amount(), is_future, self.style.future) {
+            (None, false, _) => ("刚刚", "剛剛").to_chinese(variant),
+
+            (None, true, _) => ("马上", "馬上").to_chinese(variant),
+
+            (Some(amount), false, _) => chinese_vec!(variant, [amount, "前"]).collect(),
+
+            (Some(amount), true, FutureStyle::After) => {
+                chinese_vec!(variant, [amount, ("后", "後")]).collect()
+            }
+
+            (Some(amount), true, FutureStyle::Remaining) => {
+                chinese_vec!(variant, [("还有", "還有"), amount]).collect()
+            }
+        }
+    }
+}
+
+/// Age of a person, in years, possibly followed by a half year - as in `三岁半`.
+///
+/// ```
+/// use chinese_format::{*, duration::*};
+///
+/// assert_eq!(Age::new(2).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "两岁".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Age::new(2).to_chinese(Variant::Traditional), "兩歲");
+///
+/// assert_eq!(Age::half(3).to_chinese(Variant::Simplified), "三岁半");
+/// assert_eq!(Age::half(3).to_chinese(Variant::Traditional), "三歲半");
+///
+/// assert_eq!(Age::half(0).to_chinese(Variant::Simplified), "半岁");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Age {
+    pub years: CountBase,
+
+    /// Whether half a year is added to the age.
+    pub half: bool,
+}
+
+impl Age {
+    pub fn new(years: CountBase) -> Self {
+        Self { years, half: false }
+    }
+
+    pub fn half(years: CountBase) -> Self {
+        Self { years, half: true }
+    }
+}
+
+impl ChineseFormat for Age {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let head = ("岁", "歲");
+
+        with_half(self.years, self.half, variant, (head, ("", "")), head)
+    }
+}
+
+fn with_half(
+    value: CountBase,
+    half: bool,
+    variant: Variant,
+    (head, tail): ((&str, &str), (&str, &str)),
+    lone_half_head: (&str, &str),
+) -> Chinese {
+    let head_logograms = head.to_chinese(variant).logograms;
+    let tail_logograms = tail.to_chinese(variant).logograms;
+
+    if !half {
+        let count = Count(value).to_chinese(variant);
+
+        return Chinese {
+            logograms: format!("{}{}{}", count.logograms, head_logograms, tail_logograms).into(),
+            omissible: count.omissible,
+        };
+    }
+
+    let logograms = if value == 0 {
+        format!(
+            "{}{}{}",
+            BAN,
+            lone_half_head.to_chinese(variant),
+            tail_logograms
+        )
+    } else {
+        format!(
+            "{}{}{}{}",
+            Count(value).to_chinese(variant),
+            head_logograms,
+            BAN,
+            tail_logograms
+        )
+    };
+
+    Chinese {
+        logograms: logograms.into(),
+        omissible: false,
+    }
+}
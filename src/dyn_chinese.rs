@@ -0,0 +1,70 @@
+use crate::{ChineseFormat, Count};
+
+/// Owned, thread-safe [ChineseFormat] trait object - ideal for storing
+/// heterogeneous formatters, for example in application registries.
+///
+/// Common types can be converted via [From]/[Into]:
+///
+/// ```
+/// use chinese_format::*;
+/// use std::collections::HashMap;
+///
+/// let mut registry: HashMap<&str, DynChinese> = HashMap::new();
+///
+/// registry.insert("greeting", "你好".into());
+/// registry.insert("guests", Count(2).into());
+/// registry.insert("floor", 7.into());
+/// registry.insert("pet", ("猫", "貓").into());
+/// registry.insert("room", Box::new(Placeholder::ling(Count(0))));
+///
+/// assert_eq!(registry["greeting"].to_chinese(Variant::Simplified), "你好");
+/// assert_eq!(registry["guests"].to_chinese(Variant::Traditional), Chinese {
+///     logograms: "兩".into(),
+///     omissible: false
+/// });
+/// assert_eq!(registry["floor"].to_chinese(Variant::Simplified), "七");
+/// assert_eq!(registry["pet"].to_chinese(Variant::Traditional), "貓");
+/// assert_eq!(registry["room"].to_chinese(Variant::Simplified), "零");
+/// assert_eq!(
+///     registry["guests"].to_chinese_with_glyphs(Variant::Simplified, DigitGlyphSet::FINANCIAL),
+///     Count(2).to_chinese_with_glyphs(Variant::Simplified, DigitGlyphSet::FINANCIAL)
+/// );
+///
+/// let registry_for_threads = std::sync::Arc::new(registry);
+///
+/// std::thread::spawn(move || {
+///     assert_eq!(registry_for_threads["guests"].to_chinese(Variant::Simplified), "两");
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+pub type DynChinese = Box<dyn ChineseFormat + Send + Sync>;
+
+macro_rules! impl_from_for_dyn_chinese {
+    ($($type: ty),+) => {
+        $(
+            impl From<$type> for DynChinese {
+                fn from(value: $type) -> Self {
+                    Box::new(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_for_dyn_chinese!(
+    &'static str,
+    String,
+    (&'static str, &'static str),
+    Count,
+    u128,
+    u64,
+    u32,
+    u16,
+    u8,
+    i128,
+    i64,
+    i32,
+    i16,
+    i8
+);
@@ -0,0 +1,188 @@
+//! School scores and grades - such as `九十五分`, `满分` or `A等` - for education apps.
+//!
+//! In this context, `分` is a point of a score - unrelated to minutes or cents.
+//!
+//! ```
+//! use chinese_format::{*, education::*};
+//!
+//! let score = Score::new(95).with_full_marks(100);
+//! assert_eq!(score.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "九十五分".into(),
+//!     omissible: false
+//! });
+//!
+//! let perfect = Score::new(100).with_full_marks(100);
+//! let sentence = chinese_vec!(Variant::Simplified, ["得了", perfect]).collect();
+//! assert_eq!(sentence, "得了满分");
+//!
+//! assert!(score.is_passing(PassingLine(60)));
+//! assert_eq!(PassingLine(60).to_chinese(Variant::Traditional), "及格線六十分");
+//!
+//! assert_eq!(Grade::Letter('A').to_chinese(Variant::Simplified), "A等");
+//! ```
+use crate::{define_measure, Chinese, ChineseFormat, Count, SemanticUnit, Variant};
+
+define_measure!(pub, Points, pub, Count, SemanticUnit::Point);
+
+/// Score obtained in a test - as in `九十五分` - possibly reaching the full marks (`满分`/`滿分`).
+///
+/// ```
+/// use chinese_format::{*, education::*};
+///
+/// assert_eq!(Score::new(2).to_chinese(Variant::Simplified), "两分");
+///
+/// assert_eq!(Score::new(150).to_chinese(Variant::Simplified), "一百五十分");
+///
+/// let full = Score::new(150).with_full_marks(150);
+/// assert_eq!(full.to_chinese(Variant::Simplified), "满分");
+/// assert_eq!(full.to_chinese(Variant::Traditional), "滿分");
+/// assert!(full.is_full_marks());
+///
+/// assert_eq!(Score::new(0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零分".into(),
+///     omissible: false
+/// });
+///
+/// assert!(!Score::new(59).is_passing(PassingLine(60)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Score {
+    points: u16,
+    full_marks: Option<u16>,
+}
+
+impl Score {
+    const FULL_MARKS: (&'static str, &'static str) = ("满分", "滿分");
+
+    /// Creates a score having no full marks.
+    pub fn new(points: u16) -> Self {
+        Self {
+            points,
+            full_marks: None,
+        }
+    }
+
+    /// Sets the greatest score of the test - read as `满分`/`滿分` when reached.
+    pub fn with_full_marks(self, full_marks: u16) -> Self {
+        Self {
+            full_marks: Some(full_marks),
+            ..self
+        }
+    }
+
+    pub fn points(&self) -> u16 {
+        self.points
+    }
+
+    pub fn full_marks(&self) -> Option<u16> {
+        self.full_marks
+    }
+
+    pub fn is_full_marks(&self) -> bool {
+        self.full_marks
+            .is_some_and(|full_marks| self.points >= full_marks)
+    }
+
+    pub fn is_passing(&self, passing_line: PassingLine) -> bool {
+        self.points >= passing_line.0
+    }
+}
+
+impl ChineseFormat for Score {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.is_full_marks() {
+            return Self::FULL_MARKS.to_chinese(variant);
+        }
+
+        Chinese {
+            logograms: Points(Count(self.points as u128))
+                .to_chinese(variant)
+                .logograms,
+            omissible: false,
+        }
+    }
+}
+
+/// The minimum passing score - as in `及格线六十分`.
+///
+/// ```
+/// use chinese_format::{*, education::*};
+///
+/// assert_eq!(PassingLine(60).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "及格线六十分".into(),
+///     omissible: false
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PassingLine(pub u16);
+
+impl PassingLine {
+    const JIGE_XIAN: (&'static str, &'static str) = ("及格线", "及格線");
+}
+
+impl ChineseFormat for PassingLine {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!(
+                "{}{}",
+                Self::JIGE_XIAN.to_chinese(variant),
+                Score::new(self.0).to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Grade - either a letter, as in `A等`, or one of the standard rating levels.
+///
+/// ```
+/// use chinese_format::{*, education::*};
+///
+/// assert_eq!(Grade::Letter('B').to_chinese(Variant::Simplified), Chinese {
+///     logograms: "B等".into(),
+///     omissible: false
+/// });
+///
+/// assert_eq!(Grade::Excellent.to_chinese(Variant::Simplified), "优秀");
+/// assert_eq!(Grade::Excellent.to_chinese(Variant::Traditional), "優秀");
+/// assert_eq!(Grade::Good.to_chinese(Variant::Simplified), "良好");
+/// assert_eq!(Grade::Pass.to_chinese(Variant::Simplified), "及格");
+/// assert_eq!(Grade::Fail.to_chinese(Variant::Simplified), "不及格");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Grade {
+    /// A letter followed by `等` - as in `A等`.
+    Letter(char),
+
+    /// `优秀`/`優秀`
+    Excellent,
+
+    /// `良好`
+    Good,
+
+    /// `及格`
+    Pass,
+
+    /// `不及格`
+    Fail,
+}
+
+impl Grade {
+    const DENG: &'static str = "等";
+}
+
+impl ChineseFormat for Grade {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Self::Letter(letter) => Chinese {
+                logograms: format!("{}{}", letter, Self::DENG).into(),
+                omissible: false,
+            },
+            Self::Excellent => ("优秀", "優秀").to_chinese(variant),
+            Self::Good => "良好".to_chinese(variant),
+            Self::Pass => "及格".to_chinese(variant),
+            Self::Fail => "不及格".to_chinese(variant),
+        }
+    }
+}
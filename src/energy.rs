@@ -0,0 +1,58 @@
+//! Energy measures.
+//!
+//! In everyday speech, a kilowatt-hour is called `度` - as in [ElectricityDegree], for `三度电`.
+//!
+//! ```
+//! use chinese_format::{*, energy::*};
+//!
+//! let consumption = ElectricityDegree::new(3);
+//!
+//! assert_eq!(
+//!     consumption.to_chinese(Variant::Simplified),
+//!     Chinese {
+//!         logograms: "三度电".into(),
+//!         omissible: false
+//!     }
+//! );
+//!
+//! assert_eq!(consumption.to_chinese(Variant::Traditional), "三度電");
+//!
+//! assert_eq!(KilowattHour::new(2).to_chinese(Variant::Simplified), "两千瓦时");
+//! assert_eq!(KilowattHour::new(2).to_chinese(Variant::Traditional), "兩千瓦時");
+//!
+//! assert_eq!(
+//!     ElectricityDegree::new(5).convert::<KilowattHour>(),
+//!     Some(KilowattHour::new(5))
+//! );
+//! assert_eq!(WattHour::new(2500).humanize().to_chinese(Variant::Simplified), "二点五千瓦时");
+//!
+//! assert_eq!(
+//!     WattHour::new(0).to_chinese(Variant::Simplified),
+//!     Chinese {
+//!         logograms: "零瓦时".into(),
+//!         omissible: true
+//!     }
+//! );
+//! ```
+use crate::{define_count_measure, impl_scaled_measure, CountBase, Dimension};
+
+/// The [Dimension] of the energy measures, whose base unit is [WattHour].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Energy;
+
+impl Dimension for Energy {
+    const HUMANIZED_UNITS: &'static [(CountBase, (&'static str, &'static str))] =
+        &[(1_000, ("千瓦时", "千瓦時")), (1, ("瓦时", "瓦時"))];
+}
+
+define_count_measure!(pub, KilowattHour, ("千瓦时", "千瓦時"));
+
+define_count_measure!(pub, ElectricityDegree, ("度电", "度電"));
+
+define_count_measure!(pub, WattHour, ("瓦时", "瓦時"));
+
+impl_scaled_measure!(Energy, KilowattHour, 1_000);
+
+impl_scaled_measure!(Energy, ElectricityDegree, 1_000);
+
+impl_scaled_measure!(Energy, WattHour, 1);
@@ -0,0 +1,117 @@
+use crate::{chinese_vec, Chinese, ChineseFormat, Count, Decimal, Glyphs, Variant};
+use digit_sequence::DigitSequence;
+use std::cmp::Ordering;
+
+/// Unit in which a [SignedAmount] is expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AmountUnit {
+    /// As in `百分之二点五`.
+    Percent,
+
+    /// As in `三个百分点` - with `两` for 2.
+    PercentagePoints,
+}
+
+/// Signed amount of a change, kept as sign and magnitude - so that
+/// no negation is ever required, even for [i128::MIN].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(super) struct SignedAmount {
+    negative: bool,
+    integer: u128,
+    fractional: DigitSequence,
+}
+
+impl SignedAmount {
+    const PERCENT: &'static str = "百分之";
+
+    const PERCENTAGE_POINTS: (&'static str, &'static str) = ("个百分点", "個百分點");
+
+    /// Creates an amount from a signed value.
+    pub(super) fn new(value: Decimal) -> Self {
+        Self {
+            negative: value.integer < 0,
+            integer: value.integer.unsigned_abs(),
+            fractional: value.fractional,
+        }
+    }
+
+    /// Creates a negative amount having the given magnitude - whose sign is ignored.
+    pub(super) fn negative(magnitude: Decimal) -> Self {
+        Self {
+            negative: true,
+            ..Self::new(magnitude)
+        }
+    }
+
+    /// Whether the amount is lower than 0.
+    pub(super) fn is_negative(&self) -> bool {
+        self.negative && !self.is_zero()
+    }
+
+    pub(super) fn is_zero(&self) -> bool {
+        self.integer == 0 && self.fractional.iter().all(|digit| *digit == 0)
+    }
+
+    /// The signed value - or, if not expressible as [Decimal], just its magnitude.
+    pub(super) fn to_decimal(&self) -> Decimal {
+        let integer = if self.negative {
+            0i128.checked_sub_unsigned(self.integer)
+        } else {
+            i128::try_from(self.integer).ok()
+        }
+        .expect("The magnitude always comes from an i128");
+
+        Decimal {
+            integer,
+            fractional: self.fractional.clone(),
+        }
+    }
+
+    /// Compares the magnitudes, by value - ignoring the signs
+    /// as well as the trailing zeros of the fractional parts.
+    pub(super) fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        self.integer.cmp(&other.integer).then_with(|| {
+            let left_digits: Vec<u8> = self.fractional.iter().copied().collect();
+            let right_digits: Vec<u8> = other.fractional.iter().copied().collect();
+
+            (0..left_digits.len().max(right_digits.len()))
+                .map(|index| {
+                    let left_digit = left_digits.get(index).copied().unwrap_or(0);
+                    let right_digit = right_digits.get(index).copied().unwrap_or(0);
+
+                    left_digit.cmp(&right_digit)
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+
+    /// Converts the magnitude - with no sign - to [Chinese], in the given unit.
+    pub(super) fn magnitude_to_chinese(&self, unit: AmountUnit, variant: Variant) -> Chinese {
+        match unit {
+            AmountUnit::Percent => chinese_vec!(
+                variant,
+                [Self::PERCENT, self.number_to_chinese(variant).logograms]
+            )
+            .collect(),
+
+            AmountUnit::PercentagePoints => {
+                let amount = if self.fractional.is_empty() {
+                    Count(self.integer).to_chinese(variant)
+                } else {
+                    self.number_to_chinese(variant)
+                };
+
+                chinese_vec!(variant, [amount.logograms, Self::PERCENTAGE_POINTS]).collect()
+            }
+        }
+    }
+
+    fn number_to_chinese(&self, variant: Variant) -> Chinese {
+        if self.fractional.is_empty() {
+            self.integer.to_chinese(variant)
+        } else {
+            chinese_vec!(variant, [self.integer, Glyphs::DIAN, self.fractional]).collect()
+        }
+    }
+}
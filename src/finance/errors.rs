@@ -0,0 +1,47 @@
+use crate::Decimal;
+use std::{error::Error, fmt::Display};
+
+/// Error for when a percentage cannot be expressed via
+/// the traditional `分`/`厘`/`毫` units of an [InterestRate](crate::finance::InterestRate) -
+/// because it is negative or too precise.
+///
+/// ```
+/// use chinese_format::{*, finance::*};
+/// use digit_sequence::DigitSequence;
+///
+/// assert_eq!(
+///     InvalidTraditionalRate(Decimal {
+///         integer: 1,
+///         fractional: 234u16.into()
+///     }).to_string(),
+///     "Invalid traditional rate: 1.234%"
+/// );
+///
+/// assert_eq!(
+///     InvalidTraditionalRate(Decimal {
+///         integer: -3,
+///         fractional: DigitSequence::new()
+///     }).to_string(),
+///     "Invalid traditional rate: -3%"
+/// );
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidTraditionalRate(pub Decimal);
+
+impl Display for InvalidTraditionalRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.fractional.is_empty() {
+            write!(f, "Invalid traditional rate: {}%", self.0.integer)
+        } else {
+            write!(
+                f,
+                "Invalid traditional rate: {}.{}%",
+                self.0.integer, self.0.fractional
+            )
+        }
+    }
+}
+
+impl Error for InvalidTraditionalRate {}
@@ -0,0 +1,235 @@
+use super::amount::{AmountUnit, SignedAmount};
+use crate::{chinese_vec, Chinese, ChineseFormat, Decimal, Variant};
+
+/// How a [MarketChange] is expressed.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MarketChangeStyle {
+    /// Relative change - as in `上涨百分之二点五`.
+    Percent,
+
+    /// Absolute change of a rate - as in `下跌三个百分点`.
+    PercentagePoints,
+
+    /// Daily price limit reached - `涨停` or `跌停`, whatever the value.
+    Limit,
+}
+
+/// Movement of a price, an index or a rate.
+///
+/// It is based on a signed [Decimal]; however, since [Decimal] cannot express
+/// negative values greater than -1, [falling](Self::falling) is also available,
+/// taking the magnitude of the drop.
+///
+/// When the change is zero, the result is always `持平` -
+/// which is not [omissible](Chinese::omissible), as it still conveys information.
+///
+/// ```
+/// use chinese_format::{*, finance::*};
+/// use digit_sequence::DigitSequence;
+///
+/// let rise = MarketChange::new(
+///     Decimal {
+///         integer: 2,
+///         fractional: 5u8.into()
+///     },
+///     MarketChangeStyle::Percent
+/// );
+/// assert_eq!(rise.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "上涨百分之二点五".into(),
+///     omissible: false
+/// });
+/// assert_eq!(rise.to_chinese(Variant::Traditional), "上漲百分之二點五");
+///
+/// let points = MarketChange::new(
+///     Decimal {
+///         integer: -3,
+///         fractional: DigitSequence::new()
+///     },
+///     MarketChangeStyle::PercentagePoints
+/// );
+/// assert_eq!(points.to_chinese(Variant::Simplified), "下跌三个百分点");
+/// assert_eq!(points.to_chinese(Variant::Traditional), "下跌三個百分點");
+///
+/// let two_points = MarketChange::new(
+///     Decimal {
+///         integer: 2,
+///         fractional: DigitSequence::new()
+///     },
+///     MarketChangeStyle::PercentagePoints
+/// );
+/// assert_eq!(two_points.to_chinese(Variant::Simplified), "上涨两个百分点");
+///
+/// let small_drop = MarketChange::falling(
+///     Decimal {
+///         integer: 0,
+///         fractional: 25u8.into()
+///     },
+///     MarketChangeStyle::PercentagePoints
+/// );
+/// assert_eq!(small_drop.to_chinese(Variant::Simplified), "下跌零点二五个百分点");
+///
+/// let limit_up = MarketChange::new(
+///     Decimal {
+///         integer: 10,
+///         fractional: DigitSequence::new()
+///     },
+///     MarketChangeStyle::Limit
+/// );
+/// assert_eq!(limit_up.to_chinese(Variant::Simplified), "涨停");
+/// assert_eq!(limit_up.to_chinese(Variant::Traditional), "漲停");
+///
+/// let limit_down = MarketChange::falling(
+///     Decimal {
+///         integer: 10,
+///         fractional: DigitSequence::new()
+///     },
+///     MarketChangeStyle::Limit
+/// );
+/// assert_eq!(limit_down.to_chinese(Variant::Simplified), "跌停");
+///
+/// let flat = MarketChange::new(
+///     Decimal {
+///         integer: 0,
+///         fractional: 0u8.into()
+///     },
+///     MarketChangeStyle::Percent
+/// );
+/// assert_eq!(flat.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "持平".into(),
+///     omissible: false
+/// });
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MarketChange {
+    amount: SignedAmount,
+    style: MarketChangeStyle,
+}
+
+impl MarketChange {
+    const RISE: (&'static str, &'static str) = ("上涨", "上漲");
+
+    const FALL: &'static str = "下跌";
+
+    const LIMIT_UP: (&'static str, &'static str) = ("涨停", "漲停");
+
+    const LIMIT_DOWN: &'static str = "跌停";
+
+    const FLAT: &'static str = "持平";
+
+    /// Creates a change from a signed value.
+    pub fn new(change: Decimal, style: MarketChangeStyle) -> MarketChange {
+        Self {
+            amount: SignedAmount::new(change),
+            style,
+        }
+    }
+
+    /// Creates a drop having the given magnitude - whose sign is ignored.
+    pub fn falling(magnitude: Decimal, style: MarketChangeStyle) -> MarketChange {
+        Self {
+            amount: SignedAmount::negative(magnitude),
+            style,
+        }
+    }
+
+    /// The signed change - or, if not expressible as [Decimal],
+    /// just its magnitude.
+    pub fn change(&self) -> Decimal {
+        self.amount.to_decimal()
+    }
+
+    /// Whether the change is a drop - a zero change never being considered as such.
+    pub fn is_falling(&self) -> bool {
+        self.amount.is_negative()
+    }
+
+    /// How the change is expressed.
+    pub fn style(&self) -> MarketChangeStyle {
+        self.style
+    }
+
+    fn amount_to_chinese(&self, unit: AmountUnit, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.direction(),
+                self.amount.magnitude_to_chinese(unit, variant).logograms
+            ]
+        )
+        .collect()
+    }
+
+    fn direction(&self) -> &'static dyn ChineseFormat {
+        if self.is_falling() {
+            &Self::FALL
+        } else {
+            &Self::RISE
+        }
+    }
+}
+
+impl ChineseFormat for MarketChange {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.amount.is_zero() {
+            return Self::FLAT.to_chinese(variant);
+        }
+
+        match self.style {
+            MarketChangeStyle::Percent => self.amount_to_chinese(AmountUnit::Percent, variant),
+
+            MarketChangeStyle::PercentagePoints => {
+                self.amount_to_chinese(AmountUnit::PercentagePoints, variant)
+            }
+
+            MarketChangeStyle::Limit => {
+                if self.is_falling() {
+                    Self::LIMIT_DOWN.to_chinese(variant)
+                } else {
+                    Self::LIMIT_UP.to_chinese(variant)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use digit_sequence::DigitSequence;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Creating a market change" {
+            describe "from the minimum integer part" {
+                before {
+                    let minimum = Decimal {
+                        integer: i128::MIN,
+                        fractional: DigitSequence::new()
+                    };
+
+                    let change = MarketChange::new(minimum.clone(), MarketChangeStyle::PercentagePoints);
+                }
+
+                it "should be falling" {
+                    eq!(change.is_falling(), true);
+                }
+
+                it "should return the original value" {
+                    eq!(change.change(), minimum);
+                }
+
+                it "should be convertible to Chinese" {
+                    eq!(
+                        change.to_chinese(Variant::Simplified),
+                        "下跌一百七十涧一千四百一十一沟八千三百四十六穰零四百六十九秭二千三百一十七垓三千一百六十八京七千三百零三兆七千一百五十八亿八千四百一十万五千七百二十八个百分点"
+                    );
+                }
+            }
+        }
+    }
+}
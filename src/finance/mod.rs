@@ -0,0 +1,281 @@
+//! Financial expressions - such as interest rates, market changes and trends.
+//!
+//! ```
+//! use chinese_format::{*, finance::*};
+//!
+//! # fn main() -> GenericResult<()> {
+//! let mortgage = InterestRate::try_new(
+//!     RatePeriod::Annual,
+//!     Decimal {
+//!         integer: 3,
+//!         fractional: 85u8.into()
+//!     },
+//!     RateStyle::Percent
+//! )?;
+//! assert_eq!(mortgage.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "年利率百分之三点八五".into(),
+//!     omissible: false
+//! });
+//!
+//! let loan = InterestRate::try_new(
+//!     RatePeriod::Monthly,
+//!     Decimal {
+//!         integer: 0,
+//!         fractional: 5u8.into()
+//!     },
+//!     RateStyle::Traditional
+//! )?;
+//! assert_eq!(loan.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "月息五厘".into(),
+//!     omissible: false
+//! });
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **REQUIRED FEATURE**: `digit-sequence`.
+mod amount;
+mod errors;
+mod market;
+mod trend;
+
+use crate::{chinese_vec, Chinese, ChineseFormat, Decimal, DecimalPercent, Variant};
+
+pub use errors::*;
+pub use market::*;
+pub use trend::*;
+
+/// The period an [InterestRate] refers to.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RatePeriod {
+    /// Yearly rate - `年利率`, as in `年利率百分之三点八五`, with [RateStyle::Percent];
+    /// `年息`, as in `年息一分二厘`, with [RateStyle::Traditional].
+    Annual,
+
+    /// Monthly rate - `月利率`, as in `月利率百分之一点二`, with [RateStyle::Percent];
+    /// `月息`, as in `月息五厘`, with [RateStyle::Traditional].
+    Monthly,
+}
+
+/// How an [InterestRate] is expressed.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RateStyle {
+    /// Modern style, via [DecimalPercent] - as in `年利率百分之三点八五`.
+    Percent,
+
+    /// Traditional style, via the `分`/`厘`/`毫` units - as in `月息五厘`.
+    ///
+    /// The value of each unit depends on the [RatePeriod]:
+    ///
+    /// * [Annual](RatePeriod::Annual): `分` is 10%, `厘` is 1%, `毫` is 0.1%.
+    ///
+    /// * [Monthly](RatePeriod::Monthly): `分` is 1%, `厘` is 0.1%, `毫` is 0.01%.
+    Traditional,
+}
+
+/// Interest rate, whose value is a percentage.
+///
+/// Must be created via [try_new](Self::try_new), which fails with [InvalidTraditionalRate]
+/// if the [Traditional](RateStyle::Traditional) style is requested for a percentage that
+/// is negative or more precise than `毫`.
+///
+/// ```
+/// use chinese_format::{*, finance::*};
+/// use digit_sequence::DigitSequence;
+///
+/// # fn main() -> GenericResult<()> {
+/// let monthly_percent = InterestRate::try_new(
+///     RatePeriod::Monthly,
+///     Decimal {
+///         integer: 1,
+///         fractional: 2u8.into()
+///     },
+///     RateStyle::Percent
+/// )?;
+/// assert_eq!(monthly_percent.to_chinese(Variant::Simplified), "月利率百分之一点二");
+/// assert_eq!(monthly_percent.to_chinese(Variant::Traditional), "月利率百分之一點二");
+///
+/// let annual_traditional = InterestRate::try_new(
+///     RatePeriod::Annual,
+///     Decimal {
+///         integer: 12,
+///         fractional: DigitSequence::new()
+///     },
+///     RateStyle::Traditional
+/// )?;
+/// assert_eq!(annual_traditional.to_chinese(Variant::Simplified), "年息一分二厘");
+/// assert_eq!(annual_traditional.percent(), &Decimal {
+///     integer: 12,
+///     fractional: DigitSequence::new()
+/// });
+///
+/// let monthly_traditional = InterestRate::try_new(
+///     RatePeriod::Monthly,
+///     Decimal {
+///         integer: 2,
+///         fractional: 15u8.into()
+///     },
+///     RateStyle::Traditional
+/// )?;
+/// assert_eq!(monthly_traditional.to_chinese(Variant::Simplified), "月息二分一厘五毫");
+///
+/// let whole_fen = InterestRate::try_new(
+///     RatePeriod::Monthly,
+///     Decimal {
+///         integer: 1,
+///         fractional: 0u8.into()
+///     },
+///     RateStyle::Traditional
+/// )?;
+/// assert_eq!(whole_fen.to_chinese(Variant::Simplified), "月息一分");
+///
+/// let too_precise = Decimal {
+///     integer: 1,
+///     fractional: 25u8.into()
+/// };
+/// assert_eq!(
+///     InterestRate::try_new(RatePeriod::Annual, too_precise.clone(), RateStyle::Traditional),
+///     Err(InvalidTraditionalRate(too_precise))
+/// );
+///
+/// let negative = Decimal {
+///     integer: -1,
+///     fractional: DigitSequence::new()
+/// };
+/// assert_eq!(
+///     InterestRate::try_new(RatePeriod::Monthly, negative.clone(), RateStyle::Traditional),
+///     Err(InvalidTraditionalRate(negative))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InterestRate {
+    period: RatePeriod,
+    percent: Decimal,
+    style: RateStyle,
+}
+
+impl InterestRate {
+    const TRADITIONAL_UNITS: [&'static str; 3] = ["分", "厘", "毫"];
+
+    /// Creates an interest rate from its percentage.
+    pub fn try_new(
+        period: RatePeriod,
+        percent: Decimal,
+        style: RateStyle,
+    ) -> Result<InterestRate, InvalidTraditionalRate> {
+        let rate = Self {
+            period,
+            percent,
+            style,
+        };
+
+        if style == RateStyle::Traditional {
+            rate.to_traditional_units()?;
+        }
+
+        Ok(rate)
+    }
+
+    /// The period the rate refers to.
+    pub fn period(&self) -> RatePeriod {
+        self.period
+    }
+
+    /// The rate, as a percentage.
+    pub fn percent(&self) -> &Decimal {
+        &self.percent
+    }
+
+    /// How the rate is expressed.
+    pub fn style(&self) -> RateStyle {
+        self.style
+    }
+
+    /// Splits the percentage into the amounts of `分`, `厘` and `毫`.
+    fn to_traditional_units(&self) -> Result<[u128; 3], InvalidTraditionalRate> {
+        let fraction_digits = match self.period {
+            RatePeriod::Annual => 1,
+            RatePeriod::Monthly => 2,
+        };
+
+        let error = || InvalidTraditionalRate(self.percent.clone());
+
+        if self.percent.integer < 0
+            || self
+                .percent
+                .fractional
+                .iter()
+                .skip(fraction_digits)
+                .any(|digit| *digit != 0)
+        {
+            return Err(error());
+        }
+
+        let hao = (0..fraction_digits)
+            .map(|index| {
+                self.percent
+                    .fractional
+                    .iter()
+                    .nth(index)
+                    .copied()
+                    .unwrap_or(0)
+            })
+            .try_fold(self.percent.integer as u128, |total, digit| {
+                total.checked_mul(10)?.checked_add(digit as u128)
+            })
+            .ok_or_else(error)?;
+
+        Ok([hao / 100, (hao / 10) % 10, hao % 10])
+    }
+}
+
+impl ChineseFormat for InterestRate {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self.style {
+            RateStyle::Percent => {
+                let label = match self.period {
+                    RatePeriod::Annual => "年利率",
+                    RatePeriod::Monthly => "月利率",
+                };
+
+                chinese_vec!(variant, [label, DecimalPercent(self.percent.clone())]).collect()
+            }
+
+            RateStyle::Traditional => {
+                let label = match self.period {
+                    RatePeriod::Annual => "年息",
+                    RatePeriod::Monthly => "月息",
+                };
+
+                let amounts = self
+                    .to_traditional_units()
+                    .expect("The rate is validated on construction");
+
+                let units: String = amounts
+                    .iter()
+                    .zip(Self::TRADITIONAL_UNITS)
+                    .filter(|(amount, _)| **amount > 0)
+                    .map(|(amount, unit)| {
+                        format!("{}{}", amount.to_chinese(variant).logograms, unit)
+                    })
+                    .collect();
+
+                let units = if units.is_empty() {
+                    format!("零{}", Self::TRADITIONAL_UNITS[1])
+                } else {
+                    units
+                };
+
+                chinese_vec!(variant, [label, units]).collect()
+            }
+        }
+    }
+}
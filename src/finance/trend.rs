@@ -0,0 +1,263 @@
+use super::amount::{AmountUnit, SignedAmount};
+use crate::{chinese_vec, Chinese, ChineseFormat, Decimal, Glyphs, Percent, Variant};
+use digit_sequence::DigitSequence;
+use std::cmp::Ordering;
+
+/// How the amount of a [Trend] is expressed.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TrendUnit {
+    /// Relative change - as in `上升百分之三`.
+    #[default]
+    Percent,
+
+    /// Absolute change of a rate - as in `下降两个百分点`.
+    PercentagePoints,
+}
+
+/// The verbs describing the direction of a [Trend].
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TrendVerbs {
+    /// Verb for positive changes - such as `上升`.
+    pub rise: Glyphs,
+
+    /// Verb for negative changes - such as `下降`.
+    pub fall: Glyphs,
+
+    /// Verb for changes not exceeding the flat threshold - such as `持平`.
+    pub flat: Glyphs,
+}
+
+impl TrendVerbs {
+    /// `上升`, `下降` and `持平` - the default verbs.
+    pub const RISE_FALL: TrendVerbs = TrendVerbs {
+        rise: Glyphs::same("上升"),
+        fall: Glyphs::same("下降"),
+        flat: Glyphs::same("持平"),
+    };
+
+    /// `增长`/`增長`, `减少`/`減少` and `持平` - for quantities such as revenues.
+    pub const GROWTH: TrendVerbs = TrendVerbs {
+        rise: Glyphs::new("增长", "增長"),
+        fall: Glyphs::new("减少", "減少"),
+        ..Self::RISE_FALL
+    };
+}
+
+/// The default [TrendVerbs] are [TrendVerbs::RISE_FALL].
+impl Default for TrendVerbs {
+    fn default() -> Self {
+        Self::RISE_FALL
+    }
+}
+
+/// Trend of a time series - as in `上升百分之三`, `下降两个百分点` or `基本持平`.
+///
+/// Like [MarketChange](super::MarketChange), it is based on a signed [Decimal] -
+/// with [falling](Self::falling) for drops greater than -1 - or on a [Percent].
+///
+/// Changes whose magnitude does not exceed the [flat threshold](Self::with_flat_threshold)
+/// are described as flat - prefixed by `基本` unless the change is exactly zero.
+///
+/// ```
+/// use chinese_format::{*, finance::*};
+/// use digit_sequence::DigitSequence;
+///
+/// let rise = Trend::from(Percent(3));
+/// assert_eq!(rise.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "上升百分之三".into(),
+///     omissible: false
+/// });
+///
+/// let drop = Trend::new(
+///     Decimal {
+///         integer: -2,
+///         fractional: DigitSequence::new()
+///     },
+///     TrendUnit::PercentagePoints
+/// );
+/// assert_eq!(drop.to_chinese(Variant::Simplified), "下降两个百分点");
+/// assert_eq!(drop.to_chinese(Variant::Traditional), "下降兩個百分點");
+///
+/// let revenue = Trend::new(
+///     Decimal {
+///         integer: 12,
+///         fractional: 5u8.into()
+///     },
+///     TrendUnit::Percent
+/// )
+/// .with_verbs(TrendVerbs::GROWTH);
+/// assert_eq!(revenue.to_chinese(Variant::Simplified), "增长百分之十二点五");
+/// assert_eq!(revenue.to_chinese(Variant::Traditional), "增長百分之十二點五");
+///
+/// let threshold = Decimal {
+///     integer: 0,
+///     fractional: 5u8.into()
+/// };
+///
+/// let steady = Trend::falling(
+///     Decimal {
+///         integer: 0,
+///         fractional: 30u8.into()
+///     },
+///     TrendUnit::Percent
+/// )
+/// .with_flat_threshold(threshold.clone());
+/// assert_eq!(steady.to_chinese(Variant::Simplified), "基本持平");
+///
+/// let noticeable = Trend::falling(
+///     Decimal {
+///         integer: 0,
+///         fractional: 8u8.into()
+///     },
+///     TrendUnit::Percent
+/// )
+/// .with_flat_threshold(threshold);
+/// assert_eq!(noticeable.to_chinese(Variant::Simplified), "下降百分之零点八");
+///
+/// assert_eq!(Trend::from(Percent(0)).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "持平".into(),
+///     omissible: false
+/// });
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Trend {
+    amount: SignedAmount,
+    unit: TrendUnit,
+    verbs: TrendVerbs,
+    flat_threshold: SignedAmount,
+}
+
+impl Trend {
+    const ROUGHLY: &'static str = "基本";
+
+    /// Creates a trend from a signed value.
+    pub fn new(change: Decimal, unit: TrendUnit) -> Trend {
+        Self::from_amount(SignedAmount::new(change), unit)
+    }
+
+    /// Creates a drop having the given magnitude - whose sign is ignored.
+    pub fn falling(magnitude: Decimal, unit: TrendUnit) -> Trend {
+        Self::from_amount(SignedAmount::negative(magnitude), unit)
+    }
+
+    fn from_amount(amount: SignedAmount, unit: TrendUnit) -> Trend {
+        Self {
+            amount,
+            unit,
+            verbs: TrendVerbs::default(),
+            flat_threshold: SignedAmount::new(Decimal {
+                integer: 0,
+                fractional: DigitSequence::new(),
+            }),
+        }
+    }
+
+    /// Sets the verbs describing the direction.
+    pub fn with_verbs(self, verbs: TrendVerbs) -> Self {
+        Self { verbs, ..self }
+    }
+
+    /// Sets the greatest magnitude - whose sign is ignored - still described as flat.
+    pub fn with_flat_threshold(self, flat_threshold: Decimal) -> Self {
+        Self {
+            flat_threshold: SignedAmount::new(flat_threshold),
+            ..self
+        }
+    }
+
+    /// Whether the trend is a drop - flat trends never being considered as such.
+    pub fn is_falling(&self) -> bool {
+        self.amount.is_negative() && !self.is_flat()
+    }
+
+    /// How the amount is expressed.
+    pub fn unit(&self) -> TrendUnit {
+        self.unit
+    }
+
+    fn is_flat(&self) -> bool {
+        self.amount.cmp_magnitude(&self.flat_threshold) != Ordering::Greater
+    }
+
+    fn amount_to_chinese(&self, variant: Variant) -> Chinese {
+        let unit = match self.unit {
+            TrendUnit::Percent => AmountUnit::Percent,
+            TrendUnit::PercentagePoints => AmountUnit::PercentagePoints,
+        };
+
+        self.amount.magnitude_to_chinese(unit, variant)
+    }
+}
+
+/// [Trend] can be obtained from a [Percent] - expressing a relative change.
+impl From<Percent> for Trend {
+    fn from(percent: Percent) -> Self {
+        Self::new(
+            Decimal {
+                integer: percent.0,
+                fractional: DigitSequence::new(),
+            },
+            TrendUnit::Percent,
+        )
+    }
+}
+
+impl ChineseFormat for Trend {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.is_flat() {
+            let flat = self.verbs.flat.to_chinese(variant);
+
+            if self.amount.is_zero() {
+                return flat;
+            }
+
+            return chinese_vec!(variant, [Self::ROUGHLY, flat.logograms]).collect();
+        }
+
+        let verb = if self.is_falling() {
+            self.verbs.fall
+        } else {
+            self.verbs.rise
+        };
+
+        chinese_vec!(variant, [verb, self.amount_to_chinese(variant).logograms]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Creating a trend" {
+            it "should support the minimum integer part" {
+                let trend = Trend::new(
+                    Decimal {
+                        integer: i128::MIN,
+                        fractional: DigitSequence::new()
+                    },
+                    TrendUnit::PercentagePoints
+                );
+
+                eq!(trend.is_falling(), true);
+            }
+
+            it "should support the minimum integer part as flat threshold" {
+                let trend = Trend::from(Percent(i128::MAX)).with_flat_threshold(Decimal {
+                    integer: i128::MIN,
+                    fractional: DigitSequence::new()
+                });
+
+                eq!(trend.to_chinese(Variant::Simplified), "基本持平");
+            }
+        }
+    }
+}
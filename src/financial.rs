@@ -1,5 +1,4 @@
-use crate::{Chinese, ChineseFormat, Variant};
-use chinese_number::{ChineseCase, ChineseCountMethod, ChineseVariant};
+use crate::{Chinese, ChineseFormat, DigitGlyphSet, Variant};
 
 /// The integer type on which [Financial] is based.
 pub type FinancialBase = u64;
@@ -12,12 +11,12 @@ pub type FinancialBase = u64;
 /// let two = Financial(2);
 ///
 /// assert_eq!(two.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "贰".to_string(),
+///     logograms: "贰".into(),
 ///     omissible: false
 /// });
 ///
 /// assert_eq!(two.to_chinese(Variant::Traditional), Chinese {
-///     logograms: "貳".to_string(),
+///     logograms: "貳".into(),
 ///     omissible: false
 /// });
 ///
@@ -33,12 +32,12 @@ pub struct Financial(pub FinancialBase);
 /// let ten = Financial(10);
 ///
 /// assert_eq!(ten.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "拾".to_string(),
+///     logograms: "拾".into(),
 ///     omissible: false
 /// });
 ///
 /// assert_eq!(ten.to_chinese(Variant::Traditional), Chinese {
-///     logograms: "拾".to_string(),
+///     logograms: "拾".into(),
 ///     omissible: false
 /// });
 ///
@@ -51,12 +50,12 @@ pub struct Financial(pub FinancialBase);
 /// let zero = Financial(0);
 ///
 /// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "零".to_string(),
+///     logograms: "零".into(),
 ///     omissible: true
 /// });
 ///
 /// assert_eq!(zero.to_chinese(Variant::Traditional), Chinese {
-///     logograms: "零".to_string(),
+///     logograms: "零".into(),
 ///     omissible: true
 /// });
 ///
@@ -64,32 +63,28 @@ pub struct Financial(pub FinancialBase);
 /// let max = Financial(FinancialBase::MAX);
 ///
 /// assert_eq!(max.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "壹仟捌佰肆拾肆京陆仟柒佰肆拾肆兆零柒佰叁拾柒亿零玖佰伍拾伍万壹仟陆佰壹拾伍".to_string(),
+///     logograms: "壹仟捌佰肆拾肆京陆仟柒佰肆拾肆兆零柒佰叁拾柒亿零玖佰伍拾伍万壹仟陆佰壹拾伍".into(),
 ///     omissible: false
 /// });
 ///
 /// assert_eq!(max.to_chinese(Variant::Traditional), Chinese {
-///     logograms: "壹仟捌佰肆拾肆京陸仟柒佰肆拾肆兆零柒佰參拾柒億零玖佰伍拾伍萬壹仟陸佰壹拾伍".to_string(),
+///     logograms: "壹仟捌佰肆拾肆京陸仟柒佰肆拾肆兆零柒佰參拾柒億零玖佰伍拾伍萬壹仟陸佰壹拾伍".into(),
 ///     omissible: false
 /// });
 /// ```
 impl ChineseFormat for Financial {
-    fn to_chinese(&self, variant: crate::Variant) -> crate::Chinese {
-        let logograms: String = chinese_number::NumberToChinese::to_chinese(
-            self.0,
-            match variant {
-                Variant::Simplified => ChineseVariant::Simple,
-                Variant::Traditional => ChineseVariant::Traditional,
-            },
-            ChineseCase::Upper,
-            ChineseCountMethod::TenThousand,
-        )
-        .expect("Converting an integer to financial Chinese should never fail!");
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.0
+            .to_chinese_with_glyphs(variant, DigitGlyphSet::FINANCIAL)
+    }
 
-        Chinese {
-            logograms,
-            omissible: self.0 == 0,
-        }
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        crate::pinyin::number_tokens(
+            &self.to_chinese(variant).logograms,
+            variant,
+            DigitGlyphSet::FINANCIAL,
+        )
     }
 }
 
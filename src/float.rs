@@ -0,0 +1,263 @@
+use crate::{chinese_vec, Chinese, ChineseFormat, Decimal, IntegerPart, Sign, Variant};
+use digit_sequence::DigitSequence;
+
+/// How a [Float] is written.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FloatStrategy {
+    /// Positional notation - as in `三点一四` - unless the value is too large,
+    /// that is from `10^16`, or so small that it would be rounded to zero:
+    /// in such cases, it switches to [Scientific](Self::Scientific).
+    #[default]
+    Auto,
+
+    /// Always positional notation - except when the integer part cannot be
+    /// expressed as an [IntegerPart], which requires [Scientific](Self::Scientific).
+    Positional,
+
+    /// Always scientific notation - as in `一点五乘以十的八次方`.
+    Scientific,
+}
+
+/// Floating-point number - rounded to at most [max_digits](Self::max_digits)
+/// fractional digits, the trailing zeros being dropped.
+///
+/// Not-a-number is written as `非数`/`非數`, whereas infinities as `无穷大`/`無窮大`:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let pi = Float::new(std::f64::consts::PI);
+/// assert_eq!(pi.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三点一四一五九三".into(),
+///     omissible: false
+/// });
+///
+/// let rounded = Float { max_digits: 2, ..pi };
+/// assert_eq!(rounded.to_chinese(Variant::Traditional), "三點一四");
+///
+/// assert_eq!(Float::new(-0.5).to_chinese(Variant::Simplified), "负零点五");
+/// assert_eq!(Float::new(2.0).to_chinese(Variant::Simplified), "二");
+/// assert_eq!(Float::from(0.25f32).to_chinese(Variant::Simplified), "零点二五");
+///
+/// assert_eq!(Float::new(0.0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+///
+/// assert_eq!(Float::new(f64::NAN).to_chinese(Variant::Simplified), "非数");
+/// assert_eq!(Float::new(f64::NAN).to_chinese(Variant::Traditional), "非數");
+/// assert_eq!(Float::new(f64::INFINITY).to_chinese(Variant::Simplified), "无穷大");
+/// assert_eq!(Float::new(f64::NEG_INFINITY).to_chinese(Variant::Traditional), "負無窮大");
+/// ```
+///
+/// Very large and very small values fall back to scientific notation,
+/// according to the [FloatStrategy]:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(Float::new(1.5e20).to_chinese(Variant::Simplified), "一点五乘以十的二十次方");
+/// assert_eq!(Float::new(-2.5e-9).to_chinese(Variant::Traditional), "負二點五乘以十的負九次方");
+///
+/// let positional = Float {
+///     strategy: FloatStrategy::Positional,
+///     ..Float::new(1.5e20)
+/// };
+/// assert_eq!(positional.to_chinese(Variant::Simplified), "一垓五千京");
+///
+/// let tiny = Float {
+///     strategy: FloatStrategy::Positional,
+///     ..Float::new(2.5e-9)
+/// };
+/// assert_eq!(tiny.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+///
+/// let scientific = Float {
+///     max_digits: 2,
+///     strategy: FloatStrategy::Scientific,
+///     ..Float::new(123456.0)
+/// };
+/// assert_eq!(scientific.to_chinese(Variant::Simplified), "一点二三乘以十的五次方");
+///
+/// let huge = Float {
+///     strategy: FloatStrategy::Positional,
+///     ..Float::new(f64::MAX)
+/// };
+/// assert_eq!(huge.to_chinese(Variant::Simplified), "一点七九七六九三乘以十的三百零八次方");
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Float {
+    pub value: f64,
+
+    /// The maximum number of fractional digits - of the mantissa, in scientific notation.
+    pub max_digits: usize,
+
+    pub strategy: FloatStrategy,
+}
+
+impl Float {
+    /// The default value for [max_digits](Self::max_digits).
+    pub const DEFAULT_MAX_DIGITS: usize = 6;
+
+    /// Values from this threshold are written in scientific notation by [FloatStrategy::Auto].
+    const AUTO_SCIENTIFIC_THRESHOLD: f64 = 1e16;
+
+    const TIMES_TEN_TO_THE: (&'static str, &'static str) = ("乘以十的", "乘以十的");
+
+    const POWER: &'static str = "次方";
+
+    /// Creates a [Float] with [DEFAULT_MAX_DIGITS](Self::DEFAULT_MAX_DIGITS)
+    /// and [FloatStrategy::Auto].
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            max_digits: Self::DEFAULT_MAX_DIGITS,
+            strategy: FloatStrategy::Auto,
+        }
+    }
+
+    /// Splits the formatted magnitude into a [Decimal], dropping the trailing zeros.
+    fn parse_magnitude(formatted: &str) -> Option<Decimal> {
+        let (integer, fractional) = formatted.split_once('.').unwrap_or((formatted, ""));
+
+        Some(Decimal {
+            integer: integer.parse::<IntegerPart>().ok()?,
+            fractional: fractional
+                .trim_end_matches('0')
+                .parse::<DigitSequence>()
+                .unwrap_or_default(),
+        })
+    }
+
+    fn positional_magnitude(&self) -> Option<Decimal> {
+        Self::parse_magnitude(&format!("{:.*}", self.max_digits, self.value.abs()))
+    }
+
+    fn with_sign(&self, magnitude: Chinese, is_zero: bool, variant: Variant) -> Chinese {
+        if self.value.is_sign_negative() && !is_zero {
+            chinese_vec!(variant, [Sign(-1), magnitude.logograms]).collect()
+        } else {
+            magnitude
+        }
+    }
+
+    fn positional_chinese(&self, magnitude: Decimal, variant: Variant) -> Chinese {
+        let is_zero = magnitude.integer == 0 && magnitude.fractional.is_empty();
+
+        let chinese = self.with_sign(magnitude.to_chinese(variant), is_zero, variant);
+
+        Chinese {
+            omissible: is_zero,
+            ..chinese
+        }
+    }
+
+    fn scientific_chinese(&self, variant: Variant) -> Chinese {
+        let formatted = format!("{:.*e}", self.max_digits, self.value.abs());
+
+        let (mantissa, exponent) = formatted
+            .split_once('e')
+            .expect("Scientific notation always has an exponent");
+
+        let mantissa =
+            Self::parse_magnitude(mantissa).expect("The mantissa always has a single digit");
+
+        let exponent: i32 = exponent.parse().expect("The exponent is an integer");
+
+        let mantissa_chinese = self.with_sign(mantissa.to_chinese(variant), false, variant);
+
+        chinese_vec!(
+            variant,
+            [
+                mantissa_chinese.logograms,
+                Self::TIMES_TEN_TO_THE,
+                exponent,
+                Self::POWER
+            ]
+        )
+        .collect()
+    }
+}
+
+impl From<f64> for Float {
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<f32> for Float {
+    fn from(value: f32) -> Self {
+        Self::new(value as f64)
+    }
+}
+
+/// [f64] is converted via [Float::new].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(36.6f64.to_chinese(Variant::Simplified), "三十六点六");
+/// assert_eq!(f64::NAN.to_chinese(Variant::Simplified), "非数");
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+impl ChineseFormat for f64 {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Float::new(*self).to_chinese(variant)
+    }
+}
+
+/// [f32] is converted via [Float::new], after widening to [f64] -
+/// the rounding to [Float::DEFAULT_MAX_DIGITS] hiding the widening noise.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(0.5f32.to_chinese(Variant::Simplified), "零点五");
+/// assert_eq!(0.1f32.to_chinese(Variant::Simplified), "零点一");
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+impl ChineseFormat for f32 {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Float::from(*self).to_chinese(variant)
+    }
+}
+
+impl ChineseFormat for Float {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.value.is_nan() {
+            return ("非数", "非數").to_chinese(variant);
+        }
+
+        if self.value.is_infinite() {
+            return self.with_sign(("无穷大", "無窮大").to_chinese(variant), false, variant);
+        }
+
+        if self.strategy == FloatStrategy::Scientific {
+            return self.scientific_chinese(variant);
+        }
+
+        match self.positional_magnitude() {
+            Some(magnitude)
+                if self.strategy == FloatStrategy::Auto
+                    && (self.value.abs() >= Self::AUTO_SCIENTIFIC_THRESHOLD
+                        || (self.value != 0.0
+                            && magnitude.integer == 0
+                            && magnitude.fractional.is_empty())) =>
+            {
+                self.scientific_chinese(variant)
+            }
+
+            Some(magnitude) => self.positional_chinese(magnitude, variant),
+
+            None => self.scientific_chinese(variant),
+        }
+    }
+}
@@ -80,7 +80,7 @@ impl Fraction {
 /// //Positive fractions
 /// let positive_fraction = Fraction::try_new(8, 3)?;
 /// assert_eq!(positive_fraction.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "八分之三".to_string(),
+///     logograms: "八分之三".into(),
 ///     omissible: false
 /// });
 /// assert_eq!(positive_fraction.to_chinese(Variant::Traditional), "八分之三");
@@ -88,7 +88,7 @@ impl Fraction {
 /// //Zero fractions, no matter the denominator
 /// let zero_fraction = Fraction::try_new(8, 0)?;
 /// assert_eq!(zero_fraction.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "零".to_string(),
+///     logograms: "零".into(),
 ///     omissible: true
 /// });
 /// assert_eq!(zero_fraction.to_chinese(Variant::Traditional), "零");
@@ -96,7 +96,7 @@ impl Fraction {
 /// //Negative fractions
 /// let negative_fraction = Fraction::try_new(3, -11)?;
 /// assert_eq!(negative_fraction.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "负三分之十一".to_string(),
+///     logograms: "负三分之十一".into(),
 ///     omissible: false
 /// });
 /// assert_eq!(negative_fraction.to_chinese(Variant::Traditional), "負三分之十一");
@@ -119,9 +119,24 @@ impl ChineseFormat for Fraction {
             .collect()
         } else {
             Chinese {
-                logograms: "零".to_string(),
+                logograms: "零".into(),
                 omissible: true,
             }
         }
     }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        if self.numerator != 0 {
+            [
+                Sign(self.numerator).to_chinese_tokens(variant),
+                self.denominator.to_chinese_tokens(variant),
+                crate::pinyin::tokens_with_role("分之", crate::pinyin::TokenRole::Connector),
+                self.numerator.abs().to_chinese_tokens(variant),
+            ]
+            .concat()
+        } else {
+            0.to_chinese_tokens(variant)
+        }
+    }
 }
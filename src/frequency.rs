@@ -0,0 +1,58 @@
+//! Frequency measures.
+//!
+//! ```
+//! use chinese_format::{*, frequency::*};
+//!
+//! let mains = Hertz::new(50);
+//!
+//! assert_eq!(
+//!     mains.to_chinese(Variant::Simplified),
+//!     Chinese {
+//!         logograms: "五十赫兹".into(),
+//!         omissible: false
+//!     }
+//! );
+//!
+//! assert_eq!(mains.to_chinese(Variant::Traditional), "五十赫茲");
+//!
+//! assert_eq!(Megahertz::new(2400).humanize().to_chinese(Variant::Simplified), "二点四吉赫");
+//! assert_eq!(Kilohertz::new(2).to_chinese(Variant::Simplified), "两千赫");
+//!
+//! assert_eq!(
+//!     Hertz::new(0).to_chinese(Variant::Simplified),
+//!     Chinese {
+//!         logograms: "零赫兹".into(),
+//!         omissible: true
+//!     }
+//! );
+//! ```
+use crate::{define_count_measure, impl_scaled_measure, CountBase, Dimension};
+
+/// The [Dimension] of the frequency measures, whose base unit is [Hertz].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Frequency;
+
+impl Dimension for Frequency {
+    const HUMANIZED_UNITS: &'static [(CountBase, (&'static str, &'static str))] = &[
+        (1_000_000_000, ("吉赫", "吉赫")),
+        (1_000_000, ("兆赫", "兆赫")),
+        (1_000, ("千赫", "千赫")),
+        (1, ("赫兹", "赫茲")),
+    ];
+}
+
+define_count_measure!(pub, Gigahertz, "吉赫");
+
+define_count_measure!(pub, Megahertz, "兆赫");
+
+define_count_measure!(pub, Kilohertz, "千赫");
+
+define_count_measure!(pub, Hertz, ("赫兹", "赫茲"));
+
+impl_scaled_measure!(Frequency, Gigahertz, 1_000_000_000);
+
+impl_scaled_measure!(Frequency, Megahertz, 1_000_000);
+
+impl_scaled_measure!(Frequency, Kilohertz, 1_000);
+
+impl_scaled_measure!(Frequency, Hertz, 1);
@@ -0,0 +1,313 @@
+use crate::{Chinese, ChineseFormat, Variant};
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+/// Defines a cyclic sequence of logograms - as an enum having the given variants -
+/// providing index-based access, iteration and offset arithmetic.
+macro_rules! define_cycle {
+    ($type: ident, [$($variant: ident => $logogram: literal),+]) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $type {
+            $(
+                #[doc = concat!("`", $logogram, "`")]
+                $variant
+            ),+
+        }
+
+        impl $type {
+            /// All the elements, in cyclic order.
+            pub const ALL: [$type; [$($logogram),+].len()] = [$(Self::$variant),+];
+
+            const LOGOGRAMS: [char; [$($logogram),+].len()] = [$($logogram),+];
+
+            /// The element at the given 0-based index - wrapping around the cycle.
+            pub fn from_index(index: usize) -> Self {
+                Self::ALL[index % Self::ALL.len()]
+            }
+
+            /// The 0-based position within the cycle.
+            pub fn index(self) -> usize {
+                self as usize
+            }
+
+            pub fn to_char(self) -> char {
+                Self::LOGOGRAMS[self.index()]
+            }
+
+            /// The element having the given logogram, if any.
+            pub fn from_char(logogram: char) -> Option<Self> {
+                Self::LOGOGRAMS
+                    .iter()
+                    .position(|candidate| *candidate == logogram)
+                    .map(Self::from_index)
+            }
+
+            /// Iterates over all the elements, in cyclic order.
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.into_iter()
+            }
+
+            /// Endlessly iterates over the cycle, starting from this element.
+            pub fn cycle(self) -> impl Iterator<Item = Self> {
+                Self::ALL.into_iter().cycle().skip(self.index())
+            }
+
+            /// The number of steps forward from `other` to this element.
+            pub fn offset_from(self, other: Self) -> usize {
+                (self.index() + Self::ALL.len() - other.index()) % Self::ALL.len()
+            }
+        }
+
+        /// Moves forward along the cycle - or backward, for negative offsets.
+        impl Add<isize> for $type {
+            type Output = Self;
+
+            fn add(self, offset: isize) -> Self {
+                let length = Self::ALL.len() as isize;
+
+                //Reducing the offset first prevents overflows
+                Self::from_index(self.index() + offset.rem_euclid(length) as usize)
+            }
+        }
+
+        /// Moves backward along the cycle - or forward, for negative offsets.
+        impl Sub<isize> for $type {
+            type Output = Self;
+
+            fn sub(self, offset: isize) -> Self {
+                let length = Self::ALL.len() as isize;
+
+                //Reducing the offset first prevents overflows
+                Self::from_index(self.index() + Self::ALL.len() - offset.rem_euclid(length) as usize)
+            }
+        }
+
+        impl ChineseFormat for $type {
+            fn to_chinese(&self, _variant: Variant) -> Chinese {
+                Chinese {
+                    logograms: self.to_char().to_string().into(),
+                    omissible: false,
+                }
+            }
+        }
+    };
+}
+
+define_cycle!(
+    Tiangan,
+    [
+        Jia => '甲',
+        Yi => '乙',
+        Bing => '丙',
+        Ding => '丁',
+        Wu => '戊',
+        Ji => '己',
+        Geng => '庚',
+        Xin => '辛',
+        Ren => '壬',
+        Gui => '癸'
+    ]
+);
+
+define_cycle!(
+    Dizhi,
+    [
+        Zi => '子',
+        Chou => '丑',
+        Yin => '寅',
+        Mao => '卯',
+        Chen => '辰',
+        Si => '巳',
+        Wu => '午',
+        Wei => '未',
+        Shen => '申',
+        You => '酉',
+        Xu => '戌',
+        Hai => '亥'
+    ]
+);
+
+/// Error for when a [Tiangan] and a [Dizhi] cannot be paired into a [GanZhi] -
+/// because their indexes have different parity.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     InvalidGanZhi(Tiangan::Jia, Dizhi::Chou).to_string(),
+///     "Invalid GanZhi: 甲丑"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidGanZhi(pub Tiangan, pub Dizhi);
+
+impl Display for InvalidGanZhi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid GanZhi: {}{}",
+            self.0.to_char(),
+            self.1.to_char()
+        )
+    }
+}
+
+impl Error for InvalidGanZhi {}
+
+/// Element of the sexagenary cycle - such as `甲子` - pairing a [Tiangan] with a [Dizhi].
+///
+/// Both [Tiangan] - the heavenly stems - and [Dizhi] - the earthly branches - are
+/// standalone cyclic sequences, supporting index-based access, iteration and offsets:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(Tiangan::Bing.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "丙".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Dizhi::from_index(13), Dizhi::Chou);
+/// assert_eq!(Dizhi::Hai.index(), 11);
+/// assert_eq!(Tiangan::from_char('癸'), Some(Tiangan::Gui));
+///
+/// assert_eq!(Tiangan::Gui + 1, Tiangan::Jia);
+/// assert_eq!(Dizhi::Zi - 1, Dizhi::Hai);
+/// assert_eq!(Dizhi::Yin.offset_from(Dizhi::Xu), 4);
+///
+/// assert_eq!(Tiangan::Jia + isize::MAX, Tiangan::from_index(isize::MAX as usize));
+/// assert_eq!(Tiangan::Jia - isize::MIN, Tiangan::from_index(isize::MIN.unsigned_abs()));
+/// assert_eq!(Dizhi::Zi + isize::MIN, Dizhi::from_index(12 - isize::MIN.unsigned_abs() % 12));
+///
+/// let stems: String = Tiangan::iter().map(Tiangan::to_char).collect();
+/// assert_eq!(stems, "甲乙丙丁戊己庚辛壬癸");
+///
+/// let branches: String = Dizhi::Xu.cycle().take(4).map(Dizhi::to_char).collect();
+/// assert_eq!(branches, "戌亥子丑");
+/// ```
+///
+/// The pair can be obtained from its 0-based index in the 60-element cycle -
+/// or from a year, which is handy for calendars:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// assert_eq!(GanZhi::from_index(0).to_chinese(Variant::Simplified), "甲子");
+/// assert_eq!(GanZhi::from_index(59).to_chinese(Variant::Simplified), "癸亥");
+///
+/// let year = GanZhi::from_year(2024);
+/// assert_eq!(year.to_chinese(Variant::Traditional), "甲辰");
+/// assert_eq!(year.index(), 40);
+/// assert_eq!((year + 1).to_chinese(Variant::Simplified), "乙巳");
+/// assert_eq!(GanZhi::from_year(1984), GanZhi::from_index(0));
+///
+/// assert_eq!(GanZhi::from_year(i32::MIN), GanZhi::from_index(48));
+/// assert_eq!(GanZhi::from_year(i32::MAX), GanZhi::from_index(3));
+/// assert_eq!(year + isize::MAX, GanZhi::from_index(40 + isize::MAX as usize % 60));
+/// assert_eq!(year - isize::MIN, GanZhi::from_index(40 + isize::MIN.unsigned_abs() % 60));
+///
+/// let renwu = GanZhi::try_new(Tiangan::Ren, Dizhi::Wu)?;
+/// assert_eq!(renwu.index(), 18);
+/// assert_eq!(renwu.tiangan(), Tiangan::Ren);
+/// assert_eq!(renwu.dizhi(), Dizhi::Wu);
+///
+/// assert_eq!(
+///     GanZhi::try_new(Tiangan::Jia, Dizhi::Chou),
+///     Err(InvalidGanZhi(Tiangan::Jia, Dizhi::Chou))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GanZhi {
+    tiangan: Tiangan,
+    dizhi: Dizhi,
+}
+
+impl GanZhi {
+    /// The length of the sexagenary cycle.
+    pub const CYCLE_LENGTH: usize = 60;
+
+    /// A year whose element is `甲子`.
+    const JIAZI_YEAR: i32 = 1984;
+
+    /// Tries to pair the given stem and branch - failing with [InvalidGanZhi]
+    /// if their indexes have different parity.
+    pub fn try_new(tiangan: Tiangan, dizhi: Dizhi) -> Result<GanZhi, InvalidGanZhi> {
+        if tiangan.index() % 2 != dizhi.index() % 2 {
+            return Err(InvalidGanZhi(tiangan, dizhi));
+        }
+
+        Ok(Self { tiangan, dizhi })
+    }
+
+    /// The element at the given 0-based index - wrapping around the cycle.
+    pub fn from_index(index: usize) -> Self {
+        Self {
+            tiangan: Tiangan::from_index(index),
+            dizhi: Dizhi::from_index(index),
+        }
+    }
+
+    /// The element associated with the given year - which, strictly speaking,
+    /// begins at the Chinese New Year.
+    pub fn from_year(year: i32) -> Self {
+        //Widened, so that any year can be converted without overflowing
+        let offset = year as i64 - Self::JIAZI_YEAR as i64;
+
+        Self::from_index(offset.rem_euclid(Self::CYCLE_LENGTH as i64) as usize)
+    }
+
+    /// The 0-based position within the sexagenary cycle.
+    pub fn index(self) -> usize {
+        (0..Self::CYCLE_LENGTH)
+            .step_by(Tiangan::ALL.len())
+            .map(|base| base + self.tiangan.index())
+            .find(|index| index % Dizhi::ALL.len() == self.dizhi.index())
+            .expect("Stem and branch must have the same parity")
+    }
+
+    pub fn tiangan(self) -> Tiangan {
+        self.tiangan
+    }
+
+    pub fn dizhi(self) -> Dizhi {
+        self.dizhi
+    }
+}
+
+/// Moves forward along the sexagenary cycle - or backward, for negative offsets.
+impl Add<isize> for GanZhi {
+    type Output = Self;
+
+    fn add(self, offset: isize) -> Self {
+        let length = Self::CYCLE_LENGTH as isize;
+
+        //Reducing the offset first prevents overflows
+        Self::from_index(self.index() + offset.rem_euclid(length) as usize)
+    }
+}
+
+/// Moves backward along the sexagenary cycle - or forward, for negative offsets.
+impl Sub<isize> for GanZhi {
+    type Output = Self;
+
+    fn sub(self, offset: isize) -> Self {
+        let length = Self::CYCLE_LENGTH as isize;
+
+        //Reducing the offset first prevents overflows
+        Self::from_index(self.index() + Self::CYCLE_LENGTH - offset.rem_euclid(length) as usize)
+    }
+}
+
+impl ChineseFormat for GanZhi {
+    fn to_chinese(&self, _variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!("{}{}", self.tiangan.to_char(), self.dizhi.to_char()).into(),
+            omissible: false,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use super::Dms;
+use std::{error::Error, fmt::Display};
+
+/// Error for when the minutes or the seconds of a [Dms](super::Dms) are not less than 60.
+///
+/// ```
+/// use chinese_format::geo::*;
+///
+/// assert_eq!(
+///     InvalidDms {
+///         degrees: 39,
+///         minutes: 60,
+///         seconds: 0
+///     }.to_string(),
+///     "Invalid degrees-minutes-seconds: 39°60′0″"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidDms {
+    pub degrees: u16,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+impl Display for InvalidDms {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid degrees-minutes-seconds: {}°{}′{}″",
+            self.degrees, self.minutes, self.seconds
+        )
+    }
+}
+
+impl Error for InvalidDms {}
+
+/// Error for when a [Latitude](super::Latitude) exceeds 90 degrees.
+///
+/// ```
+/// use chinese_format::{*, geo::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// assert_eq!(
+///     LatitudeOutOfRange(Dms::try_new(90, 30, 0)?).to_string(),
+///     "Latitude out of range: 90°30′0″"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LatitudeOutOfRange(pub Dms);
+
+impl Display for LatitudeOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Latitude out of range: {}", self.0)
+    }
+}
+
+impl Error for LatitudeOutOfRange {}
+
+/// Error for when a [Longitude](super::Longitude) exceeds 180 degrees.
+///
+/// ```
+/// use chinese_format::{*, geo::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// assert_eq!(
+///     LongitudeOutOfRange(Dms::try_new(181, 0, 0)?).to_string(),
+///     "Longitude out of range: 181°0′0″"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LongitudeOutOfRange(pub Dms);
+
+impl Display for LongitudeOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Longitude out of range: {}", self.0)
+    }
+}
+
+impl Error for LongitudeOutOfRange {}
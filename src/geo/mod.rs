@@ -0,0 +1,476 @@
+//! Geographic coordinates - such as `北纬三十九度五十四分` - expressed in
+//! degrees, minutes and seconds of arc, as well as angles and compass directions.
+//!
+//! ```
+//! use chinese_format::{*, geo::*};
+//!
+//! # fn main() -> GenericResult<()> {
+//! let beijing = Coordinates {
+//!     latitude: Latitude::try_new(LatitudeHemisphere::North, Dms::try_new(39, 54, 0)?)?,
+//!     longitude: Longitude::try_new(LongitudeHemisphere::East, Dms::try_new(116, 23, 0)?)?
+//! };
+//!
+//! assert_eq!(beijing.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "北纬三十九度五十四分，东经一百一十六度二十三分".into(),
+//!     omissible: false
+//! });
+//! assert_eq!(
+//!     beijing.to_chinese(Variant::Traditional),
+//!     "北緯三十九度五十四分，東經一百一十六度二十三分"
+//! );
+//! # Ok(())
+//! # }
+//! ```
+mod errors;
+
+use crate::{chinese_vec, define_measure, Chinese, ChineseFormat, Variant};
+use std::fmt::Display;
+
+pub use errors::*;
+
+define_measure!(pub, ArcDegree, pub, u16, "度");
+
+define_measure!(pub, ArcMinute, pub, u8, "分");
+
+define_measure!(pub, ArcSecond, pub, u8, "秒");
+
+/// Angle expressed in degrees, minutes and seconds - such as `三十九度五十四分`.
+///
+/// Trailing zero components are omitted, whereas the degrees are always present:
+///
+/// ```
+/// use chinese_format::{*, geo::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let dms = Dms::try_new(39, 54, 27)?;
+/// assert_eq!(dms.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三十九度五十四分二十七秒".into(),
+///     omissible: false
+/// });
+/// assert_eq!(dms.degrees(), 39);
+/// assert_eq!(dms.minutes(), 54);
+/// assert_eq!(dms.seconds(), 27);
+/// assert_eq!(dms.to_string(), "39°54′27″");
+///
+/// assert_eq!(Dms::try_new(2, 0, 0)?.to_chinese(Variant::Simplified), "二度");
+/// assert_eq!(Dms::try_new(30, 0, 5)?.to_chinese(Variant::Simplified), "三十度零分五秒");
+/// assert_eq!(Dms::try_new(0, 0, 0)?.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零度".into(),
+///     omissible: false
+/// });
+///
+/// assert_eq!(
+///     Dms::try_new(39, 54, 60),
+///     Err(InvalidDms { degrees: 39, minutes: 54, seconds: 60 })
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dms {
+    degrees: u16,
+    minutes: u8,
+    seconds: u8,
+}
+
+impl Dms {
+    pub fn try_new(degrees: u16, minutes: u8, seconds: u8) -> Result<Dms, InvalidDms> {
+        if minutes >= 60 || seconds >= 60 {
+            return Err(InvalidDms {
+                degrees,
+                minutes,
+                seconds,
+            });
+        }
+
+        Ok(Self {
+            degrees,
+            minutes,
+            seconds,
+        })
+    }
+
+    pub fn degrees(&self) -> u16 {
+        self.degrees
+    }
+
+    pub fn minutes(&self) -> u8 {
+        self.minutes
+    }
+
+    pub fn seconds(&self) -> u8 {
+        self.seconds
+    }
+
+    /// Whether the angle is not greater than the given number of degrees.
+    fn is_within(&self, degrees: u16) -> bool {
+        self.degrees < degrees
+            || (self.degrees == degrees && self.minutes == 0 && self.seconds == 0)
+    }
+}
+
+impl Display for Dms {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}°{}′{}″", self.degrees, self.minutes, self.seconds)
+    }
+}
+
+impl ChineseFormat for Dms {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let degrees = ArcDegree(self.degrees).to_chinese(variant);
+
+        let logograms = match (self.minutes, self.seconds) {
+            (0, 0) => degrees.logograms,
+
+            (minutes, 0) => {
+                chinese_vec!(variant, [degrees.logograms, ArcMinute(minutes)])
+                    .collect()
+                    .logograms
+            }
+
+            (minutes, seconds) => {
+                chinese_vec!(
+                    variant,
+                    [
+                        degrees.logograms,
+                        ArcMinute(minutes).to_chinese(variant).logograms,
+                        ArcSecond(seconds)
+                    ]
+                )
+                .collect()
+                .logograms
+            }
+        };
+
+        Chinese {
+            logograms,
+            omissible: false,
+        }
+    }
+}
+
+/// Hemisphere of a [Latitude].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LatitudeHemisphere {
+    /// `北纬`/`北緯`
+    North,
+
+    /// `南纬`/`南緯`
+    South,
+}
+
+impl ChineseFormat for LatitudeHemisphere {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Self::North => ("北纬", "北緯"),
+            Self::South => ("南纬", "南緯"),
+        }
+        .to_chinese(variant)
+    }
+}
+
+/// Hemisphere of a [Longitude].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LongitudeHemisphere {
+    /// `东经`/`東經`
+    East,
+
+    /// `西经`/`西經`
+    West,
+}
+
+impl ChineseFormat for LongitudeHemisphere {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Self::East => ("东经", "東經"),
+            Self::West => ("西经", "西經"),
+        }
+        .to_chinese(variant)
+    }
+}
+
+/// Latitude - not exceeding 90 degrees - preceded by its hemisphere.
+///
+/// ```
+/// use chinese_format::{*, geo::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let sydney = Latitude::try_new(LatitudeHemisphere::South, Dms::try_new(33, 52, 4)?)?;
+/// assert_eq!(sydney.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "南纬三十三度五十二分四秒".into(),
+///     omissible: false
+/// });
+/// assert_eq!(sydney.hemisphere(), LatitudeHemisphere::South);
+/// assert_eq!(sydney.dms(), Dms::try_new(33, 52, 4)?);
+///
+/// let pole = Latitude::try_new(LatitudeHemisphere::North, Dms::try_new(90, 0, 0)?)?;
+/// assert_eq!(pole.to_chinese(Variant::Traditional), "北緯九十度");
+///
+/// let beyond = Dms::try_new(90, 0, 1)?;
+/// assert_eq!(
+///     Latitude::try_new(LatitudeHemisphere::North, beyond),
+///     Err(LatitudeOutOfRange(beyond))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Latitude {
+    hemisphere: LatitudeHemisphere,
+    dms: Dms,
+}
+
+impl Latitude {
+    pub fn try_new(
+        hemisphere: LatitudeHemisphere,
+        dms: Dms,
+    ) -> Result<Latitude, LatitudeOutOfRange> {
+        if !dms.is_within(90) {
+            return Err(LatitudeOutOfRange(dms));
+        }
+
+        Ok(Self { hemisphere, dms })
+    }
+
+    pub fn hemisphere(&self) -> LatitudeHemisphere {
+        self.hemisphere
+    }
+
+    pub fn dms(&self) -> Dms {
+        self.dms
+    }
+}
+
+impl ChineseFormat for Latitude {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.hemisphere, self.dms]).collect()
+    }
+}
+
+/// Longitude - not exceeding 180 degrees - preceded by its hemisphere.
+///
+/// ```
+/// use chinese_format::{*, geo::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let new_york = Longitude::try_new(LongitudeHemisphere::West, Dms::try_new(74, 0, 21)?)?;
+/// assert_eq!(new_york.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "西经七十四度零分二十一秒".into(),
+///     omissible: false
+/// });
+/// assert_eq!(new_york.to_chinese(Variant::Traditional), "西經七十四度零分二十一秒");
+/// assert_eq!(new_york.hemisphere(), LongitudeHemisphere::West);
+///
+/// let beyond = Dms::try_new(180, 30, 0)?;
+/// assert_eq!(
+///     Longitude::try_new(LongitudeHemisphere::East, beyond),
+///     Err(LongitudeOutOfRange(beyond))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Longitude {
+    hemisphere: LongitudeHemisphere,
+    dms: Dms,
+}
+
+impl Longitude {
+    pub fn try_new(
+        hemisphere: LongitudeHemisphere,
+        dms: Dms,
+    ) -> Result<Longitude, LongitudeOutOfRange> {
+        if !dms.is_within(180) {
+            return Err(LongitudeOutOfRange(dms));
+        }
+
+        Ok(Self { hemisphere, dms })
+    }
+
+    pub fn hemisphere(&self) -> LongitudeHemisphere {
+        self.hemisphere
+    }
+
+    pub fn dms(&self) -> Dms {
+        self.dms
+    }
+}
+
+impl ChineseFormat for Longitude {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.hemisphere, self.dms]).collect()
+    }
+}
+
+/// Position on the Earth - the [Latitude] and the [Longitude] being separated by `，`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coordinates {
+    pub latitude: Latitude,
+    pub longitude: Longitude,
+}
+
+impl Coordinates {
+    const SEPARATOR: &'static str = "，";
+}
+
+impl ChineseFormat for Coordinates {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.latitude, Self::SEPARATOR, self.longitude]).collect()
+    }
+}
+
+/// Angle - such as `三十五度` - optionally followed by `角`, as in `四十五度角`.
+///
+/// ```
+/// use chinese_format::{*, geo::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let slope = Angle::from(35);
+/// assert_eq!(slope.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三十五度".into(),
+///     omissible: false
+/// });
+///
+/// let corner = Angle {
+///     as_noun: true,
+///     ..Angle::from(45)
+/// };
+/// assert_eq!(corner.to_chinese(Variant::Simplified), "四十五度角");
+///
+/// let precise = Angle::from(Dms::try_new(23, 26, 0)?);
+/// assert_eq!(precise.to_chinese(Variant::Traditional), "二十三度二十六分");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Angle {
+    pub dms: Dms,
+
+    /// Whether `角` follows the measure - referring to the angle itself.
+    pub as_noun: bool,
+}
+
+impl Angle {
+    const NOUN: &'static str = "角";
+}
+
+impl From<Dms> for Angle {
+    fn from(dms: Dms) -> Self {
+        Self {
+            dms,
+            as_noun: false,
+        }
+    }
+}
+
+/// [Angle] can be obtained from a whole number of degrees.
+impl From<u16> for Angle {
+    fn from(degrees: u16) -> Self {
+        Dms::try_new(degrees, 0, 0)
+            .expect("Whole degrees are always valid")
+            .into()
+    }
+}
+
+impl ChineseFormat for Angle {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.as_noun {
+            chinese_vec!(variant, [self.dms, Self::NOUN]).collect()
+        } else {
+            self.dms.to_chinese(variant)
+        }
+    }
+}
+
+/// One of the 16 points of the compass - such as `东北偏北`/`東北偏北`.
+///
+/// ```
+/// use chinese_format::{*, geo::*};
+///
+/// assert_eq!(CompassDirection::North.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "北".into(),
+///     omissible: false
+/// });
+/// assert_eq!(CompassDirection::NorthNorthEast.to_chinese(Variant::Simplified), "东北偏北");
+/// assert_eq!(CompassDirection::EastNorthEast.to_chinese(Variant::Traditional), "東北偏東");
+/// assert_eq!(CompassDirection::SouthWest.to_chinese(Variant::Simplified), "西南");
+/// assert_eq!(CompassDirection::WestNorthWest.to_chinese(Variant::Simplified), "西北偏西");
+///
+/// assert_eq!(CompassDirection::from_bearing(0), CompassDirection::North);
+/// assert_eq!(CompassDirection::from_bearing(20), CompassDirection::NorthNorthEast);
+/// assert_eq!(CompassDirection::from_bearing(135), CompassDirection::SouthEast);
+/// assert_eq!(CompassDirection::from_bearing(350), CompassDirection::North);
+/// assert_eq!(CompassDirection::from_bearing(630), CompassDirection::West);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CompassDirection {
+    North,
+    NorthNorthEast,
+    NorthEast,
+    EastNorthEast,
+    East,
+    EastSouthEast,
+    SouthEast,
+    SouthSouthEast,
+    South,
+    SouthSouthWest,
+    SouthWest,
+    WestSouthWest,
+    West,
+    WestNorthWest,
+    NorthWest,
+    NorthNorthWest,
+}
+
+impl CompassDirection {
+    /// All the directions, clockwise from [North](Self::North).
+    pub const ALL: [CompassDirection; 16] = [
+        Self::North,
+        Self::NorthNorthEast,
+        Self::NorthEast,
+        Self::EastNorthEast,
+        Self::East,
+        Self::EastSouthEast,
+        Self::SouthEast,
+        Self::SouthSouthEast,
+        Self::South,
+        Self::SouthSouthWest,
+        Self::SouthWest,
+        Self::WestSouthWest,
+        Self::West,
+        Self::WestNorthWest,
+        Self::NorthWest,
+        Self::NorthNorthWest,
+    ];
+
+    /// The direction closest to the given bearing - in degrees, clockwise from north.
+    pub fn from_bearing(degrees: u16) -> Self {
+        let normalized = (degrees % 360) as usize;
+
+        Self::ALL[(normalized * 2 + 22) / 45 % Self::ALL.len()]
+    }
+}
+
+impl ChineseFormat for CompassDirection {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Self::North => ("北", "北"),
+            Self::NorthNorthEast => ("东北偏北", "東北偏北"),
+            Self::NorthEast => ("东北", "東北"),
+            Self::EastNorthEast => ("东北偏东", "東北偏東"),
+            Self::East => ("东", "東"),
+            Self::EastSouthEast => ("东南偏东", "東南偏東"),
+            Self::SouthEast => ("东南", "東南"),
+            Self::SouthSouthEast => ("东南偏南", "東南偏南"),
+            Self::South => ("南", "南"),
+            Self::SouthSouthWest => ("西南偏南", "西南偏南"),
+            Self::SouthWest => ("西南", "西南"),
+            Self::WestSouthWest => ("西南偏西", "西南偏西"),
+            Self::West => ("西", "西"),
+            Self::WestNorthWest => ("西北偏西", "西北偏西"),
+            Self::NorthWest => ("西北", "西北"),
+            Self::NorthNorthWest => ("西北偏北", "西北偏北"),
+        }
+        .to_chinese(variant)
+    }
+}
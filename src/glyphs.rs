@@ -0,0 +1,114 @@
+use crate::{Chinese, ChineseFormat, ChineseParts, Variant};
+use std::borrow::Cow;
+
+/// Pair of logogram strings - the former for [Variant::Simplified],
+/// the latter for [Variant::Traditional].
+///
+/// It behaves just like a `(&'static str, &'static str)` pair, but can be
+/// created in `const` contexts and provides a registry of common pairs:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// const WEATHER: Glyphs = Glyphs::new("天气", "天氣");
+/// assert_eq!(WEATHER.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "天气".into(),
+///     omissible: false
+/// });
+/// assert_eq!(WEATHER.to_chinese(Variant::Traditional), "天氣");
+/// assert_eq!(WEATHER.pick(Variant::Traditional), "天氣");
+///
+/// const RICE: Glyphs = Glyphs::same("米");
+/// assert_eq!(RICE, Glyphs("米", "米"));
+///
+/// assert_eq!(Glyphs::DIAN.to_chinese(Variant::Traditional), "點");
+/// assert_eq!(Glyphs::HAO.to_chinese(Variant::Traditional), "號");
+/// assert_eq!(Glyphs::LIBAI.to_chinese(Variant::Traditional), "禮拜");
+///
+/// let pair: (&str, &str) = Glyphs::WAN.into();
+/// assert_eq!(pair, ("万", "萬"));
+/// assert_eq!(Glyphs::from(("亿", "億")), Glyphs::YI);
+///
+/// assert_eq!(Glyphs::same("").to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Glyphs(pub &'static str, pub &'static str);
+
+impl Glyphs {
+    /// `点`/`點`
+    pub const DIAN: Glyphs = Glyphs::new("点", "點");
+
+    /// `号`/`號`
+    pub const HAO: Glyphs = Glyphs::new("号", "號");
+
+    /// `礼拜`/`禮拜`
+    pub const LIBAI: Glyphs = Glyphs::new("礼拜", "禮拜");
+
+    /// `个`/`個`
+    pub const GE: Glyphs = Glyphs::new("个", "個");
+
+    /// `两`/`兩`
+    pub const LIANG: Glyphs = Glyphs::new("两", "兩");
+
+    /// `万`/`萬`
+    pub const WAN: Glyphs = Glyphs::new("万", "萬");
+
+    /// `亿`/`億`
+    pub const YI: Glyphs = Glyphs::new("亿", "億");
+
+    /// `负`/`負`
+    pub const FU: Glyphs = Glyphs::new("负", "負");
+
+    /// `后`/`後`
+    pub const HOU: Glyphs = Glyphs::new("后", "後");
+
+    /// `时`/`時`
+    pub const SHI: Glyphs = Glyphs::new("时", "時");
+
+    pub const fn new(simplified: &'static str, traditional: &'static str) -> Self {
+        Self(simplified, traditional)
+    }
+
+    /// The same logograms for both variants.
+    pub const fn same(logograms: &'static str) -> Self {
+        Self(logograms, logograms)
+    }
+
+    /// The logograms for the given variant.
+    pub const fn pick(&self, variant: Variant) -> &'static str {
+        match variant {
+            Variant::Simplified => self.0,
+            Variant::Traditional => self.1,
+        }
+    }
+}
+
+impl From<(&'static str, &'static str)> for Glyphs {
+    fn from((simplified, traditional): (&'static str, &'static str)) -> Self {
+        Self(simplified, traditional)
+    }
+}
+
+impl From<Glyphs> for (&'static str, &'static str) {
+    fn from(glyphs: Glyphs) -> Self {
+        (glyphs.0, glyphs.1)
+    }
+}
+
+impl ChineseFormat for Glyphs {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = self.pick(variant);
+
+        Chinese {
+            logograms: Cow::Borrowed(logograms),
+            omissible: logograms.is_empty(),
+        }
+    }
+
+    fn to_chinese_parts(&self, variant: Variant) -> ChineseParts {
+        vec![Cow::Borrowed(self.pick(variant))].into()
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{Chinese, ChineseFormat, Count, CountBase, Variant};
+
+/// Anniversary - such as `三十周年` - optionally introduced by the event it celebrates,
+/// as in `成立三十周年`.
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// let anniversary = Anniversary::new(30);
+/// assert_eq!(anniversary.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三十周年".into(),
+///     omissible: false
+/// });
+/// assert_eq!(anniversary.to_chinese(Variant::Traditional), "三十週年");
+///
+/// let founding = Anniversary::new(30).with_event("成立");
+/// assert_eq!(founding.to_chinese(Variant::Simplified), "成立三十周年");
+/// assert_eq!(founding.to_chinese(Variant::Traditional), "成立三十週年");
+///
+/// let wedding = Anniversary::new(2).with_event(("结婚", "結婚"));
+/// assert_eq!(wedding.to_chinese(Variant::Simplified), "结婚两周年");
+/// assert_eq!(wedding.to_chinese(Variant::Traditional), "結婚兩週年");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Anniversary<E = &'static str> {
+    years: CountBase,
+    event: Option<E>,
+}
+
+impl Anniversary {
+    pub fn new(years: CountBase) -> Self {
+        Self { years, event: None }
+    }
+}
+
+impl<E> Anniversary<E> {
+    /// Sets the event - implementing [ChineseFormat] - celebrated by the anniversary.
+    pub fn with_event<F>(self, event: F) -> Anniversary<F> {
+        Anniversary {
+            years: self.years,
+            event: Some(event),
+        }
+    }
+
+    pub fn years(&self) -> CountBase {
+        self.years
+    }
+}
+
+impl<E: ChineseFormat> ChineseFormat for Anniversary<E> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!(
+                "{}{}{}",
+                self.event.to_chinese(variant),
+                Count(self.years).to_chinese(variant),
+                ("周年", "週年").to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use super::DayOutOfRange;
-use crate::define_multi_register_measure;
+use crate::{
+    define_multi_register_measure, Chinese, ChineseFormat, DigitGlyphSet, Glyphs, Measure, Variant,
+};
 
-define_multi_register_measure!(pub, Day, pub(self), u8, ("号", "號"), "日");
+define_multi_register_measure!(pub, Day, pub(self), u8, Glyphs::HAO, "日");
 
 impl Day {
     fn validate(ordinal: u8) -> Result<(), DayOutOfRange> {
@@ -35,6 +37,26 @@ impl Day {
             formal: false,
         })
     }
+
+    /// Converts the day to [Chinese] - reading 20..=31 via the compact
+    /// `廿` (20) and `卅` (30) numerals, as in `廿五日`.
+    pub fn to_compact_chinese(self, variant: Variant) -> Chinese {
+        let tens = match self.value / 10 {
+            2 => "廿",
+            3 => "卅",
+            _ => return self.to_chinese(variant),
+        };
+
+        let units = match self.value % 10 {
+            0 => String::new(),
+            digit => DigitGlyphSet::STANDARD.digit(digit, variant).to_string(),
+        };
+
+        Chinese {
+            logograms: format!("{}{}{}", tens, units, self.unit().to_chinese(variant)).into(),
+            omissible: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -81,6 +103,27 @@ mod tests {
                     }
                 }
 
+                describe "when compact" {
+                    it "should use 廿 and 卅" {
+                        let day = Day::try_new_informal(25).unwrap();
+                        eq!(day.to_compact_chinese(Variant::Traditional), "廿五日");
+
+                        let day = Day::try_new_informal(20).unwrap();
+                        eq!(day.to_compact_chinese(Variant::Simplified), "廿日");
+
+                        let day = Day::try_new_informal(31).unwrap();
+                        eq!(day.to_compact_chinese(Variant::Simplified), "卅一日");
+
+                        let day = Day::try_new_formal(22).unwrap();
+                        eq!(day.to_compact_chinese(Variant::Traditional), "廿二號");
+                    }
+
+                    it "should leave the other days unchanged" {
+                        let day = Day::try_new_informal(12).unwrap();
+                        eq!(day.to_compact_chinese(Variant::Simplified), "十二日");
+                    }
+                }
+
                 describe "when converting from invalid values" {
                     it "should fail" {
                         eq!(Day::try_new_informal(0), Err(DayOutOfRange(0)));
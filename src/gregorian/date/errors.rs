@@ -63,6 +63,27 @@ impl Display for WeekDayOutOfRange {
 
 impl Error for WeekDayOutOfRange {}
 
+/// Error for when a [Quarter](super::Quarter) is out of range.
+///
+/// ```
+/// use chinese_format::gregorian::*;
+///
+/// assert_eq!(
+///     QuarterOutOfRange(5).to_string(),
+///     "Quarter out of range: 5"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuarterOutOfRange(pub u8);
+
+impl Display for QuarterOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Quarter out of range: {}", self.0)
+    }
+}
+
+impl Error for QuarterOutOfRange {}
+
 /// Error for when a date cannot exist in reality - such as `2009-02-31`.
 ///
 /// ```
@@ -104,3 +125,81 @@ impl Display for InvalidDate {
 }
 
 impl Error for InvalidDate {}
+
+/// Error for when the first year of a [YearRange](super::YearRange) is after the last one.
+///
+/// ```
+/// use chinese_format::gregorian::*;
+///
+/// assert_eq!(
+///     InvalidYearRange {
+///         start: 2024,
+///         end: 2020
+///     }.to_string(),
+///     "Invalid year range: 2024 to 2020"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidYearRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl Display for InvalidYearRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid year range: {} to {}", self.start, self.end)
+    }
+}
+
+impl Error for InvalidYearRange {}
+
+/// Error for when a week is out of range for its ISO week-numbering year -
+/// which has either 52 or 53 weeks.
+///
+/// ```
+/// use chinese_format::gregorian::*;
+///
+/// assert_eq!(
+///     WeekOutOfRange {
+///         year: 2024,
+///         week: 53
+///     }.to_string(),
+///     "Week out of range: 2024-W53"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WeekOutOfRange {
+    pub year: u16,
+    pub week: u8,
+}
+
+impl Display for WeekOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Week out of range: {}-W{}", self.year, self.week)
+    }
+}
+
+impl Error for WeekOutOfRange {}
+
+/// Error for when the ISO week of a [Date](super::Date) cannot be determined -
+/// because the date lacks its year, month or day, or because its
+/// ISO week-numbering year would not fit into a [u16].
+///
+/// ```
+/// use chinese_format::gregorian::*;
+///
+/// assert_eq!(
+///     UndeterminedIsoWeek.to_string(),
+///     "The ISO week of the date cannot be determined"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UndeterminedIsoWeek;
+
+impl Display for UndeterminedIsoWeek {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The ISO week of the date cannot be determined")
+    }
+}
+
+impl Error for UndeterminedIsoWeek {}
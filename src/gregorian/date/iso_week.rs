@@ -0,0 +1,201 @@
+use super::{year::Year, Date, UndeterminedIsoWeek, WeekOutOfRange, YearStyle};
+use crate::{Chinese, ChineseFormat, DigitGlyphSet, Variant};
+
+/// Week of the ISO 8601 week-numbering year - such as `第三十七周` -
+/// optionally preceded by its year, as in `二零二四年第三十七周`.
+///
+/// ISO weeks start on Monday, and the first week of a year is the one
+/// containing its first Thursday: as a consequence, the first and the last days
+/// of a calendar year may belong to a week of the adjacent year.
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let week = IsoWeek::try_new(2024, 37)?;
+/// assert_eq!(week.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "第三十七周".into(),
+///     omissible: false
+/// });
+/// assert_eq!(week.year(), 2024);
+/// assert_eq!(week.week(), 37);
+///
+/// let with_year = week.with_year_shown(true);
+/// assert_eq!(with_year.to_chinese(Variant::Simplified), "二零二四年第三十七周");
+///
+/// let spoken = with_year.with_year_style(YearStyle::Spoken);
+/// assert_eq!(spoken.to_chinese(Variant::Traditional), "兩千零二十四年第三十七周");
+///
+/// assert_eq!(IsoWeek::try_new(2020, 53)?.week(), 53);
+/// assert_eq!(
+///     IsoWeek::try_new(2024, 53),
+///     Err(WeekOutOfRange { year: 2024, week: 53 })
+/// );
+/// assert_eq!(
+///     IsoWeek::try_new(2024, 0),
+///     Err(WeekOutOfRange { year: 2024, week: 0 })
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// It can also be derived from a full [Date] - inheriting its [YearStyle]:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let date = DateBuilder::new()
+///     .with_year(2024)
+///     .with_month(9)
+///     .with_day(12)
+///     .build()?;
+/// let week = IsoWeek::try_from(&date)?.with_year_shown(true);
+/// assert_eq!(week.to_chinese(Variant::Simplified), "二零二四年第三十七周");
+///
+/// let new_year = DateBuilder::new()
+///     .with_year(2021)
+///     .with_month(1)
+///     .with_day(1)
+///     .build()?;
+/// assert_eq!(IsoWeek::try_from(&new_year)?, IsoWeek::try_new(2020, 53)?);
+///
+/// let new_year_eve = DateBuilder::new()
+///     .with_year(2008)
+///     .with_month(12)
+///     .with_day(29)
+///     .build()?;
+/// assert_eq!(IsoWeek::try_from(&new_year_eve)?, IsoWeek::try_new(2009, 1)?);
+///
+/// let without_year = DateBuilder::new().with_month(9).with_day(12).build()?;
+/// assert_eq!(IsoWeek::try_from(&without_year), Err(UndeterminedIsoWeek));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoWeek {
+    year: u16,
+    week: u8,
+    year_shown: bool,
+    year_style: YearStyle,
+}
+
+impl IsoWeek {
+    /// Creates the given week of the given ISO week-numbering year - failing
+    /// with [WeekOutOfRange] if the year has no such week.
+    pub fn try_new(year: u16, week: u8) -> Result<IsoWeek, WeekOutOfRange> {
+        if week == 0 || week > Self::weeks_in_year(year as i32) {
+            return Err(WeekOutOfRange { year, week });
+        }
+
+        Ok(Self {
+            year,
+            week,
+            year_shown: false,
+            year_style: YearStyle::default(),
+        })
+    }
+
+    /// Sets whether the year precedes the week.
+    pub fn with_year_shown(self, year_shown: bool) -> Self {
+        Self { year_shown, ..self }
+    }
+
+    /// Sets how the year is read, when shown.
+    pub fn with_year_style(self, year_style: YearStyle) -> Self {
+        Self { year_style, ..self }
+    }
+
+    /// The ISO week-numbering year - which can differ from the calendar year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The week number - from 1 to 52, or to 53 in long years.
+    pub fn week(&self) -> u8 {
+        self.week
+    }
+
+    /// The week day of the 31st December of the given year - 0 being Monday.
+    fn december_31_week_day(year: i32) -> i32 {
+        (year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400) + 6).rem_euclid(7)
+    }
+
+    /// 53 when the year starts or ends on Thursday, 52 otherwise.
+    fn weeks_in_year(year: i32) -> u8 {
+        if Self::december_31_week_day(year) == 3 || Self::december_31_week_day(year - 1) == 2 {
+            53
+        } else {
+            52
+        }
+    }
+
+    /// The ISO (year, week) pair of a valid calendar date.
+    fn iso_year_and_week(year: u16, month: u8, day: u8) -> (i32, u8) {
+        const DAYS_BEFORE_MONTH: [u16; 12] =
+            [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+        let year = year as i32;
+
+        let leap_day = if month > 2 && Year::from(year as u16).is_leap() {
+            1
+        } else {
+            0
+        };
+
+        let ordinal = (DAYS_BEFORE_MONTH[month as usize - 1] + day as u16 + leap_day) as i32;
+
+        let iso_week_day = (Self::december_31_week_day(year - 1) + ordinal) % 7 + 1;
+
+        let week = (ordinal - iso_week_day + 10) / 7;
+
+        if week < 1 {
+            (year - 1, Self::weeks_in_year(year - 1))
+        } else if week > Self::weeks_in_year(year) as i32 {
+            (year + 1, 1)
+        } else {
+            (year, week as u8)
+        }
+    }
+}
+
+/// [IsoWeek] can be obtained from a [Date] having year, month and day.
+impl TryFrom<&Date> for IsoWeek {
+    type Error = UndeterminedIsoWeek;
+
+    fn try_from(date: &Date) -> Result<Self, Self::Error> {
+        let (Some(year), Some(month), Some(day)) = (date.year(), date.month(), date.day()) else {
+            return Err(UndeterminedIsoWeek);
+        };
+
+        let (iso_year, week) = Self::iso_year_and_week(year, month, day);
+
+        let iso_year: u16 = iso_year.try_into().map_err(|_| UndeterminedIsoWeek)?;
+
+        Ok(Self::try_new(iso_year, week)
+            .expect("The computed week is always valid")
+            .with_year_style(date.year_style))
+    }
+}
+
+impl ChineseFormat for IsoWeek {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let week = format!("第{}周", self.week.to_chinese(variant));
+
+        Chinese {
+            logograms: if self.year_shown {
+                format!(
+                    "{}{}",
+                    Year::from(self.year)
+                        .to_styled_chinese(self.year_style, DigitGlyphSet::STANDARD, variant)
+                        .logograms,
+                    week
+                )
+                .into()
+            } else {
+                week.into()
+            },
+            omissible: false,
+        }
+    }
+}
@@ -1,20 +1,35 @@
+mod anniversary;
 mod day;
 mod errors;
+mod iso_week;
 mod month;
 mod pattern;
+mod quarter;
+mod semester;
 mod styled_week_day;
 mod week_day;
 mod week_format;
 mod year;
+mod year_range;
+mod year_style;
 
+pub use self::anniversary::*;
+pub use self::iso_week::*;
 pub use self::pattern::*;
+pub use self::quarter::*;
+pub use self::semester::*;
 pub use self::week_day::*;
 pub use self::week_format::*;
+pub use self::year_range::*;
+pub use self::year_style::*;
 pub use errors::*;
 
 use self::{day::Day, month::Month, styled_week_day::StyledWeekDay, year::Year};
 use crate::GenericResult;
-use crate::{chinese_vec, Chinese, ChineseFormat, EmptyPlaceholder, Variant};
+use crate::{
+    chinese_vec, Chinese, ChineseFormat, ChineseVec, CompositionStyle, DigitGlyphSet,
+    EmptyPlaceholder, Glyphs, Ji, Variant,
+};
 
 /// Provides a configurable way to build [Date] instances.
 ///
@@ -39,12 +54,12 @@ use crate::{chinese_vec, Chinese, ChineseFormat, EmptyPlaceholder, Variant};
 ///     .build()?;
 ///
 /// assert_eq!(date.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "一九九八年六月十三日礼拜六".to_string(),
+///     logograms: "一九九八年六月十三日礼拜六".into(),
 ///     omissible: false
 /// });
 ///
 /// assert_eq!(date.to_chinese(Variant::Traditional), Chinese {
-///     logograms: "一九九八年六月十三日禮拜六".to_string(),
+///     logograms: "一九九八年六月十三日禮拜六".into(),
 ///     omissible: false
 /// });
 ///
@@ -219,9 +234,20 @@ pub struct DateBuilder {
     day: Option<u8>,
     week_day: Option<WeekDay>,
     formal: bool,
+    compact_day: bool,
     week_format: WeekFormat,
+    year_glyphs: DigitGlyphSet,
+    year_style: YearStyle,
+    ji: DatePatternFlags,
 }
 
+const NO_JI: DatePatternFlags = DatePatternFlags {
+    year: false,
+    month: false,
+    day: false,
+    week_day: false,
+};
+
 impl DateBuilder {
     /// Creates the default instance of the builder.
     pub fn new() -> Self {
@@ -237,18 +263,42 @@ impl DateBuilder {
     /// Sets the month - between 1 and 12.
     pub fn with_month(mut self, month: u8) -> Self {
         self.month = Some(month);
+        self.ji.month = false;
+        self
+    }
+
+    /// Sets the month to `几`, as in `几月`.
+    pub fn with_ji_month(mut self) -> Self {
+        self.month = None;
+        self.ji.month = true;
         self
     }
 
     /// Sets the day - between 1 and 31.
     pub fn with_day(mut self, day: u8) -> Self {
         self.day = Some(day);
+        self.ji.day = false;
+        self
+    }
+
+    /// Sets the day to `几`, as in `几号`.
+    pub fn with_ji_day(mut self) -> Self {
+        self.day = None;
+        self.ji.day = true;
         self
     }
 
     /// Sets the week day.
     pub fn with_week_day(mut self, week_day: WeekDay) -> Self {
         self.week_day = Some(week_day);
+        self.ji.week_day = false;
+        self
+    }
+
+    /// Sets the week day to `几`, as in `星期几`.
+    pub fn with_ji_week_day(mut self) -> Self {
+        self.week_day = None;
+        self.ji.week_day = true;
         self
     }
 
@@ -258,12 +308,31 @@ impl DateBuilder {
         self
     }
 
+    /// Sets whether the days from 20 to 31 are written via `廿` and `卅` -
+    /// as in `廿五日` - for traditional and calligraphic contexts.
+    pub fn with_compact_day(mut self, compact_day: bool) -> Self {
+        self.compact_day = compact_day;
+        self
+    }
+
     /// Sets the word used to express a week.
     pub fn with_week_format(mut self, week_format: WeekFormat) -> Self {
         self.week_format = week_format;
         self
     }
 
+    /// Sets the glyphs used for the digits of the year - such as [DigitGlyphSet::CIRCLE_ZERO].
+    pub fn with_year_glyphs(mut self, year_glyphs: DigitGlyphSet) -> Self {
+        self.year_glyphs = year_glyphs;
+        self
+    }
+
+    /// Sets how the year is read.
+    pub fn with_year_style(mut self, year_style: YearStyle) -> Self {
+        self.year_style = year_style;
+        self
+    }
+
     fn validate_consistency(&self, year: Option<&Year>) -> Result<(), InvalidDate> {
         let is_leap_year = year.map(Year::is_leap).unwrap_or(true);
 
@@ -296,9 +365,9 @@ impl DateBuilder {
     pub fn build(&self) -> GenericResult<Date> {
         DatePattern::validate(DatePatternFlags {
             year: self.year.is_some(),
-            month: self.month.is_some(),
-            day: self.day.is_some(),
-            week_day: self.week_day.is_some(),
+            month: self.month.is_some() || self.ji.month,
+            day: self.day.is_some() || self.ji.day,
+            week_day: self.week_day.is_some() || self.ji.week_day,
         })?;
 
         let year: Option<Year> = self.year.map(|year| year.into());
@@ -331,6 +400,12 @@ impl DateBuilder {
             month,
             day,
             week_day,
+            year_glyphs: self.year_glyphs,
+            year_style: self.year_style,
+            ji: self.ji,
+            formal: self.formal,
+            compact_day: self.compact_day,
+            week_format: self.week_format,
         })
     }
 }
@@ -344,7 +419,11 @@ impl Default for DateBuilder {
             day: None,
             week_day: None,
             formal: true,
+            compact_day: false,
             week_format: WeekFormat::default(),
+            year_glyphs: DigitGlyphSet::default(),
+            year_style: YearStyle::default(),
+            ji: NO_JI,
         }
     }
 }
@@ -359,20 +438,158 @@ pub struct Date {
     month: Option<Month>,
     day: Option<Day>,
     week_day: Option<StyledWeekDay>,
+    year_glyphs: DigitGlyphSet,
+    year_style: YearStyle,
+    ji: DatePatternFlags,
+    formal: bool,
+    compact_day: bool,
+    week_format: WeekFormat,
+}
+
+impl Date {
+    /// The year, if present.
+    pub fn year(&self) -> Option<u16> {
+        self.year.as_ref().map(u16::from)
+    }
+
+    /// The month, if present.
+    pub fn month(&self) -> Option<u8> {
+        self.month.map(u8::from)
+    }
+
+    /// The day, if present.
+    pub fn day(&self) -> Option<u8> {
+        self.day.map(u8::from)
+    }
+
+    /// The week day, if present.
+    pub fn week_day(&self) -> Option<WeekDay> {
+        self.week_day.map(|styled| styled.week_day)
+    }
+
+    fn year_to_logograms(&self, variant: Variant) -> String {
+        match &self.year {
+            Some(year) => year
+                .to_styled_chinese(self.year_style, self.year_glyphs, variant)
+                .logograms
+                .into_owned(),
+
+            None => String::new(),
+        }
+    }
+
+    fn month_to_chinese(&self, variant: Variant) -> Chinese {
+        if self.ji.month {
+            return Ji::of("月").to_chinese(variant);
+        }
+
+        EmptyPlaceholder::new(&self.month).to_chinese(variant)
+    }
+
+    fn day_to_chinese(&self, variant: Variant) -> Chinese {
+        if self.ji.day {
+            let unit = if self.formal {
+                Glyphs::HAO
+            } else {
+                Glyphs::same("日")
+            };
+
+            return Ji::of(unit).to_chinese(variant);
+        }
+
+        match &self.day {
+            Some(day) if self.compact_day => day.to_compact_chinese(variant),
+            _ => EmptyPlaceholder::new(&self.day).to_chinese(variant),
+        }
+    }
+
+    fn week_day_to_chinese(&self, variant: Variant) -> Chinese {
+        if self.ji.week_day {
+            return chinese_vec!(variant, [self.week_format, Ji]).collect();
+        }
+
+        EmptyPlaceholder::new(&self.week_day).to_chinese(variant)
+    }
 }
 
+/// The components of a [Date] can be joined via a [CompositionStyle]:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let date = DateBuilder::new()
+///     .with_year(2024)
+///     .with_month(3)
+///     .with_day(5)
+///     .build()?;
+///
+/// assert_eq!(
+///     date.to_chinese_composed(Variant::Simplified, CompositionStyle::Spaced),
+///     "二零二四年 三月 五号"
+/// );
+///
+/// //The zero digits of the year can be written as 〇
+/// let printed_date = DateBuilder::new()
+///     .with_year(2024)
+///     .with_month(10)
+///     .with_day(20)
+///     .with_year_glyphs(DigitGlyphSet::CIRCLE_ZERO)
+///     .build()?;
+///
+/// assert_eq!(printed_date.to_chinese(Variant::Simplified), "二〇二四年十月二十号");
+///
+/// //The days from 20 to 31 can be written via 廿 and 卅
+/// let calligraphic_date = DateBuilder::new()
+///     .with_month(3)
+///     .with_day(25)
+///     .with_formal(false)
+///     .with_compact_day(true)
+///     .build()?;
+///
+/// assert_eq!(calligraphic_date.to_chinese(Variant::Traditional), "三月廿五日");
+///
+/// //Any component but the year can be asked via 几
+/// let question = DateBuilder::new()
+///     .with_ji_month()
+///     .with_ji_day()
+///     .build()?;
+///
+/// assert_eq!(question.to_chinese(Variant::Simplified), "几月几号");
+/// assert_eq!(question.to_chinese(Variant::Traditional), "幾月幾號");
+///
+/// let partial_question = DateBuilder::new()
+///     .with_month(5)
+///     .with_ji_day()
+///     .with_formal(false)
+///     .build()?;
+///
+/// assert_eq!(partial_question.to_chinese(Variant::Simplified), "五月几日");
+///
+/// let week_day_question = DateBuilder::new()
+///     .with_ji_week_day()
+///     .with_week_format(WeekFormat::LiBai)
+///     .build()?;
+///
+/// assert_eq!(week_day_question.to_chinese(Variant::Simplified), "礼拜几");
+/// assert_eq!(week_day_question.to_chinese(Variant::Traditional), "禮拜幾");
+/// # Ok(())
+/// # }
+/// ```
 impl ChineseFormat for Date {
     fn to_chinese(&self, variant: Variant) -> Chinese {
-        chinese_vec!(
-            variant,
-            [
-                EmptyPlaceholder::new(&self.year),
-                EmptyPlaceholder::new(&self.month),
-                EmptyPlaceholder::new(&self.day),
-                EmptyPlaceholder::new(&self.week_day)
-            ]
-        )
-        .trim_end()
-        .collect()
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let components: ChineseVec = vec![
+            self.year_to_logograms(variant).to_chinese(variant),
+            self.month_to_chinese(variant),
+            self.day_to_chinese(variant),
+            self.week_day_to_chinese(variant),
+        ]
+        .into();
+
+        components.trim_end().collect_composed(style)
     }
 }
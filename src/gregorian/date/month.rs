@@ -31,7 +31,7 @@ mod tests {
                         let month: Month = 3.try_into().unwrap();
 
                         eq!(month.to_chinese(Variant::Simplified), Chinese {
-                            logograms: "三月".to_string(),
+                            logograms: "三月".into(),
                             omissible: false
                         });
                     }
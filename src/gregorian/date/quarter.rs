@@ -0,0 +1,74 @@
+use super::{MonthOutOfRange, QuarterOutOfRange};
+use crate::{Chinese, ChineseFormat, Variant};
+
+/// Quarter of the year - such as `第三季度` - used in business reporting.
+///
+/// Each quarter can also be cast to its corresponding ordinal:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let quarter: Quarter = 3.try_into()?;
+/// assert_eq!(quarter, Quarter::Third);
+/// assert_eq!(Quarter::Third as u8, 3);
+///
+/// assert_eq!(quarter.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "第三季度".into(),
+///     omissible: false
+/// });
+/// assert_eq!(quarter.to_chinese(Variant::Traditional), "第三季度");
+///
+/// assert_eq!(Quarter::from_month(11)?, Quarter::Fourth);
+/// assert_eq!(Quarter::from_month(13), Err(MonthOutOfRange(13)));
+///
+/// let result: Result<Quarter, QuarterOutOfRange> = 5.try_into();
+/// assert_eq!(result, Err(QuarterOutOfRange(5)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Quarter {
+    First = 1,
+    Second,
+    Third,
+    Fourth,
+}
+
+impl Quarter {
+    /// The quarter containing the given month - in the 1..=12 range.
+    pub fn from_month(month: u8) -> Result<Quarter, MonthOutOfRange> {
+        if !(1..=12).contains(&month) {
+            return Err(MonthOutOfRange(month));
+        }
+
+        Ok(month
+            .div_ceil(3)
+            .try_into()
+            .expect("Each valid month belongs to a quarter"))
+    }
+}
+
+/// [Quarter] can be obtained from [u8], for values in the 1..=4 range.
+impl TryFrom<u8> for Quarter {
+    type Error = QuarterOutOfRange;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::First),
+            2 => Ok(Self::Second),
+            3 => Ok(Self::Third),
+            4 => Ok(Self::Fourth),
+            _ => Err(QuarterOutOfRange(value)),
+        }
+    }
+}
+
+impl ChineseFormat for Quarter {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!("第{}季度", (*self as u8).to_chinese(variant)).into(),
+            omissible: false,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Chinese, ChineseFormat, Variant};
+
+const TERM: (&str, &str) = ("学期", "學期");
+
+/// Half of the school year - rendered as `上学期` or `下学期`.
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// assert_eq!(Semester::First.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "上学期".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Semester::First.to_chinese(Variant::Traditional), "上學期");
+///
+/// assert_eq!(Semester::Second.to_chinese(Variant::Simplified), "下学期");
+/// assert_eq!(Semester::Second.to_chinese(Variant::Traditional), "下學期");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Semester {
+    First,
+    Second,
+}
+
+impl ChineseFormat for Semester {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let position = match self {
+            Self::First => "上",
+            Self::Second => "下",
+        };
+
+        Chinese {
+            logograms: format!("{}{}", position, TERM.to_chinese(variant)).into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Term identified by its ordinal - as in `第一学期` - for example within
+/// a multi-year curriculum.
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// assert_eq!(OrdinalSemester(1).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "第一学期".into(),
+///     omissible: false
+/// });
+/// assert_eq!(OrdinalSemester(2).to_chinese(Variant::Traditional), "第二學期");
+///
+/// let semester: OrdinalSemester = Semester::Second.into();
+/// assert_eq!(semester, OrdinalSemester(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OrdinalSemester(pub u8);
+
+/// [Semester] can be infallibly converted to [OrdinalSemester].
+impl From<Semester> for OrdinalSemester {
+    fn from(semester: Semester) -> Self {
+        match semester {
+            Semester::First => Self(1),
+            Semester::Second => Self(2),
+        }
+    }
+}
+
+impl ChineseFormat for OrdinalSemester {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!(
+                "第{}{}",
+                self.0.to_chinese(variant),
+                TERM.to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
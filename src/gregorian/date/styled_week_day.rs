@@ -15,7 +15,10 @@ impl StyledWeekDay {
                 WeekFormat::Zhou => "日",
             }
             .to_string(),
-            _ => (self.week_day as u8).to_chinese(variant).logograms,
+            _ => (self.week_day as u8)
+                .to_chinese(variant)
+                .logograms
+                .into_owned(),
         }
     }
 }
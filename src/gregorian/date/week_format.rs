@@ -1,4 +1,4 @@
-use crate::{Chinese, ChineseFormat, Variant};
+use crate::{Chinese, ChineseFormat, Glyphs, Variant};
 
 /// The Chinese ways to describe a week.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -26,7 +26,7 @@ impl ChineseFormat for WeekFormat {
         match self {
             Self::XingQi => "星期".to_chinese(variant),
             Self::Zhou => "周".to_chinese(variant),
-            Self::LiBai => ("礼拜", "禮拜").to_chinese(variant),
+            Self::LiBai => Glyphs::LIBAI.to_chinese(variant),
         }
     }
 }
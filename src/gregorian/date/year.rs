@@ -1,4 +1,5 @@
-use crate::define_no_copy_measure;
+use super::YearStyle;
+use crate::{define_no_copy_measure, Chinese, Count, DigitGlyphSet, Measure, Variant};
 use digit_sequence::DigitSequence;
 
 define_no_copy_measure!(pub, Year, pub(self), DigitSequence, "年");
@@ -10,6 +11,51 @@ impl Year {
 
         (value % 4 == 0) && (value % 100 != 0 || value % 400 == 0)
     }
+
+    /// The logograms of the year value - without the unit - read according to the given [YearStyle]
+    /// and written via the given [DigitGlyphSet].
+    pub(crate) fn value_to_logograms(
+        &self,
+        style: YearStyle,
+        glyphs: DigitGlyphSet,
+        variant: Variant,
+    ) -> String {
+        match style {
+            YearStyle::Digits => self
+                .value()
+                .to_chinese_with_glyphs(variant, glyphs)
+                .logograms
+                .into_owned(),
+
+            YearStyle::Spoken => {
+                let value: u16 = self.into();
+
+                Count(value as u128)
+                    .to_spoken_chinese(variant, glyphs)
+                    .logograms
+                    .into_owned()
+            }
+        }
+    }
+
+    /// Converts the year to [Chinese], reading it according to the given [YearStyle]
+    /// and writing it via the given [DigitGlyphSet].
+    pub(crate) fn to_styled_chinese(
+        &self,
+        style: YearStyle,
+        glyphs: DigitGlyphSet,
+        variant: Variant,
+    ) -> Chinese {
+        Chinese {
+            logograms: format!(
+                "{}{}",
+                self.value_to_logograms(style, glyphs, variant),
+                self.unit().to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
 }
 
 /// [Year] can be infallibly obtained from [u16].
@@ -0,0 +1,98 @@
+use super::{year::Year, InvalidYearRange, YearStyle};
+use crate::{Chinese, ChineseFormat, DigitGlyphSet, Variant};
+
+/// Range of years - such as `二零二零至二零二四年`.
+///
+/// The unit `年` is only written once, after the last year; when the bounds
+/// coincide, the range is rendered as a single year.
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let term = YearRange::try_new(2020, 2024)?;
+/// assert_eq!(term.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "二零二零至二零二四年".into(),
+///     omissible: false
+/// });
+/// assert_eq!(term.start(), 2020);
+/// assert_eq!(term.end(), 2024);
+///
+/// let spoken = term.with_year_style(YearStyle::Spoken);
+/// assert_eq!(spoken.to_chinese(Variant::Simplified), "两千零二十至两千零二十四年");
+/// assert_eq!(spoken.to_chinese(Variant::Traditional), "兩千零二十至兩千零二十四年");
+///
+/// let single = YearRange::try_new(1998, 1998)?;
+/// assert_eq!(single.to_chinese(Variant::Simplified), "一九九八年");
+///
+/// assert_eq!(
+///     YearRange::try_new(2024, 2020),
+///     Err(InvalidYearRange { start: 2024, end: 2020 })
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearRange {
+    start: u16,
+    end: u16,
+    year_style: YearStyle,
+}
+
+impl YearRange {
+    const CONNECTOR: &'static str = "至";
+
+    pub fn try_new(start: u16, end: u16) -> Result<YearRange, InvalidYearRange> {
+        if start > end {
+            return Err(InvalidYearRange { start, end });
+        }
+
+        Ok(Self {
+            start,
+            end,
+            year_style: YearStyle::default(),
+        })
+    }
+
+    /// Sets how the years are read.
+    pub fn with_year_style(self, year_style: YearStyle) -> Self {
+        Self { year_style, ..self }
+    }
+
+    pub fn start(&self) -> u16 {
+        self.start
+    }
+
+    pub fn end(&self) -> u16 {
+        self.end
+    }
+}
+
+impl ChineseFormat for YearRange {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let end = Year::from(self.end).to_styled_chinese(
+            self.year_style,
+            DigitGlyphSet::STANDARD,
+            variant,
+        );
+
+        if self.start == self.end {
+            return end;
+        }
+
+        Chinese {
+            logograms: format!(
+                "{}{}{}",
+                Year::from(self.start).value_to_logograms(
+                    self.year_style,
+                    DigitGlyphSet::STANDARD,
+                    variant
+                ),
+                Self::CONNECTOR,
+                end.logograms
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
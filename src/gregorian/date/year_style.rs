@@ -0,0 +1,42 @@
+/// How the year of a [Date](super::Date) is read.
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let digits = DateBuilder::new()
+///     .with_year(2024)
+///     .build()?;
+/// assert_eq!(digits.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "二零二四年".into(),
+///     omissible: false
+/// });
+///
+/// let spoken = DateBuilder::new()
+///     .with_year(2024)
+///     .with_year_style(YearStyle::Spoken)
+///     .build()?;
+/// assert_eq!(spoken.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "两千零二十四年".into(),
+///     omissible: false
+/// });
+/// assert_eq!(spoken.to_chinese(Variant::Traditional), "兩千零二十四年");
+///
+/// let past = DateBuilder::new()
+///     .with_year(1998)
+///     .with_month(7)
+///     .with_year_style(YearStyle::Spoken)
+///     .build()?;
+/// assert_eq!(past.to_chinese(Variant::Simplified), "一千九百九十八年七月");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum YearStyle {
+    /// Digit by digit - as in `二零二四年`; the standard.
+    #[default]
+    Digits,
+
+    /// As a number - as in `两千零二十四年`, used in formal speech.
+    Spoken,
+}
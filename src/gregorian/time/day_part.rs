@@ -1,7 +1,5 @@
-use super::{Hour, Hour24};
-use crate::{Chinese, ChineseFormat, Variant};
-use lazy_static::lazy_static;
-use std::collections::HashMap;
+use super::{Hour, Hour24, HourOutOfRange};
+use crate::{Chinese, ChineseFormat, Glyphs, Variant};
 
 /// Each of the 8 traditional parts of the day-night cycle.
 ///
@@ -9,7 +7,8 @@ use std::collections::HashMap;
 /// just like the a.m./p.m. in English, but more fine-grained.
 ///
 /// Each day part conventionally lasts 3 hours,
-/// starting from `早上` at 5.
+/// starting from `早上` at 5 - but hours can be reassigned
+/// via a [DayPartScheme].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DayPart {
     EarlyMorning,
@@ -20,6 +19,12 @@ pub enum DayPart {
     Evening,
     Midnight,
     LateNight,
+
+    /// `凌晨` - the small hours, available via [DayPartScheme::LINGCHEN].
+    SmallHours,
+
+    /// A label provided by the user - for custom [DayPartScheme] tables.
+    Custom(Glyphs),
 }
 
 /// Even though [DayPart] is essentially designed
@@ -42,7 +47,7 @@ pub enum DayPart {
 /// ```
 impl From<Hour24> for DayPart {
     fn from(hour24: Hour24) -> Self {
-        PART_OF_DAY_BY_HOUR[&(hour24.clock_value().0 as u8)]
+        DayPartScheme::default().day_part(hour24)
     }
 }
 
@@ -123,51 +128,275 @@ impl From<Hour24> for DayPart {
 ///     DayPart::LateNight.to_chinese(Variant::Traditional),
 ///     "深夜"
 /// );
+///
+/// assert_eq!(
+///     DayPart::SmallHours.to_chinese(Variant::Simplified),
+///     "凌晨"
+/// );
+/// assert_eq!(
+///     DayPart::SmallHours.to_chinese(Variant::Traditional),
+///     "凌晨"
+/// );
 /// ```
 impl ChineseFormat for DayPart {
-    fn to_chinese(&self, _variant: Variant) -> Chinese {
-        Chinese {
-            logograms: match self {
-                DayPart::EarlyMorning => "早上",
-                DayPart::Morning => "上午",
-                DayPart::Midday => "中午",
-                DayPart::Afternoon => "下午",
-                DayPart::EarlyEvening => "傍晚",
-                DayPart::Evening => "晚上",
-                DayPart::Midnight => "午夜",
-                DayPart::LateNight => "深夜",
-            }
-            .to_string(),
-            omissible: false,
-        }
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese::from_static(match self {
+            DayPart::EarlyMorning => "早上",
+            DayPart::Morning => "上午",
+            DayPart::Midday => "中午",
+            DayPart::Afternoon => "下午",
+            DayPart::EarlyEvening => "傍晚",
+            DayPart::Evening => "晚上",
+            DayPart::Midnight => "午夜",
+            DayPart::LateNight => "深夜",
+            DayPart::SmallHours => "凌晨",
+            DayPart::Custom(glyphs) => glyphs.pick(variant),
+        })
     }
 }
 
-lazy_static! {
-    static ref PART_OF_DAY_BY_HOUR: HashMap<u8, DayPart> = HashMap::from([
-        (5, DayPart::EarlyMorning),
-        (6, DayPart::EarlyMorning),
-        (7, DayPart::EarlyMorning),
-        (8, DayPart::Morning),
-        (9, DayPart::Morning),
-        (10, DayPart::Morning),
-        (11, DayPart::Midday),
-        (12, DayPart::Midday),
-        (13, DayPart::Midday),
-        (14, DayPart::Afternoon),
-        (15, DayPart::Afternoon),
-        (16, DayPart::Afternoon),
-        (17, DayPart::EarlyEvening),
-        (18, DayPart::EarlyEvening),
-        (19, DayPart::EarlyEvening),
-        (20, DayPart::Evening),
-        (21, DayPart::Evening),
-        (22, DayPart::Evening),
-        (23, DayPart::Midnight),
-        (0, DayPart::Midnight),
-        (1, DayPart::Midnight),
-        (2, DayPart::LateNight),
-        (3, DayPart::LateNight),
-        (4, DayPart::LateNight),
+/// Table assigning a [DayPart] to each hour of the day - used
+/// by [LinearTime](super::LinearTime) when the day part is included.
+///
+/// The default scheme is [TRADITIONAL](Self::TRADITIONAL), but any hour
+/// can be reassigned - even to a [custom](DayPart::Custom) label:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let seven_pm: Hour24 = 19.try_into()?;
+///
+/// let scheme = DayPartScheme::default();
+/// assert_eq!(scheme, DayPartScheme::TRADITIONAL);
+/// assert_eq!(scheme.day_part(seven_pm), DayPart::EarlyEvening);
+///
+/// let earlier_evening = scheme.with_hours(19..=19, DayPart::Evening)?;
+/// assert_eq!(earlier_evening.day_part(seven_pm), DayPart::Evening);
+///
+/// let dinner = DayPart::Custom(Glyphs::new("晚饭时间", "晚飯時間"));
+/// let meals = scheme.with_hours([18, 19], dinner)?;
+/// assert_eq!(meals.day_part(seven_pm).to_chinese(Variant::Traditional), "晚飯時間");
+///
+/// assert_eq!(scheme.with_hours(20..=24, DayPart::Evening), Err(HourOutOfRange(24)));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayPartScheme {
+    parts: [DayPart; 24],
+}
+
+impl DayPartScheme {
+    /// The 8 traditional parts - each lasting 3 hours, starting from `早上` at 5.
+    pub const TRADITIONAL: DayPartScheme = DayPartScheme::new([
+        DayPart::Midnight,
+        DayPart::Midnight,
+        DayPart::LateNight,
+        DayPart::LateNight,
+        DayPart::LateNight,
+        DayPart::EarlyMorning,
+        DayPart::EarlyMorning,
+        DayPart::EarlyMorning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Midday,
+        DayPart::Midday,
+        DayPart::Midday,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::EarlyEvening,
+        DayPart::EarlyEvening,
+        DayPart::EarlyEvening,
+        DayPart::Evening,
+        DayPart::Evening,
+        DayPart::Evening,
+        DayPart::Midnight,
+    ]);
+
+    /// Like [TRADITIONAL](Self::TRADITIONAL), but with `凌晨` - as common in everyday speech -
+    /// from 0 to 4.
+    ///
+    /// ```
+    /// use chinese_format::{*, gregorian::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let scheme = DayPartScheme::LINGCHEN;
+    ///
+    /// assert_eq!(scheme.day_part(3.try_into()?), DayPart::SmallHours);
+    /// assert_eq!(scheme.day_part(23.try_into()?), DayPart::Midnight);
+    /// assert_eq!(scheme.day_part(5.try_into()?), DayPart::EarlyMorning);
+    ///
+    /// let time = LinearTime {
+    ///     day_part: true,
+    ///     hour: 2.try_into()?,
+    ///     minute: 15.try_into()?,
+    ///     second: None,
+    /// }
+    /// .with_day_part_scheme(scheme);
+    /// assert_eq!(time.to_chinese(Variant::Simplified), "凌晨两点十五分");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const LINGCHEN: DayPartScheme = DayPartScheme::new([
+        DayPart::SmallHours,
+        DayPart::SmallHours,
+        DayPart::SmallHours,
+        DayPart::SmallHours,
+        DayPart::SmallHours,
+        DayPart::EarlyMorning,
+        DayPart::EarlyMorning,
+        DayPart::EarlyMorning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Midday,
+        DayPart::Midday,
+        DayPart::Midday,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::EarlyEvening,
+        DayPart::EarlyEvening,
+        DayPart::EarlyEvening,
+        DayPart::Evening,
+        DayPart::Evening,
+        DayPart::Evening,
+        DayPart::Midnight,
+    ]);
+
+    /// Just `上午` - from 0 to 11 - and `下午` - from 12 to 23 - for contexts
+    /// such as meeting invitations.
+    ///
+    /// ```
+    /// use chinese_format::{*, gregorian::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let scheme = DayPartScheme::AM_PM;
+    ///
+    /// assert_eq!(scheme.day_part(6.try_into()?), DayPart::Morning);
+    /// assert_eq!(scheme.day_part(12.try_into()?), DayPart::Afternoon);
+    ///
+    /// let meeting = LinearTime {
+    ///     day_part: true,
+    ///     hour: 19.try_into()?,
+    ///     minute: 30.try_into()?,
+    ///     second: None,
+    /// }
+    /// .with_day_part_scheme(scheme);
+    /// assert_eq!(meeting.to_chinese(Variant::Simplified), "下午七点三十分");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const AM_PM: DayPartScheme = DayPartScheme::new([
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Morning,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
+        DayPart::Afternoon,
     ]);
+
+    /// Like [AM_PM](Self::AM_PM), but with `中午` at 12.
+    ///
+    /// ```
+    /// use chinese_format::{*, gregorian::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let scheme = DayPartScheme::AM_NOON_PM;
+    ///
+    /// assert_eq!(scheme.day_part(11.try_into()?), DayPart::Morning);
+    /// assert_eq!(scheme.day_part(12.try_into()?), DayPart::Midday);
+    /// assert_eq!(scheme.day_part(13.try_into()?), DayPart::Afternoon);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const AM_NOON_PM: DayPartScheme = {
+        let mut parts = Self::AM_PM.parts;
+        parts[12] = DayPart::Midday;
+        Self::new(parts)
+    };
+
+    /// Creates a scheme from the day parts of the hours - from 0 to 23.
+    pub const fn new(parts: [DayPart; 24]) -> Self {
+        Self { parts }
+    }
+
+    /// Tries to assign the given hours to a day part - failing
+    /// with [HourOutOfRange] if any hour is not in the 0..=23 range.
+    pub fn with_hours(
+        self,
+        hours: impl IntoIterator<Item = u8>,
+        day_part: DayPart,
+    ) -> Result<Self, HourOutOfRange> {
+        let mut parts = self.parts;
+
+        for hour in hours {
+            *parts.get_mut(hour as usize).ok_or(HourOutOfRange(hour))? = day_part;
+        }
+
+        Ok(Self { parts })
+    }
+
+    /// The day part assigned to the given hour.
+    pub fn day_part(&self, hour: Hour24) -> DayPart {
+        self.parts[hour.clock_value().0 as usize]
+    }
+
+    /// The day parts of the hours - from 0 to 23.
+    pub fn parts(&self) -> &[DayPart; 24] {
+        &self.parts
+    }
+
+    /// Iterates over each hour - from 0 to 23 - paired with its day part,
+    /// so that the mapping can be inspected:
+    ///
+    /// ```
+    /// use chinese_format::gregorian::*;
+    ///
+    /// let small_hours: Vec<u8> = DayPartScheme::LINGCHEN
+    ///     .iter()
+    ///     .filter(|(_, day_part)| *day_part == DayPart::SmallHours)
+    ///     .map(|(hour, _)| hour.into())
+    ///     .collect();
+    ///
+    /// assert_eq!(small_hours, vec![0, 1, 2, 3, 4]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Hour24, DayPart)> + '_ {
+        self.parts.iter().enumerate().map(|(hour, day_part)| {
+            (
+                Hour24::try_from(hour as u8).expect("The hour is in the 0..=23 range"),
+                *day_part,
+            )
+        })
+    }
+}
+
+/// The default [DayPartScheme] is [DayPartScheme::TRADITIONAL].
+impl Default for DayPartScheme {
+    fn default() -> Self {
+        Self::TRADITIONAL
+    }
 }
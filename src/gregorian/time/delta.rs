@@ -1,5 +1,9 @@
-use super::{Hour12, Minute};
-use crate::{chinese_vec, Chinese, ChineseFormat, Variant};
+use super::{Hour12, Minute, Second};
+use crate::{
+    chinese_vec,
+    duration::{DurationUnit, TimeDuration},
+    Chinese, ChineseFormat, CompositionStyle, CountBase, EmptyPlaceholder, Variant,
+};
 
 /// Time expressed as minutes (a *delta*) past/to an hour.
 ///
@@ -11,91 +15,104 @@ use crate::{chinese_vec, Chinese, ChineseFormat, Variant};
 /// # fn main() -> GenericResult<()> {
 /// let o_clock = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 0.try_into()?
+///     minute: 0.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(o_clock.to_chinese(Variant::Simplified), "六点钟");
 /// assert_eq!(o_clock.to_chinese(Variant::Traditional), "六點鐘");
 ///
 /// let past_one = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 1.try_into()?
+///     minute: 1.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(past_one.to_chinese(Variant::Simplified), "六点过一分");
 /// assert_eq!(past_one.to_chinese(Variant::Traditional), "六點過一分");
 ///
 /// let past_five = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 5.try_into()?
+///     minute: 5.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(past_five.to_chinese(Variant::Simplified), "六点过五分");
 /// assert_eq!(past_five.to_chinese(Variant::Traditional), "六點過五分");
 ///
 /// let past_fourteen = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 14.try_into()?
+///     minute: 14.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(past_fourteen.to_chinese(Variant::Simplified), "六点过十四分");
 /// assert_eq!(past_fourteen.to_chinese(Variant::Traditional), "六點過十四分");
 ///
 /// let quarter = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 15.try_into()?
+///     minute: 15.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(quarter.to_chinese(Variant::Simplified), "六点刻");
 /// assert_eq!(quarter.to_chinese(Variant::Traditional), "六點刻");
 ///
 /// let past_sixteen = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 16.try_into()?
+///     minute: 16.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(past_sixteen.to_chinese(Variant::Simplified), "六点过十六分");
 /// assert_eq!(past_sixteen.to_chinese(Variant::Traditional), "六點過十六分");
 ///
 /// let past_twenty_nine = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 29.try_into()?
+///     minute: 29.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(past_twenty_nine.to_chinese(Variant::Simplified), "六点过二十九分");
 /// assert_eq!(past_twenty_nine.to_chinese(Variant::Traditional), "六點過二十九分");
 ///
 /// let half = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 30.try_into()?
+///     minute: 30.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(half.to_chinese(Variant::Simplified), "六点半");
 /// assert_eq!(half.to_chinese(Variant::Traditional), "六點半");
 ///
 /// let twenty_nine_to = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 31.try_into()?
+///     minute: 31.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(twenty_nine_to.to_chinese(Variant::Simplified), "七点差二十九分");
 /// assert_eq!(twenty_nine_to.to_chinese(Variant::Traditional), "七點差二十九分");
 ///
 /// let sixteen_to = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 44.try_into()?
+///     minute: 44.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(sixteen_to.to_chinese(Variant::Simplified), "七点差十六分");
 /// assert_eq!(sixteen_to.to_chinese(Variant::Traditional), "七點差十六分");
 ///
 /// let three_quarters = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 45.try_into()?
+///     minute: 45.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(three_quarters.to_chinese(Variant::Simplified), "六点三刻");
 /// assert_eq!(three_quarters.to_chinese(Variant::Traditional), "六點三刻");
 ///
 /// let fourteen_to = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 46.try_into()?
+///     minute: 46.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(fourteen_to.to_chinese(Variant::Simplified), "七点差十四分");
 /// assert_eq!(fourteen_to.to_chinese(Variant::Traditional), "七點差十四分");
 ///
 /// let one_to = DeltaTime {
 ///     hour: 6.try_into()?,
-///     minute: 59.try_into()?
+///     minute: 59.try_into()?,
+///     second: None
 /// };
 /// assert_eq!(one_to.to_chinese(Variant::Simplified), "七点差一分");
 /// assert_eq!(one_to.to_chinese(Variant::Traditional), "七點差一分");
@@ -103,6 +120,53 @@ use crate::{chinese_vec, Chinese, ChineseFormat, Variant};
 /// # Ok(())
 /// # }
 /// ```
+///
+/// When the second is declared - and is not 0 - the time is always expressed
+/// as a delta past the hour, with half minutes read as `半分钟` or `一分半钟`:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let half_minute = DeltaTime {
+///     hour: 6.try_into()?,
+///     minute: 0.try_into()?,
+///     second: Some(30.try_into()?)
+/// };
+/// assert_eq!(half_minute.to_chinese(Variant::Simplified), "六点过半分钟");
+/// assert_eq!(half_minute.to_chinese(Variant::Traditional), "六點過半分鐘");
+///
+/// let one_and_a_half = DeltaTime {
+///     hour: 6.try_into()?,
+///     minute: 1.try_into()?,
+///     second: Some(30.try_into()?)
+/// };
+/// assert_eq!(one_and_a_half.to_chinese(Variant::Simplified), "六点过一分半钟");
+///
+/// let thirty_five = DeltaTime {
+///     hour: 6.try_into()?,
+///     minute: 0.try_into()?,
+///     second: Some(35.try_into()?)
+/// };
+/// assert_eq!(thirty_five.to_chinese(Variant::Simplified), "六点过三十五秒");
+///
+/// let minutes_and_seconds = DeltaTime {
+///     hour: 6.try_into()?,
+///     minute: 45.try_into()?,
+///     second: Some(12.try_into()?)
+/// };
+/// assert_eq!(minutes_and_seconds.to_chinese(Variant::Simplified), "六点过四十五分十二秒");
+///
+/// let zero_seconds = DeltaTime {
+///     hour: 6.try_into()?,
+///     minute: 45.try_into()?,
+///     second: Some(0.try_into()?)
+/// };
+/// assert_eq!(zero_seconds.to_chinese(Variant::Simplified), "六点三刻");
+///
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DeltaTime {
     /// The hour, as displayed by the *hour* hand of an analog clock.
@@ -110,6 +174,9 @@ pub struct DeltaTime {
 
     /// The minute, as displayed by the *minute* hand of an analog clock.
     pub minute: Minute,
+
+    /// Optionally, the second - for second-level timing.
+    pub second: Option<Second>,
 }
 
 const ZHONG: (&str, &str) = ("钟", "鐘");
@@ -122,9 +189,57 @@ const BAN: &str = "半";
 
 const CHA: &str = "差";
 
+/// The components of a [DeltaTime] can be joined via a [CompositionStyle]:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let time = DeltaTime {
+///     hour: 3.try_into()?,
+///     minute: 30.try_into()?,
+///     second: None
+/// };
+///
+/// assert_eq!(
+///     time.to_chinese_composed(Variant::Simplified, CompositionStyle::Spaced),
+///     "三点 半"
+/// );
+/// # Ok(())
+/// # }
+/// ```
 impl ChineseFormat for DeltaTime {
     fn to_chinese(&self, variant: Variant) -> Chinese {
-        match self.minute.into() {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let minute: u8 = self.minute.into();
+
+        match self.second.map(u8::from) {
+            None | Some(0) => {}
+
+            Some(30) => {
+                let minutes = TimeDuration::half(minute as CountBase, DurationUnit::Minute);
+
+                return chinese_vec!(variant, [self.hour, GUO, minutes]).collect_composed(style);
+            }
+
+            Some(second) => {
+                return chinese_vec!(
+                    variant,
+                    [
+                        self.hour,
+                        GUO,
+                        EmptyPlaceholder::new(&(minute > 0).then_some(self.minute)),
+                        TimeDuration::new(second as CountBase, DurationUnit::Second)
+                    ]
+                )
+                .collect_composed(style)
+            }
+        }
+
+        match minute {
             0 => chinese_vec!(variant, [self.hour, ZHONG]),
 
             1..=14 | 16..=29 => chinese_vec!(variant, [self.hour, GUO, self.minute]),
@@ -144,6 +259,6 @@ impl ChineseFormat for DeltaTime {
                 ]
             ),
         }
-        .collect()
+        .collect_composed(style)
     }
 }
@@ -0,0 +1,136 @@
+use super::{Hour24, Minute, Second};
+use crate::{chinese_vec, Chinese, ChineseFormat, DigitGlyphSet, Glyphs, LeftPadder, Variant};
+
+/// How a [DigitalClock] is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DigitalClockStyle {
+    /// Each component is read as a number - padded with `零` when lower than 10 -
+    /// as in `零八点零五分`.
+    #[default]
+    Padded,
+
+    /// Each component is read digit by digit - with `〇` for 0 - and
+    /// the components are separated by `:`, as in `〇八:〇五`.
+    Digits,
+}
+
+/// Time as shown by a digital clock - as in `零八点零五分` or `〇八:〇五` -
+/// for example when emulating transit displays.
+///
+/// Unlike [LinearTime](super::LinearTime), every component always has 2 digits -
+/// and `2` is always read as `二`.
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let departure = DigitalClock {
+///     hour: 8.try_into()?,
+///     minute: 5.try_into()?,
+///     second: None,
+///     style: DigitalClockStyle::Padded
+/// };
+/// assert_eq!(departure.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零八点零五分".into(),
+///     omissible: false
+/// });
+/// assert_eq!(departure.to_chinese(Variant::Traditional), "零八點零五分");
+///
+/// let digits = DigitalClock {
+///     style: DigitalClockStyle::Digits,
+///     ..departure
+/// };
+/// assert_eq!(digits.to_chinese(Variant::Simplified), "〇八:〇五");
+///
+/// let late = DigitalClock {
+///     hour: 22.try_into()?,
+///     minute: 10.try_into()?,
+///     second: Some(2.try_into()?),
+///     style: DigitalClockStyle::Padded
+/// };
+/// assert_eq!(late.to_chinese(Variant::Simplified), "二十二点十分零二秒");
+///
+/// let late_digits = DigitalClock {
+///     style: DigitalClockStyle::Digits,
+///     ..late
+/// };
+/// assert_eq!(late_digits.to_chinese(Variant::Simplified), "二二:一〇:〇二");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DigitalClock {
+    pub hour: Hour24,
+
+    pub minute: Minute,
+
+    pub second: Option<Second>,
+
+    pub style: DigitalClockStyle,
+}
+
+const FEN: &str = "分";
+
+const MIAO: &str = "秒";
+
+const SEPARATOR: &str = ":";
+
+impl DigitalClock {
+    fn components(&self) -> Vec<u8> {
+        [
+            Some(u8::from(self.hour)),
+            Some(u8::from(self.minute)),
+            self.second.map(u8::from),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn padded(value: u8, unit: &dyn ChineseFormat, variant: Variant) -> String {
+        let component = chinese_vec!(variant, [value, unit]).collect().logograms;
+
+        LeftPadder {
+            logogram: '零',
+            min_width: if value < 10 { 3 } else { 0 },
+            source: &component,
+        }
+        .to_chinese(variant)
+        .logograms
+        .into_owned()
+    }
+
+    fn digits(value: u8, variant: Variant) -> String {
+        [value / 10, value % 10]
+            .into_iter()
+            .map(|digit| DigitGlyphSet::CIRCLE_ZERO.digit(digit, variant))
+            .collect()
+    }
+}
+
+impl ChineseFormat for DigitalClock {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let components = self.components();
+
+        let logograms = match self.style {
+            DigitalClockStyle::Padded => components
+                .into_iter()
+                .zip([&Glyphs::DIAN as &dyn ChineseFormat, &FEN, &MIAO])
+                .map(|(value, unit)| Self::padded(value, unit, variant))
+                .collect::<String>(),
+
+            DigitalClockStyle::Digits => components
+                .into_iter()
+                .map(|value| Self::digits(value, variant))
+                .collect::<Vec<_>>()
+                .join(SEPARATOR),
+        };
+
+        Chinese {
+            logograms: logograms.into(),
+            omissible: false,
+        }
+    }
+}
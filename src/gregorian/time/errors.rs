@@ -21,6 +21,28 @@ impl Display for HourOutOfRange {
 
 impl Error for HourOutOfRange {}
 
+/// Error for when an hour does not belong to any [NightWatch](super::NightWatch) -
+/// that is, it is not in the 19..=4 range.
+///
+/// ```
+/// use chinese_format::gregorian::*;
+///
+/// assert_eq!(
+///     NotNightWatch(12).to_string(),
+///     "Hour outside of the night watches: 12"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NotNightWatch(pub u8);
+
+impl Display for NotNightWatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hour outside of the night watches: {}", self.0)
+    }
+}
+
+impl Error for NotNightWatch {}
+
 /// Error for when the *minute* part of a time expression is out of range.
 ///
 /// ```
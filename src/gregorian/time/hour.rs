@@ -1,10 +1,40 @@
-use crate::{chinese_vec, Chinese, ChineseFormat, Count, Measure, Variant};
+use crate::{chinese_vec, Chinese, ChineseFormat, Count, Glyphs, Measure, Variant};
 
-const HOUR_UNIT: (&str, &str) = ("点", "點");
+const HOUR_UNIT: Glyphs = Glyphs::DIAN;
 
 /// The generic hour - independent of the specific time format.
+///
+/// It is implemented by both [Hour12](super::Hour12) and [Hour24](super::Hour24);
+/// every implementation is also a [Measure] - and therefore a [ChineseFormat] -
+/// having its clock value followed by `点`/`點`.
+///
+/// This enables functions that are generic over the time format:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// fn on_the_hour<H: Hour>(hour: H, variant: Variant) -> Chinese {
+///     chinese_vec!(variant, [hour.clock_value(), Glyphs::DIAN, ("整", "整")]).collect()
+/// }
+///
+/// # fn main() -> GenericResult<()> {
+/// let h19: Hour24 = 19.try_into()?;
+/// assert_eq!(on_the_hour(h19, Variant::Simplified), "十九点整");
+///
+/// let h7: Hour12 = h19.into();
+/// assert_eq!(on_the_hour(h7, Variant::Traditional), "七點整");
+///
+/// assert_eq!(h7.clock_value(), &Count(7));
+/// assert_eq!(h7.to_chinese(Variant::Simplified), "七点");
+/// # Ok(())
+/// # }
+/// ```
 pub trait Hour {
-    /// The number representing the hour on a clock.
+    /// The number representing the hour on a clock - that is, the number
+    /// actually read: in the 1..=12 range for [Hour12](super::Hour12)
+    /// and in the 0..=23 range for [Hour24](super::Hour24).
+    ///
+    /// Being a [Count], it is read as `两` when equal to 2.
     fn clock_value(&self) -> &Count;
 }
 
@@ -135,6 +135,13 @@ impl From<Hour24> for Hour12 {
 }
 
 /// Conversion from [u8] is only allowed for the 1..=12 range.
+/// [Hour12] can be infallibly converted to [u8].
+impl From<Hour12> for u8 {
+    fn from(source: Hour12) -> Self {
+        source.0 .0 as u8
+    }
+}
+
 impl TryFrom<u8> for Hour12 {
     type Error = HourOutOfRange;
 
@@ -44,6 +44,43 @@ impl Hour for Hour24 {
     }
 }
 
+impl Hour24 {
+    /// Adds the given hours - possibly negative - wrapping around the day;
+    /// the number of days carried over is returned as well.
+    ///
+    /// ```
+    /// use chinese_format::gregorian::*;
+    ///
+    /// # fn main() -> chinese_format::GenericResult<()> {
+    /// let twenty_two: Hour24 = 22.try_into()?;
+    ///
+    /// assert_eq!(twenty_two.add_hours(1), (23.try_into()?, 0));
+    /// assert_eq!(twenty_two.add_hours(3), (1.try_into()?, 1));
+    /// assert_eq!(twenty_two.add_hours(-23), (23.try_into()?, -1));
+    ///
+    /// assert_eq!(twenty_two.add_hours(i64::MAX), (5.try_into()?, 384_307_168_202_282_326));
+    /// assert_eq!(twenty_two.add_hours(i64::MIN), (14.try_into()?, -384_307_168_202_282_325));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_hours(self, hours: i64) -> (Self, i64) {
+        //Widened, so that any offset can be added without overflowing
+        let total = self.0 .0 as i128 + hours as i128;
+
+        (
+            Self(Count(total.rem_euclid(24) as CountBase)),
+            total.div_euclid(24) as i64,
+        )
+    }
+}
+
+/// [Hour24] can be infallibly converted to [u8].
+impl From<Hour24> for u8 {
+    fn from(source: Hour24) -> Self {
+        source.0 .0 as u8
+    }
+}
+
 impl TryFrom<u8> for Hour24 {
     type Error = HourOutOfRange;
 
@@ -1,5 +1,7 @@
-use super::{DayPart, Hour, Hour12, Hour24, Minute, Second};
-use crate::{chinese_vec, Chinese, ChineseFormat, EmptyPlaceholder, LeftPadder, Variant};
+use super::{DayPart, DayPartScheme, Hour, Hour12, Hour24, Minute, Second};
+use crate::{
+    chinese_vec, Chinese, ChineseFormat, CompositionStyle, EmptyPlaceholder, LeftPadder, Variant,
+};
 
 /// Time expression showing time linearly - from day part down to second.
 ///
@@ -18,7 +20,7 @@ use crate::{chinese_vec, Chinese, ChineseFormat, EmptyPlaceholder, LeftPadder, V
 ///         second: None,
 ///     }.to_chinese(Variant::Simplified),
 ///     Chinese {
-///         logograms: "十九点二十四分".to_string(),
+///         logograms: "十九点二十四分".into(),
 ///         omissible: false
 ///     }
 /// );
@@ -76,6 +78,9 @@ use crate::{chinese_vec, Chinese, ChineseFormat, EmptyPlaceholder, LeftPadder, V
 /// # Ok(())
 /// # }
 /// ```
+///
+/// The day part is assigned by the default [DayPartScheme], which can be customized
+/// via [with_day_part_scheme](LinearTime::with_day_part_scheme).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct LinearTime {
     /// Describes whether the [DayPart] should be included: in this case,
@@ -92,14 +97,158 @@ pub struct LinearTime {
     pub second: Option<Second>,
 }
 
+impl LinearTime {
+    /// Adds the given hours - possibly negative - wrapping around the day;
+    /// the number of days carried over is returned as well.
+    ///
+    /// ```
+    /// use chinese_format::{*, gregorian::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let time = LinearTime {
+    ///     day_part: false,
+    ///     hour: 21.try_into()?,
+    ///     minute: 30.try_into()?,
+    ///     second: None,
+    /// };
+    ///
+    /// let (later, days) = time.add_hours(5);
+    /// assert_eq!(later.to_chinese(Variant::Simplified), "两点三十分");
+    /// assert_eq!(days, 1);
+    ///
+    /// let (farthest, days) = time.add_hours(i64::MAX);
+    /// assert_eq!(farthest.to_chinese(Variant::Simplified), "四点三十分");
+    /// assert_eq!(days, 384_307_168_202_282_326);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_hours(self, hours: i64) -> (Self, i64) {
+        let (hour, days) = self.hour.add_hours(hours);
+
+        (Self { hour, ..self }, days)
+    }
+
+    /// Adds the given minutes - possibly negative - carrying over to the hour
+    /// and wrapping around the day; the number of days carried over is returned as well.
+    ///
+    /// ```
+    /// use chinese_format::{*, gregorian::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let time = LinearTime {
+    ///     day_part: false,
+    ///     hour: 23.try_into()?,
+    ///     minute: 40.try_into()?,
+    ///     second: None,
+    /// };
+    ///
+    /// let (later, days) = time.add_minutes(45);
+    /// assert_eq!(later.to_chinese(Variant::Simplified), "零点二十五分");
+    /// assert_eq!(days, 1);
+    ///
+    /// let (earlier, days) = time.add_minutes(-25);
+    /// assert_eq!(earlier.to_chinese(Variant::Simplified), "二十三点十五分");
+    /// assert_eq!(days, 0);
+    ///
+    /// let (farthest, days) = time.add_minutes(i64::MAX);
+    /// assert_eq!(farthest.to_chinese(Variant::Simplified), "十七点四十七分");
+    /// assert_eq!(days, 6_405_119_470_038_039);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_minutes(self, minutes: i64) -> (Self, i64) {
+        let (minute, hours) = self.minute.add_minutes(minutes);
+
+        Self { minute, ..self }.add_hours(hours)
+    }
+
+    /// Assigns the [DayPart] to the hour via the given [DayPartScheme],
+    /// instead of the default one.
+    ///
+    /// ```
+    /// use chinese_format::{*, gregorian::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let time = LinearTime {
+    ///     day_part: true,
+    ///     hour: 19.try_into()?,
+    ///     minute: 30.try_into()?,
+    ///     second: None,
+    /// };
+    /// assert_eq!(time.to_chinese(Variant::Simplified), "傍晚七点三十分");
+    ///
+    /// let evening = time.with_day_part_scheme(
+    ///     DayPartScheme::default().with_hours(19..=19, DayPart::Evening)?
+    /// );
+    /// assert_eq!(evening.to_chinese(Variant::Simplified), "晚上七点三十分");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_day_part_scheme(self, day_part_scheme: DayPartScheme) -> SchemedLinearTime {
+        SchemedLinearTime {
+            time: self,
+            day_part_scheme,
+        }
+    }
+}
+
+/// The components - day part, hour, minute and second - can be
+/// joined via a [CompositionStyle]:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let time = LinearTime {
+///     day_part: true,
+///     hour: 9.try_into()?,
+///     minute: 20.try_into()?,
+///     second: None,
+/// };
+///
+/// assert_eq!(
+///     time.to_chinese_composed(Variant::Simplified, CompositionStyle::Spaced),
+///     "上午 九点 二十分"
+/// );
+/// # Ok(())
+/// # }
+/// ```
 impl ChineseFormat for LinearTime {
     fn to_chinese(&self, variant: Variant) -> Chinese {
-        let (day_part, hour): (Option<DayPart>, Box<dyn Hour>) = if self.day_part {
-            let day_part: DayPart = self.hour.into();
-            let hour12: Hour12 = self.hour.into();
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        self.with_day_part_scheme(DayPartScheme::default())
+            .to_chinese_composed(variant, style)
+    }
+}
+
+/// [LinearTime] whose [DayPart] is assigned via a custom [DayPartScheme] -
+/// as returned by [LinearTime::with_day_part_scheme].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SchemedLinearTime {
+    /// The time expression.
+    pub time: LinearTime,
+
+    /// Assigns the [DayPart] to the hour, when the `day_part` of the time is true.
+    pub day_part_scheme: DayPartScheme,
+}
+
+impl ChineseFormat for SchemedLinearTime {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let time = &self.time;
+
+        let (day_part, hour): (Option<DayPart>, Box<dyn Hour>) = if time.day_part {
+            let day_part = self.day_part_scheme.day_part(time.hour);
+            let hour12: Hour12 = time.hour.into();
             (Some(day_part), Box::new(hour12))
         } else {
-            (None, Box::new(self.hour))
+            (None, Box::new(time.hour))
         };
 
         chinese_vec!(
@@ -110,15 +259,15 @@ impl ChineseFormat for LinearTime {
                 EmptyPlaceholder::new(&LeftPadder {
                     logogram: '零',
                     min_width: 3,
-                    source: &self.minute
+                    source: &time.minute
                 }),
                 EmptyPlaceholder::new(&LeftPadder {
                     logogram: '零',
                     min_width: 3,
-                    source: &self.second
+                    source: &time.second
                 })
             ]
         )
-        .collect()
+        .collect_composed(style)
     }
 }
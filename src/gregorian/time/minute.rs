@@ -40,6 +40,35 @@ impl Minute {
     pub fn complement(&self) -> Result<Self, MinuteOutOfRange> {
         (60 - self.0).try_into()
     }
+
+    /// Adds the given minutes - possibly negative - wrapping around the hour;
+    /// the number of hours carried over is returned as well.
+    ///
+    /// ```
+    /// use chinese_format::gregorian::*;
+    ///
+    /// # fn main() -> chinese_format::GenericResult<()> {
+    /// let fifty: Minute = 50.try_into()?;
+    ///
+    /// assert_eq!(fifty.add_minutes(5), (55.try_into()?, 0));
+    /// assert_eq!(fifty.add_minutes(15), (5.try_into()?, 1));
+    /// assert_eq!(fifty.add_minutes(130), (0.try_into()?, 3));
+    /// assert_eq!(fifty.add_minutes(-55), (55.try_into()?, -1));
+    ///
+    /// assert_eq!(fifty.add_minutes(i64::MAX), (57.try_into()?, 153_722_867_280_912_930));
+    /// assert_eq!(fifty.add_minutes(i64::MIN), (42.try_into()?, -153_722_867_280_912_930));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_minutes(self, minutes: i64) -> (Self, i64) {
+        //Widened, so that any offset can be added without overflowing
+        let total = self.0 as i128 + minutes as i128;
+
+        (
+            Self(total.rem_euclid(60) as u8),
+            total.div_euclid(60) as i64,
+        )
+    }
 }
 
 /// [Minute] can be instantiated via conversion from integers in the 0..=59 range.
@@ -1,5 +1,6 @@
 mod day_part;
 mod delta;
+mod digital_clock;
 mod errors;
 mod hour;
 mod hour12;
@@ -7,14 +8,16 @@ mod hour24;
 mod linear;
 mod minute;
 mod second;
-
-use hour::*;
+mod traditional_time;
 
 pub use day_part::*;
 pub use delta::*;
+pub use digital_clock::*;
 pub use errors::*;
+pub use hour::*;
 pub use hour12::*;
 pub use hour24::*;
 pub use linear::*;
 pub use minute::*;
 pub use second::*;
+pub use traditional_time::*;
@@ -25,6 +25,36 @@ define_measure!(pub, Second, pub(self), u8, "秒");
 /// # Ok(())
 /// # }
 /// ```
+impl Second {
+    /// Adds the given seconds - possibly negative - wrapping around the minute;
+    /// the number of minutes carried over is returned as well.
+    ///
+    /// ```
+    /// use chinese_format::gregorian::*;
+    ///
+    /// # fn main() -> chinese_format::GenericResult<()> {
+    /// let forty: Second = 40.try_into()?;
+    ///
+    /// assert_eq!(forty.add_seconds(19), (59.try_into()?, 0));
+    /// assert_eq!(forty.add_seconds(20), (0.try_into()?, 1));
+    /// assert_eq!(forty.add_seconds(-41), (59.try_into()?, -1));
+    ///
+    /// assert_eq!(forty.add_seconds(i64::MAX), (47.try_into()?, 153_722_867_280_912_930));
+    /// assert_eq!(forty.add_seconds(i64::MIN), (32.try_into()?, -153_722_867_280_912_930));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_seconds(self, seconds: i64) -> (Self, i64) {
+        //Widened, so that any offset can be added without overflowing
+        let total = self.0 as i128 + seconds as i128;
+
+        (
+            Self(total.rem_euclid(60) as u8),
+            total.div_euclid(60) as i64,
+        )
+    }
+}
+
 impl TryFrom<u8> for Second {
     type Error = SecondOutOfRange;
 
@@ -0,0 +1,5 @@
+mod night_watch;
+mod shi_chen;
+
+pub use night_watch::*;
+pub use shi_chen::*;
@@ -0,0 +1,89 @@
+use super::super::{Hour, Hour24, NotNightWatch};
+use super::ShiChen;
+use crate::{Chinese, ChineseFormat, Dizhi, Variant};
+
+/// Each of the 5 traditional night watches (`更`) - from `一更` to `五更`.
+///
+/// Each watch matches a [ShiChen], from `戌时` - beginning at 19 - to `寅时` - ending at 5;
+/// therefore, it can be obtained from an [Hour24] via a *fallible* conversion,
+/// returning [NotNightWatch] for the daytime hours:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let midnight = NightWatch::try_from(Hour24::try_from(0)?)?;
+///
+/// assert_eq!(midnight, NightWatch::Third);
+/// assert_eq!(midnight.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三更".into(),
+///     omissible: false
+/// });
+///
+/// let idiom = chinese_vec!(Variant::Simplified, [midnight, "半夜"]).collect();
+/// assert_eq!(idiom, "三更半夜");
+///
+/// let dusk = NightWatch::try_from(Hour24::try_from(19)?)?;
+/// assert_eq!(dusk.to_chinese(Variant::Traditional), "一更");
+/// assert_eq!(dusk.shi_chen(), ShiChen(Dizhi::Xu));
+///
+/// assert_eq!(NightWatch::try_from(Hour24::try_from(4)?)?, NightWatch::Fifth);
+///
+/// assert_eq!(
+///     NightWatch::try_from(Hour24::try_from(5)?),
+///     Err(NotNightWatch(5))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NightWatch {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+}
+
+impl NightWatch {
+    const ALL: [NightWatch; 5] = [
+        Self::First,
+        Self::Second,
+        Self::Third,
+        Self::Fourth,
+        Self::Fifth,
+    ];
+
+    const FIRST_SHI_CHEN: Dizhi = Dizhi::Xu;
+
+    const GENG: &'static str = "更";
+
+    /// The double-hour matching the watch.
+    pub fn shi_chen(self) -> ShiChen {
+        ShiChen(Self::FIRST_SHI_CHEN + self as isize)
+    }
+}
+
+impl TryFrom<Hour24> for NightWatch {
+    type Error = NotNightWatch;
+
+    fn try_from(hour24: Hour24) -> Result<Self, Self::Error> {
+        let ShiChen(dizhi) = hour24.into();
+
+        Self::ALL
+            .get(dizhi.offset_from(Self::FIRST_SHI_CHEN))
+            .copied()
+            .ok_or(NotNightWatch(hour24.clock_value().0 as u8))
+    }
+}
+
+impl ChineseFormat for NightWatch {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!("{}{}", (*self as u8 + 1).to_chinese(variant), Self::GENG).into(),
+            omissible: false,
+        }
+    }
+}
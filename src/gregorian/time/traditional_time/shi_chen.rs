@@ -0,0 +1,67 @@
+use super::super::{Hour, Hour24};
+use crate::{Chinese, ChineseFormat, Dizhi, Variant};
+
+/// Each of the 12 traditional double-hours (`时辰`/`時辰`) - named after the [Dizhi].
+///
+/// Each double-hour lasts 2 hours, starting from `子时` at 23.
+///
+/// It can be infallibly obtained by converting an [Hour24]:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let hour: Hour24 = 23.try_into()?;
+/// let shi_chen: ShiChen = hour.into();
+///
+/// assert_eq!(shi_chen, ShiChen(Dizhi::Zi));
+/// assert_eq!(shi_chen.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "子时".into(),
+///     omissible: false
+/// });
+/// assert_eq!(shi_chen.to_chinese(Variant::Traditional), "子時");
+///
+/// assert_eq!(ShiChen::from(Hour24::try_from(0)?), ShiChen(Dizhi::Zi));
+/// assert_eq!(ShiChen::from(Hour24::try_from(1)?), ShiChen(Dizhi::Chou));
+///
+/// let noon = ShiChen::from(Hour24::try_from(12)?);
+/// assert_eq!(noon.to_chinese(Variant::Simplified), "午时");
+/// assert_eq!(u8::from(noon.start_hour()), 11);
+///
+/// assert_eq!(u8::from(ShiChen(Dizhi::Zi).start_hour()), 23);
+/// assert_eq!(u8::from(ShiChen(Dizhi::Hai).start_hour()), 21);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShiChen(pub Dizhi);
+
+impl ShiChen {
+    const SHI: (&'static str, &'static str) = ("时", "時");
+
+    /// The hour at which the double-hour begins.
+    pub fn start_hour(self) -> Hour24 {
+        let start = (self.0.index() * 2 + 23) % 24;
+
+        Hour24::try_from(start as u8).expect("The start hour is in the 0..=23 range")
+    }
+}
+
+impl From<Hour24> for ShiChen {
+    fn from(hour24: Hour24) -> Self {
+        let hour = hour24.clock_value().0 as usize;
+
+        Self(Dizhi::from_index(hour.div_ceil(2)))
+    }
+}
+
+impl ChineseFormat for ShiChen {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!("{}{}", self.0.to_char(), Self::SHI.to_chinese(variant)).into(),
+            omissible: false,
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{chinese_vec, Chinese, ChineseFormat, Decimal, Sign, Typography, Variant};
+use digit_sequence::DigitSequence;
+
+/// The numerals used to render a [HumanizedNumber].
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NumeralStyle {
+    /// Chinese numerals - as in `一百二十三点四万`.
+    Chinese,
+
+    /// Arabic numerals - as in `123.4万`.
+    Arabic,
+}
+
+/// Options for rendering a [HumanizedNumber].
+///
+/// By default, Chinese numerals are used, with at most 1 fractional digit
+/// and half-width glyphs.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanizedStyle {
+    pub numerals: NumeralStyle,
+
+    /// The maximum number of fractional digits - after rounding half away
+    /// from zero and dropping the trailing zeros.
+    pub max_fraction_digits: usize,
+
+    /// Applied to the sign, digits and decimal separator of [Arabic](NumeralStyle::Arabic) numerals.
+    pub typography: Typography,
+}
+
+impl Default for HumanizedStyle {
+    fn default() -> Self {
+        Self {
+            numerals: NumeralStyle::Chinese,
+            max_fraction_digits: 1,
+            typography: Typography::default(),
+        }
+    }
+}
+
+/// Integer shortened via the largest suitable unit among `万`, `亿` and `万亿` -
+/// as usual when displaying statistics.
+///
+/// Values whose magnitude is less than `10000` are rendered as they are.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let views = HumanizedNumber {
+///     value: 1_234_000,
+///     style: HumanizedStyle::default()
+/// };
+/// assert_eq!(views.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一百二十三点四万".into(),
+///     omissible: false
+/// });
+/// assert_eq!(views.to_chinese(Variant::Traditional), "一百二十三點四萬");
+///
+/// let arabic_views = HumanizedNumber {
+///     value: 1_234_000,
+///     style: HumanizedStyle {
+///         numerals: NumeralStyle::Arabic,
+///         ..HumanizedStyle::default()
+///     }
+/// };
+/// assert_eq!(arabic_views.to_chinese(Variant::Simplified), "123.4万");
+/// assert_eq!(arabic_views.to_chinese(Variant::Traditional), "123.4萬");
+///
+/// let precise = HumanizedNumber {
+///     value: 356_789_000,
+///     style: HumanizedStyle {
+///         numerals: NumeralStyle::Arabic,
+///         max_fraction_digits: 2,
+///         ..HumanizedStyle::default()
+///     }
+/// };
+/// assert_eq!(precise.to_chinese(Variant::Simplified), "3.57亿");
+/// assert_eq!(precise.to_chinese(Variant::Traditional), "3.57億");
+///
+/// let round = HumanizedNumber {
+///     value: 20_000,
+///     style: HumanizedStyle::default()
+/// };
+/// assert_eq!(round.to_chinese(Variant::Simplified), "二万");
+///
+/// let carried = HumanizedNumber {
+///     value: 99_999_600,
+///     style: HumanizedStyle::default()
+/// };
+/// assert_eq!(carried.to_chinese(Variant::Simplified), "一亿");
+///
+/// let trillions = HumanizedNumber {
+///     value: -1_500_000_000_000,
+///     style: HumanizedStyle::default()
+/// };
+/// assert_eq!(trillions.to_chinese(Variant::Simplified), "负一点五万亿");
+///
+/// let small = HumanizedNumber {
+///     value: 9_999,
+///     style: HumanizedStyle {
+///         numerals: NumeralStyle::Arabic,
+///         ..HumanizedStyle::default()
+///     }
+/// };
+/// assert_eq!(small.to_chinese(Variant::Simplified), "9999");
+///
+/// let full_width = HumanizedNumber {
+///     value: -356_789_000,
+///     style: HumanizedStyle {
+///         numerals: NumeralStyle::Arabic,
+///         typography: Typography::FULL_WIDTH,
+///         ..HumanizedStyle::default()
+///     }
+/// };
+/// assert_eq!(full_width.to_chinese(Variant::Simplified), "－３．６亿");
+///
+/// let zero = HumanizedNumber {
+///     value: 0,
+///     style: HumanizedStyle::default()
+/// };
+/// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanizedNumber {
+    pub value: i128,
+    pub style: HumanizedStyle,
+}
+
+/// The units, each with its power of ten, from the smallest to the largest.
+const UNITS: [(u32, (&str, &str)); 3] =
+    [(4, ("万", "萬")), (8, ("亿", "億")), (12, ("万亿", "萬億"))];
+
+impl HumanizedNumber {
+    /// Divides the magnitude by the given power of ten,
+    /// returning the rounded result without trailing zeros.
+    fn scale(&self, power: u32) -> Decimal {
+        let magnitude = self.value.unsigned_abs();
+        let divisor = 10u128.pow(power);
+
+        let remainder_digits: Vec<u8> =
+            format!("{:0width$}", magnitude % divisor, width = power as usize)
+                .bytes()
+                .map(|byte| byte - b'0')
+                .collect();
+
+        let exact = Decimal {
+            integer: (magnitude / divisor) as i128,
+            fractional: remainder_digits
+                .try_into()
+                .expect("The digits are generated from an integer"),
+        };
+
+        let rounded = exact
+            .round(self.style.max_fraction_digits)
+            .expect("The integer part is far from its limits");
+
+        let mut digits: Vec<u8> = rounded.fractional.iter().copied().collect();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        let fractional: DigitSequence = digits
+            .try_into()
+            .expect("The digits come from a valid sequence");
+
+        Decimal {
+            integer: rounded.integer,
+            fractional,
+        }
+    }
+
+    fn numerals_to_chinese(&self, amount: &Decimal, variant: Variant) -> String {
+        match self.style.numerals {
+            NumeralStyle::Chinese => amount.to_chinese(variant).logograms.into_owned(),
+
+            NumeralStyle::Arabic => {
+                let text = if amount.fractional.is_empty() {
+                    amount.integer.to_string()
+                } else {
+                    format!("{}.{}", amount.integer, amount.fractional)
+                };
+
+                self.style.typography.apply(&text)
+            }
+        }
+    }
+}
+
+impl ChineseFormat for HumanizedNumber {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let magnitude = self.value.unsigned_abs();
+
+        let scaled_unit = UNITS
+            .iter()
+            .rev()
+            .find(|(power, _)| magnitude >= 10u128.pow(*power))
+            .map(|(power, unit)| (self.scale(*power), *power, *unit))
+            .map(|(amount, power, unit)| {
+                let next_unit = UNITS.iter().find(|(next_power, _)| *next_power > power);
+
+                match next_unit {
+                    Some((next_power, next_unit))
+                        if amount.integer >= 10i128.pow(next_power - power) =>
+                    {
+                        (self.scale(*next_power), *next_unit)
+                    }
+                    _ => (amount, unit),
+                }
+            });
+
+        let (amount, unit) = match scaled_unit {
+            Some((amount, unit)) => (amount, unit),
+            None => (
+                Decimal {
+                    integer: magnitude as i128,
+                    fractional: DigitSequence::new(),
+                },
+                ("", ""),
+            ),
+        };
+
+        let arabic_sign = if self.value < 0 {
+            self.style.typography.apply("-")
+        } else {
+            String::new()
+        };
+
+        let chinese_sign = Sign(self.value);
+
+        let sign: &dyn ChineseFormat = match self.style.numerals {
+            NumeralStyle::Chinese => &chinese_sign,
+            NumeralStyle::Arabic => &arabic_sign,
+        };
+
+        let logograms = chinese_vec!(
+            variant,
+            [sign, self.numerals_to_chinese(&amount, variant), unit]
+        )
+        .collect()
+        .logograms;
+
+        Chinese {
+            logograms,
+            omissible: self.value == 0,
+        }
+    }
+}
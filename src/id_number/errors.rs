@@ -0,0 +1,25 @@
+use std::{error::Error, fmt::Display};
+
+/// Error for when an [IdNumber](crate::IdNumber) contains a character
+/// that is neither an ASCII letter nor a digit.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     InvalidIdNumberCharacter('#').to_string(),
+///     "Invalid ID number character: #"
+/// );
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidIdNumberCharacter(pub char);
+
+impl Display for InvalidIdNumberCharacter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid ID number character: {}", self.0)
+    }
+}
+
+impl Error for InvalidIdNumberCharacter {}
@@ -0,0 +1,198 @@
+mod errors;
+
+use crate::{Chinese, ChineseFormat, ReadingStyle, Typography, Variant};
+
+pub use errors::*;
+
+/// How the letters of an [IdNumber] are read.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LetterStyle {
+    /// Letters are kept exactly as they are.
+    AsIs,
+
+    /// Letters are converted to uppercase.
+    Uppercase,
+
+    /// Each letter is preceded by its case - `大写`/`大寫` or `小写`/`小寫`.
+    Spelled,
+}
+
+/// Options for reading an [IdNumber].
+///
+/// By default, letters are kept as they are and digits are read
+/// via [ReadingStyle::Standard] - so `1` is read as `一`.
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IdNumberStyle {
+    /// How letters are read.
+    pub letter_style: LetterStyle,
+
+    /// How digits are read - for example, [ReadingStyle::Yao] reads `1` as `幺`,
+    /// as usual when spelling numbers aloud.
+    pub reading_style: ReadingStyle,
+
+    /// Applied to the letters - which can be rendered as full-width glyphs.
+    pub typography: Typography,
+}
+
+impl Default for IdNumberStyle {
+    fn default() -> Self {
+        Self {
+            letter_style: LetterStyle::AsIs,
+            reading_style: ReadingStyle::Standard,
+            typography: Typography::default(),
+        }
+    }
+}
+
+/// Alphanumeric identifier - such as an ID card or passport number -
+/// read character by character, for example in customer-service scripts.
+///
+/// Must be created via [try_new](Self::try_new); digits depend on the [ReadingStyle] -
+/// by default, from `零` to `九` - whereas letters depend on the [LetterStyle].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let id_card = IdNumber::try_new("11010519491231002X", IdNumberStyle::default())?;
+/// assert_eq!(id_card.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一一零一零五一九四九一二三一零零二X".into(),
+///     omissible: false
+/// });
+///
+/// let passport = IdNumber::try_new("e12345678", IdNumberStyle {
+///     letter_style: LetterStyle::Uppercase,
+///     reading_style: ReadingStyle::Yao,
+///     ..IdNumberStyle::default()
+/// })?;
+/// assert_eq!(passport.to_chinese(Variant::Simplified), "E幺二三四五六七八");
+///
+/// let radio = IdNumber::try_new("A1079", IdNumberStyle {
+///     reading_style: ReadingStyle::Military,
+///     ..IdNumberStyle::default()
+/// })?;
+/// assert_eq!(radio.to_chinese(Variant::Simplified), "A幺洞拐勾");
+///
+/// let spelled = IdNumber::try_new("Ab1", IdNumberStyle {
+///     letter_style: LetterStyle::Spelled,
+///     ..IdNumberStyle::default()
+/// })?;
+/// assert_eq!(spelled.to_chinese(Variant::Simplified), "大写A小写b一");
+/// assert_eq!(spelled.to_chinese(Variant::Traditional), "大寫A小寫b一");
+///
+/// let full_width = IdNumber::try_new("G1024", IdNumberStyle {
+///     typography: Typography::FULL_WIDTH,
+///     ..IdNumberStyle::default()
+/// })?;
+/// assert_eq!(full_width.to_chinese(Variant::Simplified), "Ｇ一零二四");
+///
+/// let empty = IdNumber::try_new("", IdNumberStyle::default())?;
+/// assert_eq!(empty.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IdNumber {
+    value: String,
+    style: IdNumberStyle,
+}
+
+impl IdNumber {
+    const UPPERCASE: (&'static str, &'static str) = ("大写", "大寫");
+
+    const LOWERCASE: (&'static str, &'static str) = ("小写", "小寫");
+
+    /// Tries to create an identifier - failing with [InvalidIdNumberCharacter]
+    /// if the value contains anything but ASCII letters and digits.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// let result = IdNumber::try_new("G-1024", IdNumberStyle::default());
+    /// assert_eq!(result, Err(InvalidIdNumberCharacter('-')));
+    /// ```
+    pub fn try_new(
+        value: &str,
+        style: IdNumberStyle,
+    ) -> Result<IdNumber, InvalidIdNumberCharacter> {
+        if let Some(invalid_char) = value.chars().find(|c| !c.is_ascii_alphanumeric()) {
+            return Err(InvalidIdNumberCharacter(invalid_char));
+        }
+
+        Ok(Self {
+            value: value.to_string(),
+            style,
+        })
+    }
+
+    /// The identifier, as originally passed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The reading options.
+    pub fn style(&self) -> IdNumberStyle {
+        self.style
+    }
+
+    fn letter_to_chinese(&self, letter: char, variant: Variant) -> String {
+        let typography = self.style.typography;
+
+        match self.style.letter_style {
+            LetterStyle::AsIs => typography.apply(&letter.to_string()),
+
+            LetterStyle::Uppercase => typography.apply(&letter.to_ascii_uppercase().to_string()),
+
+            LetterStyle::Spelled => {
+                let case = if letter.is_ascii_uppercase() {
+                    Self::UPPERCASE
+                } else {
+                    Self::LOWERCASE
+                };
+
+                format!(
+                    "{}{}",
+                    case.to_chinese(variant),
+                    typography.apply(&letter.to_string())
+                )
+            }
+        }
+    }
+
+    fn digit_to_chinese(&self, digit: char, variant: Variant) -> char {
+        let digit = digit
+            .to_digit(10)
+            .expect("The character was validated as a digit");
+
+        self.style.reading_style.digit(digit as u8, variant)
+    }
+}
+
+impl ChineseFormat for IdNumber {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: self
+                .value
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_digit() {
+                        self.digit_to_chinese(c, variant).to_string()
+                    } else {
+                        self.letter_to_chinese(c, variant)
+                    }
+                })
+                .collect(),
+            omissible: self.value.is_empty(),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    integer_to_logograms_with_options, Chinese, ChineseFormat, CountMethod, DigitGlyphSet,
+    EngineOptions, Variant,
+};
+
+/// How `一` is read before `十` - whose conventions vary across regions and contexts.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let read = |value, tens_one| StyledInteger {
+///     value,
+///     style: IntegerStyle {
+///         tens_one,
+///         ..IntegerStyle::default()
+///     }
+/// }
+/// .to_chinese(Variant::Simplified);
+///
+/// assert_eq!(read(117, TensOne::Standard), "一百一十七");
+/// assert_eq!(read(17, TensOne::Standard), "十七");
+///
+/// assert_eq!(read(17, TensOne::Explicit), "一十七");
+/// assert_eq!(read(-100_000, TensOne::Explicit), "负一十万");
+/// assert_eq!(read(30, TensOne::Explicit), "三十");
+///
+/// assert_eq!(read(117, TensOne::Omitted), "一百十七");
+/// assert_eq!(read(3010, TensOne::Omitted), "三千零十");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TensOne {
+    /// `一` is omitted only at the beginning of the number - as in `十七` and `一百一十七`.
+    #[default]
+    Standard,
+
+    /// `一` is always read - as in `一十七` and `一百一十七`.
+    Explicit,
+
+    /// `一` is never read - as in `十七` and `一百十七`.
+    Omitted,
+}
+
+/// How a run of zero digits between two non-zero digits is read -
+/// whose strictness varies across educational standards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ZeroRun {
+    /// A single `零` for the whole run - as in `三千零五`.
+    #[default]
+    Single,
+
+    /// A `零` for each zero digit between the two non-zero digits - as in `三千零零五`,
+    /// or `十万零零零零五` across the `万` group boundary; the zero digits
+    /// after the last non-zero digit are never read - as in `三千` or `一百零五万`.
+    PerDigit,
+}
+
+/// Whether the unit after the last digit is read - as in `一百五十` -
+/// or left implicit, as in the colloquial `一百五`.
+///
+/// The unit can be dropped only when it immediately follows a higher unit:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let read = |value, final_unit| StyledInteger {
+///     value,
+///     style: IntegerStyle {
+///         final_unit,
+///         ..IntegerStyle::default()
+///     }
+/// }
+/// .to_chinese(Variant::Simplified);
+///
+/// assert_eq!(read(150, FinalUnit::Dropped), "一百五");
+/// assert_eq!(read(3200, FinalUnit::Dropped), "三千二");
+/// assert_eq!(read(25_000, FinalUnit::Dropped), "二万五");
+///
+/// assert_eq!(read(1050, FinalUnit::Dropped), "一千零五十");
+/// assert_eq!(read(50, FinalUnit::Dropped), "五十");
+/// assert_eq!(read(150_000, FinalUnit::Dropped), "十五万");
+///
+/// assert_eq!(read(150, FinalUnit::Voiced), "一百五十");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FinalUnit {
+    /// The unit is always read - as in `一百五十`.
+    #[default]
+    Voiced,
+
+    /// The unit is omitted whenever unambiguous - as in `一百五`.
+    Dropped,
+}
+
+/// Rendering options for [StyledInteger].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IntegerStyle {
+    /// The logograms of the digits and of the small units -
+    /// such as [DigitGlyphSet::CIRCLE_ZERO], writing zero as `〇`.
+    pub glyphs: DigitGlyphSet,
+
+    /// Whether `一` is read before `十`.
+    pub tens_one: TensOne,
+
+    /// How a run of zero digits is read.
+    pub zero_run: ZeroRun,
+
+    /// Whether the unit after the last digit is read.
+    pub final_unit: FinalUnit,
+}
+
+/// Integer rendered according to an [IntegerStyle].
+///
+/// Like plain integers, it is [omissible](Chinese::omissible) only when zero.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let style = IntegerStyle {
+///     glyphs: DigitGlyphSet::CIRCLE_ZERO,
+///     ..IntegerStyle::default()
+/// };
+///
+/// assert_eq!(StyledInteger { value: 105, style }.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一百〇五".into(),
+///     omissible: false
+/// });
+///
+/// assert_eq!(StyledInteger { value: 0, style }.to_chinese(Variant::Traditional), Chinese {
+///     logograms: "〇".into(),
+///     omissible: true
+/// });
+///
+/// assert_eq!(
+///     StyledInteger { value: -3005, style: IntegerStyle::default() }.to_chinese(Variant::Simplified),
+///     "负三千零五"
+/// );
+///
+/// let explicit = IntegerStyle {
+///     tens_one: TensOne::Explicit,
+///     ..IntegerStyle::default()
+/// };
+/// assert_eq!(StyledInteger { value: 17, style: explicit }.to_chinese(Variant::Simplified), "一十七");
+///
+/// let omitted = IntegerStyle {
+///     tens_one: TensOne::Omitted,
+///     ..IntegerStyle::default()
+/// };
+/// assert_eq!(StyledInteger { value: 117, style: omitted }.to_chinese(Variant::Simplified), "一百十七");
+/// assert_eq!(StyledInteger { value: 10, style: omitted }.to_chinese(Variant::Simplified), "十");
+///
+/// let strict = IntegerStyle {
+///     zero_run: ZeroRun::PerDigit,
+///     ..IntegerStyle::default()
+/// };
+/// assert_eq!(StyledInteger { value: 3005, style: strict }.to_chinese(Variant::Simplified), "三千零零五");
+/// assert_eq!(StyledInteger { value: 100_005, style: strict }.to_chinese(Variant::Simplified), "十万零零零零五");
+/// assert_eq!(StyledInteger { value: 3050, style: strict }.to_chinese(Variant::Simplified), "三千零五十");
+///
+/// let colloquial = IntegerStyle {
+///     final_unit: FinalUnit::Dropped,
+///     ..IntegerStyle::default()
+/// };
+/// assert_eq!(StyledInteger { value: 150, style: colloquial }.to_chinese(Variant::Simplified), "一百五");
+/// assert_eq!(StyledInteger { value: -3200, style: colloquial }.to_chinese(Variant::Traditional), "負三千二");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StyledInteger {
+    pub value: i128,
+    pub style: IntegerStyle,
+}
+
+impl ChineseFormat for StyledInteger {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        //CountMethod::TenThousand supports the whole range of i128
+        let logograms = integer_to_logograms_with_options(
+            self.value,
+            variant,
+            CountMethod::TenThousand,
+            EngineOptions {
+                zero_run: self.style.zero_run,
+                glyphs: self.style.glyphs,
+                tens_one: self.style.tens_one,
+                final_unit: self.style.final_unit,
+            },
+        )
+        .expect("Converting an integer to Chinese should never fail!");
+
+        Chinese {
+            logograms: logograms.into(),
+            omissible: self.value == 0,
+        }
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        crate::pinyin::number_tokens(
+            &self.to_chinese(variant).logograms,
+            variant,
+            self.style.glyphs,
+        )
+    }
+}
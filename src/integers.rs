@@ -1,5 +1,121 @@
-use crate::{Chinese, ChineseFormat, Variant};
-use chinese_number::{ChineseCase, ChineseCountMethod, ChineseVariant};
+use crate::{
+    integer_to_logograms, integer_to_logograms_with_options, Chinese, ChineseFormat,
+    ChineseInteger, DigitGlyphSet, EngineOptions, TryChineseFormat, Variant,
+};
+use std::{
+    error::Error,
+    fmt::Display,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+    },
+};
+
+/// The ways in which the large units (万, 亿, 兆, ...) of an integer can be counted.
+///
+/// Conversions of plain integers always adopt [CountMethod::TenThousand],
+/// which is the standard in modern Chinese; the other methods can be
+/// selected by dedicated types such as [DecimalStyle](crate::DecimalStyle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CountMethod {
+    /// Each unit is 10 times the previous one - 十万 is 亿.
+    ///
+    /// **Please, note**: only supports absolute values less than `10^16`.
+    Low,
+
+    /// Each unit is 10000 times the previous one - 万万 is 亿; the modern standard.
+    TenThousand,
+
+    /// Starting from 亿, each unit is 10^8 times the previous one - 万万亿 is 兆.
+    Middle,
+
+    /// Starting from 亿, each unit is the square of the previous one - 亿亿 is 兆.
+    High,
+}
+
+/// The default [CountMethod] is [CountMethod::TenThousand].
+impl Default for CountMethod {
+    fn default() -> Self {
+        Self::TenThousand
+    }
+}
+
+/// Error for when an integer cannot be expressed via the requested [CountMethod].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     IntegerOutOfRange(-90).to_string(),
+///     "Integer out of range: -90"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IntegerOutOfRange<T>(pub T);
+
+impl<T: Display> Display for IntegerOutOfRange<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Integer out of range: {}", self.0)
+    }
+}
+
+impl<T: Display + std::fmt::Debug> Error for IntegerOutOfRange<T> {}
+
+/// Integer read via a given [CountMethod].
+///
+/// Unlike plain integers - always read via [CountMethod::TenThousand], which supports
+/// every value - the conversion can fail, so it is performed by
+/// [try_to_chinese](TryChineseFormat::try_to_chinese):
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let low = CountedInteger {
+///     value: 100_000,
+///     count_method: CountMethod::Low
+/// };
+/// assert_eq!(low.try_to_chinese(Variant::Simplified), Ok(Chinese {
+///     logograms: "一亿".into(),
+///     omissible: false
+/// }));
+///
+/// let standard = CountedInteger {
+///     count_method: CountMethod::TenThousand,
+///     ..low
+/// };
+/// assert_eq!(standard.try_to_chinese(Variant::Traditional).unwrap(), "十萬");
+///
+/// let too_large = CountedInteger {
+///     value: i128::MIN,
+///     count_method: CountMethod::Low
+/// };
+/// assert_eq!(
+///     too_large.try_to_chinese(Variant::Simplified),
+///     Err(IntegerOutOfRange(i128::MIN))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CountedInteger<T> {
+    /// The integer to read.
+    pub value: T,
+
+    /// How the large units - such as `万` and `亿` - are counted.
+    pub count_method: CountMethod,
+}
+
+impl<T: ChineseInteger> TryChineseFormat for CountedInteger<T> {
+    type Error = IntegerOutOfRange<T>;
+
+    fn try_to_chinese(&self, variant: Variant) -> Result<Chinese, Self::Error> {
+        let logograms = integer_to_logograms(self.value, variant, self.count_method)
+            .ok_or(IntegerOutOfRange(self.value))?;
+
+        Ok(Chinese {
+            logograms: logograms.into(),
+            omissible: self.value.magnitude() == 0,
+        })
+    }
+}
 
 macro_rules! impl_number_to_chinese {
     ($type:ty) => {
@@ -8,22 +124,36 @@ macro_rules! impl_number_to_chinese {
         /// Of the Chinese outcomes, only 零 is [omissible](crate::Chinese::omissible).
         impl ChineseFormat for $type {
             fn to_chinese(&self, variant: Variant) -> Chinese {
-                let logograms: String = chinese_number::NumberToChinese::to_chinese(
+                self.to_chinese_with_glyphs(variant, DigitGlyphSet::STANDARD)
+            }
+
+            fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+                //CountMethod::TenThousand supports the whole range of every integer type
+                let logograms: String = integer_to_logograms_with_options(
                     *self,
-                    match variant {
-                        Variant::Simplified => ChineseVariant::Simple,
-                        Variant::Traditional => ChineseVariant::Traditional,
+                    variant,
+                    CountMethod::TenThousand,
+                    EngineOptions {
+                        glyphs,
+                        ..EngineOptions::default()
                     },
-                    ChineseCase::Lower,
-                    ChineseCountMethod::TenThousand,
                 )
                 .expect("Converting an integer to Chinese should never fail!");
 
                 Chinese {
-                    logograms,
+                    logograms: logograms.into(),
                     omissible: *self == 0,
                 }
             }
+
+            #[cfg(feature = "pinyin")]
+            fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+                crate::pinyin::number_tokens(
+                    &self.to_chinese(variant).logograms,
+                    variant,
+                    DigitGlyphSet::STANDARD,
+                )
+            }
         }
     };
 }
@@ -40,6 +170,106 @@ impl_number_to_chinese!(i32);
 impl_number_to_chinese!(i16);
 impl_number_to_chinese!(i8);
 
+impl_number_to_chinese!(usize);
+impl_number_to_chinese!(isize);
+
+macro_rules! impl_non_zero_to_chinese {
+    ($($type: ty),+) => {
+        $(
+            /// Non-zero integers are converted just like the integers they wrap -
+            /// so they are never [omissible](crate::Chinese::omissible).
+            impl ChineseFormat for $type {
+                fn to_chinese(&self, variant: Variant) -> Chinese {
+                    self.get().to_chinese(variant)
+                }
+
+                fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+                    self.get().to_chinese_with_glyphs(variant, glyphs)
+                }
+
+                #[cfg(feature = "pinyin")]
+                fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+                    self.get().to_chinese_tokens(variant)
+                }
+            }
+        )+
+    };
+}
+
+impl_non_zero_to_chinese!(
+    NonZeroU128,
+    NonZeroU64,
+    NonZeroU32,
+    NonZeroU16,
+    NonZeroU8,
+    NonZeroUsize,
+    NonZeroI128,
+    NonZeroI64,
+    NonZeroI32,
+    NonZeroI16,
+    NonZeroI8,
+    NonZeroIsize
+);
+
+/// [Wrapping] values are converted just like the values they wrap.
+///
+/// ```
+/// use chinese_format::*;
+/// use std::num::Wrapping;
+///
+/// let counter = Wrapping(u8::MAX) + Wrapping(3);
+/// assert_eq!(counter.to_chinese(Variant::Simplified), "二");
+///
+/// assert_eq!(Wrapping(0i64).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+/// ```
+impl<T: ChineseFormat> ChineseFormat for Wrapping<T> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.0.to_chinese(variant)
+    }
+
+    fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+        self.0.to_chinese_with_glyphs(variant, glyphs)
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        self.0.to_chinese_tokens(variant)
+    }
+}
+
+/// [Saturating] values are converted just like the values they wrap.
+///
+/// ```
+/// use chinese_format::*;
+/// use std::num::Saturating;
+///
+/// let stock = Saturating(3u16) - Saturating(5);
+/// assert_eq!(stock.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+///
+/// let peak = Saturating(i8::MAX) + Saturating(1);
+/// assert_eq!(peak.to_chinese(Variant::Traditional), "一百二十七");
+/// ```
+impl<T: ChineseFormat> ChineseFormat for Saturating<T> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.0.to_chinese(variant)
+    }
+
+    fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+        self.0.to_chinese_with_glyphs(variant, glyphs)
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        self.0.to_chinese_tokens(variant)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +397,10 @@ mod tests {
                             test_case!(98u8, "九十八");
                         }
 
+                        it "should convert usize" {
+                            test_case!(98usize, "九十八");
+                        }
+
                         it "should convert i128" {
                             test_case!(98i128, "九十八");
                         }
@@ -186,6 +420,17 @@ mod tests {
                         it "should convert i8" {
                             test_case!(98i8, "九十八");
                         }
+
+                        it "should convert isize" {
+                            test_case!(-98isize, "负九十八");
+                        }
+
+                        it "should convert the non-zero types" {
+                            test_case!(std::num::NonZeroU32::new(98).unwrap(), "九十八");
+                            test_case!(std::num::NonZeroUsize::MAX, usize::MAX.to_chinese(Variant::Simplified));
+                            test_case!(std::num::NonZeroI64::new(-98).unwrap(), "负九十八");
+                            test_case!(std::num::NonZeroI128::MIN, i128::MIN.to_chinese(Variant::Simplified));
+                        }
                     }
                 }
 
@@ -204,6 +449,31 @@ mod tests {
                     }
                 }
 
+                describe "when converting the extreme values of each type" {
+                    macro_rules! test_extremes {
+                        ($($type: ty),+) => {
+                            $(
+                                for value in [<$type>::MIN, <$type>::MAX] {
+                                    for variant in [Variant::Simplified, Variant::Traditional] {
+                                        eq!(
+                                            CountedInteger {
+                                                value,
+                                                count_method: CountMethod::TenThousand
+                                            }
+                                            .try_to_chinese(variant),
+                                            Ok(value.to_chinese(variant))
+                                        );
+                                    }
+                                }
+                            )+
+                        }
+                    }
+
+                    it "should never fail" {
+                        test_extremes!(u128, u64, u32, u16, u8, usize, i128, i64, i32, i16, i8, isize);
+                    }
+                }
+
                 describe "in terms of omissible" {
                     describe "when converting 0" {
                         it "should be omissible" {
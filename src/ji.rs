@@ -0,0 +1,100 @@
+use crate::{Chinese, ChineseFormat, Measure, Variant};
+
+const JI: (&str, &str) = ("几", "幾");
+
+/// The interrogative/indeterminate number `几` - asking "how many?"
+/// or standing for "a few" - usable in place of a number.
+///
+/// Together with [JiQuantity] and [JiMeasure], it supports question templates,
+/// for example in language drills:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(Ji.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "几".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Ji.to_chinese(Variant::Traditional), "幾");
+///
+/// let people = Ji::of(ClassifiedUnit(("个", "個"), "人"));
+/// assert_eq!(people.to_chinese(Variant::Simplified), "几个人");
+/// assert_eq!(people.to_chinese(Variant::Traditional), "幾個人");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ji;
+
+impl Ji {
+    /// Places `几` before the given unit.
+    pub fn of<U: ChineseFormat>(unit: U) -> JiQuantity<U> {
+        JiQuantity(unit)
+    }
+}
+
+impl ChineseFormat for Ji {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        JI.to_chinese(variant)
+    }
+}
+
+/// `几` followed by a unit - such as `几个人` - created via [Ji::of].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JiQuantity<U: ChineseFormat>(pub U);
+
+impl<U: ChineseFormat> ChineseFormat for JiQuantity<U> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!("{}{}", Ji.to_chinese(variant), self.0.to_chinese(variant)).into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Renders the unit of any [Measure] preceded by `几` instead of its value -
+/// reusing the very same measure types employed for the answers.
+///
+/// ```
+/// use chinese_format::{*, length::*};
+///
+/// let distance = JiMeasure(Kilometer::new(3));
+/// assert_eq!(distance.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "几公里".into(),
+///     omissible: false
+/// });
+///
+/// define_measure_with_classifier!(Months, ("个", "個"), "月");
+/// assert_eq!(JiMeasure(Months::new(2)).to_chinese(Variant::Traditional), "幾個月");
+/// ```
+///
+/// It also works with time components:
+///
+/// ```
+/// # #[cfg(feature = "gregorian")]
+/// # {
+/// use chinese_format::{*, gregorian::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let hour: Hour12 = 3.try_into()?;
+/// assert_eq!(JiMeasure(hour).to_chinese(Variant::Simplified), "几点");
+/// assert_eq!(JiMeasure(hour).to_chinese(Variant::Traditional), "幾點");
+/// # Ok(())
+/// # }
+/// # main().unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JiMeasure<M: Measure>(pub M);
+
+impl<M: Measure> ChineseFormat for JiMeasure<M> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!(
+                "{}{}",
+                Ji.to_chinese(variant),
+                self.0.unit().to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
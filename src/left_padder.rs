@@ -20,7 +20,7 @@ use std::iter;
 ///     source: &"五分"
 /// };
 /// assert_eq!(left_padder.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "零五分".to_string(),
+///     logograms: "零五分".into(),
 ///     omissible: false
 /// });
 ///
@@ -44,7 +44,7 @@ use std::iter;
 ///     source: &""
 /// };
 /// assert_eq!(left_padder.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "####".to_string(),
+///     logograms: "####".into(),
 ///     omissible: true
 /// });
 /// ```
@@ -65,7 +65,7 @@ impl<'a> ChineseFormat for LeftPadder<'a> {
         let padding: String = iter::repeat(self.logogram).take(padding_length).collect();
 
         Chinese {
-            logograms: format!("{}{}", padding, source_chinese.logograms),
+            logograms: format!("{}{}", padding, source_chinese.logograms).into(),
             omissible: source_chinese.omissible,
         }
     }
@@ -8,7 +8,7 @@
 //! assert_eq!(
 //!     two_km.to_chinese(Variant::Simplified),
 //!     Chinese {
-//!         logograms: "两公里".to_string(),
+//!         logograms: "两公里".into(),
 //!         omissible: false
 //!     }
 //! );
@@ -16,7 +16,7 @@
 //! assert_eq!(
 //!     two_km.to_chinese(Variant::Traditional),
 //!     Chinese {
-//!         logograms: "兩公里".to_string(),
+//!         logograms: "兩公里".into(),
 //!         omissible: false
 //!     }
 //! );
@@ -27,7 +27,7 @@
 //! assert_eq!(
 //!     two_cm.to_chinese(Variant::Simplified),
 //!     Chinese {
-//!         logograms: "两厘米".to_string(),
+//!         logograms: "两厘米".into(),
 //!         omissible: false
 //!     }
 //! );
@@ -35,7 +35,7 @@
 //! assert_eq!(
 //!     two_cm.to_chinese(Variant::Traditional),
 //!     Chinese {
-//!         logograms: "兩釐米".to_string(),
+//!         logograms: "兩釐米".into(),
 //!         omissible: false
 //!     }
 //! );
@@ -46,7 +46,7 @@
 //! assert_eq!(
 //!     zero_m.to_chinese(Variant::Simplified),
 //!     Chinese {
-//!         logograms: "零米".to_string(),
+//!         logograms: "零米".into(),
 //!         omissible: true
 //!     }
 //! );
@@ -54,12 +54,68 @@
 //! assert_eq!(
 //!     zero_m.to_chinese(Variant::Traditional),
 //!     Chinese {
-//!         logograms: "零米".to_string(),
+//!         logograms: "零米".into(),
 //!         omissible: true
 //!     }
 //! );
+//!
+//! assert_eq!(LightYear::new(4).to_chinese(Variant::Simplified), "四光年");
+//! assert_eq!(Nanometer::new(2).to_chinese(Variant::Traditional), "兩納米");
+//! assert_eq!(Nanometer::new(1500).humanize().to_chinese(Variant::Simplified), "一点五微米");
+//! assert_eq!(LightYear::new(1).convert::<Meter>(), Some(Meter::new(9_460_730_472_580_800)));
+//! ```
+//!
+//! With the `digit-sequence` feature, [Decimal](crate::Decimal)-valued measures are available
+//! for scientific content - such as `DecimalNanometer`:
+//!
 //! ```
-use crate::define_count_measure;
+//! # #[cfg(feature = "digit-sequence")]
+//! # {
+//! use chinese_format::{*, length::*};
+//!
+//! let wavelength = DecimalNanometer(Decimal {
+//!     integer: 532,
+//!     fractional: 8u8.into()
+//! });
+//! assert_eq!(wavelength.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "五百三十二点八纳米".into(),
+//!     omissible: false
+//! });
+//! assert_eq!(wavelength.to_chinese(Variant::Traditional), "五百三十二點八納米");
+//!
+//! let width = DecimalMicrometer(Decimal {
+//!     integer: 2,
+//!     fractional: 5u8.into()
+//! });
+//! assert_eq!(width.to_chinese(Variant::Simplified), "二点五微米");
+//!
+//! let distance = DecimalLightYear(Decimal {
+//!     integer: 4,
+//!     fractional: 24u8.into()
+//! });
+//! assert_eq!(distance.to_chinese(Variant::Simplified), "四点二四光年");
+//! # }
+//! ```
+use crate::{define_count_measure, impl_scaled_measure, CountBase, Dimension};
+#[cfg(feature = "digit-sequence")]
+use crate::{define_no_copy_measure, Decimal};
+
+/// The [Dimension] of the length measures, whose base unit is [Nanometer].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Length;
+
+impl Dimension for Length {
+    const HUMANIZED_UNITS: &'static [(CountBase, (&'static str, &'static str))] = &[
+        (1_000_000_000_000, ("公里", "公里")),
+        (1_000_000_000, ("米", "米")),
+        (10_000_000, ("厘米", "釐米")),
+        (1_000_000, ("毫米", "毫米")),
+        (1_000, ("微米", "微米")),
+        (1, ("纳米", "納米")),
+    ];
+}
+
+define_count_measure!(pub, LightYear, "光年");
 
 define_count_measure!(pub, Kilometer, "公里");
 
@@ -72,3 +128,34 @@ define_count_measure!(pub, Decimeter, "分米");
 define_count_measure!(pub, Centimeter, ("厘米", "釐米"));
 
 define_count_measure!(pub, Millimeter, "毫米");
+
+define_count_measure!(pub, Micrometer, "微米");
+
+define_count_measure!(pub, Nanometer, ("纳米", "納米"));
+
+impl_scaled_measure!(Length, LightYear, 9_460_730_472_580_800_000_000_000);
+
+impl_scaled_measure!(Length, Kilometer, 1_000_000_000_000);
+
+impl_scaled_measure!(Length, HalfKilometer, 500_000_000_000);
+
+impl_scaled_measure!(Length, Meter, 1_000_000_000);
+
+impl_scaled_measure!(Length, Decimeter, 100_000_000);
+
+impl_scaled_measure!(Length, Centimeter, 10_000_000);
+
+impl_scaled_measure!(Length, Millimeter, 1_000_000);
+
+impl_scaled_measure!(Length, Micrometer, 1_000);
+
+impl_scaled_measure!(Length, Nanometer, 1);
+
+#[cfg(feature = "digit-sequence")]
+define_no_copy_measure!(pub, DecimalMicrometer, pub, Decimal, "微米");
+
+#[cfg(feature = "digit-sequence")]
+define_no_copy_measure!(pub, DecimalNanometer, pub, Decimal, ("纳米", "納米"));
+
+#[cfg(feature = "digit-sequence")]
+define_no_copy_measure!(pub, DecimalLightYear, pub, Decimal, "光年");
@@ -4,18 +4,81 @@
 //! As a consequence, the library provides:
 //!
 //! - **ready-made conversions** for standard data types (integers,
-//!   strings, [Option], pairs, ...) - but also for fairly specific types like [DigitSequence](digit_sequence::DigitSequence).
+//!   strings, [Option], pairs, ...) - but also for fairly specific types like [DigitSequence](digit_sequence::DigitSequence); fallible conversions - such as integers read via a given [CountMethod] - are supported by the [TryChineseFormat] trait.
 //!
-//! - **Gregorian date/time**, in the [gregorian] module, in different formats via [DateBuilder](gregorian::DateBuilder), [LinearTime](gregorian::LinearTime) and [DeltaTime](gregorian::DeltaTime).
+//! - **Gregorian date/time**, in the [gregorian] module, in different formats via [DateBuilder](gregorian::DateBuilder), [LinearTime](gregorian::LinearTime) and [DeltaTime](gregorian::DeltaTime) - down to the second, as in `六点过一分半钟` - as well as:
+//!   - customizable day parts - including `凌晨` and a minimal `上午`/`下午` scheme - via [DayPartScheme](gregorian::DayPartScheme).
+//!   - digital clocks - such as `〇八:〇五` - via [DigitalClock](gregorian::DigitalClock).
+//!   - traditional double-hours - such as `子时` - via [ShiChen](gregorian::ShiChen), and night watches - such as `三更` - via [NightWatch](gregorian::NightWatch).
+//!   - wrapping time arithmetic, and the public [Hour](gregorian::Hour) trait - for formatters generic over the hour format.
 //!
-//! - **Monetary units**, in the [currency] module - at present, [RenminbiCurrency](currency::RenminbiCurrency) (人民币).
+//! - **Monetary units**, in the [currency] module - at present, [RenminbiCurrency](currency::RenminbiCurrency) (人民币) - as well as contract amounts pairing digits and financial logograms, such as `人民币1,234.56元（大写：壹仟贰佰叁拾肆元伍角陆分）`, via [LegalAmount](currency::LegalAmount).
 //!
-//! - **Dedicated numeric types** - such as [Decimal], [Fraction] and [Sign].
+//! - **Dedicated numeric types** - such as [Decimal], [Float], [Fraction], [Percent], [Cheng] and [Sign].
 //!
-//! - the [ChineseVec] sequence, to simplify the manipulation of _arbitrary
-//!   chains of logograms_, as well as **placeholders**.
+//! - **Combinators** - such as [OrElse], [Surround], [MapLogograms], [Labeled] and [Bilingual] - as building blocks for conditional formatting, reports and bilingual signage.
 //!
-//! - the [Measure] trait and its related macros - especially [define_measure].
+//! - **Comparisons** - such as `比去年多百分之十五` or `比A贵三十块` - via [Comparison].
+//!
+//! - **Polite phrases** - such as `请在三点前到达` or `您的订单已发货` - via [Polite], as well as phrases with value slots via [PhraseTemplate].
+//!
+//! - **Composition styles** - joining the components of composite expressions via nothing, `、` or a space - via [CompositionStyle].
+//!
+//! - **Integer styles** - writing zero as `〇`, as in `二〇二四年`, or choosing between `一百十七` and `一百一十七`, `三千零五` and `三千零零五`, or `一百五十` and `一百五` - via [IntegerStyle] - as well as complete sets of numeric logograms via [DigitGlyphSet] - including Suzhou numerals, such as `〡〨〇〥`.
+//!
+//! - **Question templates** - such as `几个人`, `几点` or `几月几号` - via [Ji] and [JiMeasure], reusing the types of the answers.
+//!
+//! - **Zero policies** - such as the standard `零` insertion between non-empty components - via [ZeroPolicy].
+//!
+//! - **Colloquial counts** - such as `我们俩` or `我们仨` - via [StyledCount] and [CountStyle].
+//!
+//! - **Strict digit-by-digit readings** - never using `两`, as required by verification codes and lottery numbers - via [DigitReading], as well as readings avoiding the digits that sound alike, such as `幺拐洞`, via [OtpReading] - and masked card numbers, such as `尾号四三二一`, via [MaskedNumber].
+//! - **License plates** - such as `京A·12345`, optionally read digit by digit - via [LicensePlate].
+//! - **Software versions** - such as `三点二点一版` or `V三.二.一` - via [Version].
+//! - **Odds and probabilities** - such as `一赔三` or `概率为百分之二十五` - via [Odds] and [Probability].
+//! - **Option labels** - such as `甲`, `乙`, `丙`, `丁` or `选项A` - for questionnaires, via [OptionLabel].
+//! - **Heavenly stems and earthly branches** - as cyclic sequences supporting offsets - via [Tiangan] and [Dizhi], as well as their sexagenary pairs - such as `甲辰` - via [GanZhi].
+//!
+//! - **Arabic numerals** for mixed output - such as `1,234,567` or `1234.5万` - via [ArabicNumber].
+//!
+//! - **Typographic control** over the ASCII characters in the output - such as full-width digits - via [Typography].
+//!
+//! - **Physical measures** - in modules such as [length], [weight], [power], [energy] and [frequency].
+//!
+//! - **Geographic coordinates** - such as `北纬三十九度五十四分` - as well as angles and compass directions, such as `四十五度角` or `东北偏北` - in the [geo] module.
+//!
+//! - **Data sizes** - such as `两百兆` or `三点五个G` - in the [data] module.
+//!
+//! - **Time spans** - including half units, such as `两个半小时` or `三岁半`, as well as [std::time::Duration] values, such as `三小时二十分钟`, and relative times, such as `三天前` or `刚刚` - in the [duration] module.
+//!
+//! - **Display width** computation and padding - counting 2 terminal cells per logogram - via [display_width] and [pad_to_display_width] - as well as aligned tables, in the [report] module.
+//!
+//! - **Omissibility policies** - overriding when values count as omissible - via [OmissibleWhen].
+//!
+//! - **Document numbers** - such as `国发〔2024〕12号` - in the [documents] module.
+//!
+//! - **People's height and weight** - such as `一米七八` - in the [body] module.
+//!
+//! - **Person references** - such as `王先生` - in the [names] module.
+//!
+//! - **Recipe quantities** - such as `两勺糖` or `适量盐` - in the [recipe] module.
+//!
+//! - **School scores and grades** - such as `九十五分`, `满分` or `A等` - in the [education] module.
+//!
+//! - **Match clock expressions** - such as `上半场第十五分钟` - in the [sports] module.
+//!
+//! - **Temperatures and weather reports** - such as `最低零下三度` - in the [temperature] module.
+//!
+//! - the [prelude] module, as well as the [DynChinese] type - for storing heterogeneous formatters.
+//!
+//! - the [Glyphs] pair - usable in `const` contexts - with its registry of common variant-aware pairs, such as `点`/`點`.
+//!
+//! - the [ChineseVec] sequence - also buildable via [ChineseVecBuilder] - to simplify the manipulation of _arbitrary
+//!   chains of logograms_ - and to assemble sentences via [CollectOptions], as well as **placeholders** - such as the generic [Placeholder].
+//!
+//! - **Streaming** - writing the logograms segment by segment, via [to_chinese_parts](ChineseFormat::to_chinese_parts), into any [std::fmt::Write] or [std::io::Write] - via [to_chinese_fmt](ChineseFormat::to_chinese_fmt) and [to_chinese_io](ChineseFormat::to_chinese_io).
+//!
+//! - the [Measure] trait and its related macros - especially [define_measure] - as well as [ScaledMeasure], for unit conversions, [MultiUnitMeasure], for values split across units, [VerbalMeasure], for frequency expressions such as `第三次`, [Distributive], for expressions such as `每个人` or `天天`, and [SemanticUnit], stating the meaning of ambiguous units such as `分`.
 //!
 //! # Features
 //!
@@ -25,49 +88,181 @@
 //!
 //!   - enables conversions to Chinese for [DigitSequence](https://crates.io/crates/digit-sequence).
 //!
-//!   - enables the [Decimal] and [IntegerPart] types.
+//!   - enables the [Decimal] and [IntegerPart] types - as well as [Float], for `f32`/`f64` values.
+//!
+//!   - enables the [DigitSequenceFormat] type, for grouped digit-by-digit readings.
+//!
+//!   - enables the [IdNumber] type, for reading alphanumeric identifiers.
+//!
+//!   - enables the [address] module - postal codes, floors, rooms and phone numbers.
+//!
+//!   - enables the [HumanizedNumber] type, for shortening big numbers via `万`/`亿`.
+//!
+//!   - enables the [DecimalPercent] type and the [finance] module - for interest rates, market changes and trends such as `基本持平`.
+//!
+//! - `arbitrary`: implements [Arbitrary](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) - generating valid values only - for [Fraction], [Decimal], [RenminbiCurrency](currency::RenminbiCurrency), [Date](gregorian::Date), [LinearTime](gregorian::LinearTime) and [DeltaTime](gregorian::DeltaTime) - when their features are enabled - for fuzzing and property testing.
+//!
+//! - `bench-internals`: enables the `bench_internals` module - performance counters such as the number of allocations per conversion - used by the benchmarks in the `benches` directory, runnable via `cargo bench --features bench-internals,currency`.
+//!
+//! - `chrono`: converts [chrono](https://crates.io/crates/chrono)'s `TimeDelta` to [SpokenDuration](duration::SpokenDuration).
 //!
 //! - `currency`: enables the whole [currency] module for monetary conversions.
 //!
-//! - `gregorian`: enables the [gregorian] module for date/time conversions.
+//! - `gregorian`: enables the [gregorian] module for date/time conversions - as well as the [travel] module, for itinerary sentences.
 //!
 //!   _Also enables_: `digit-sequence`.
+//!
+//! - `icu`: enables the `cldr` module, for interoperability with [ICU4X](https://github.com/unicode-org/icu4x) locales, dates and CLDR formatting.
+//!
+//!   _Also enables_: `gregorian`.
+//!
+//! - `minijinja`: enables the `templating` module, registering filters such as `chinese_number` and `chinese_date` in [minijinja](https://crates.io/crates/minijinja) environments.
+//!
+//! - `pinyin`: enables the [pinyin] module - including HTML `<ruby>` annotations and per-logogram tokens.
+//!
+//! - `ssml`: enables the `ssml` module - wrapping the output in [SSML](https://www.w3.org/TR/speech-synthesis11/) documents, with `<say-as>`, pauses and pinyin hints, for text-to-speech engines.
+//!
+//!   _Also enables_: `pinyin`.
+//!
+//! - `tracing`: enables the `trace` module, for recording values in [tracing](https://crates.io/crates/tracing) spans - both as Chinese and as raw numeric fields.
+mod arabic;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+mod cheng;
 mod chinese;
+mod collect_options;
+mod combinators;
+mod comparison;
+mod composition;
 mod count;
 #[cfg(feature = "digit-sequence")]
 mod decimal;
+mod digit_glyphs;
+mod digit_reading;
 #[cfg(feature = "digit-sequence")]
 mod digit_sequences;
+mod dyn_chinese;
 mod financial;
+#[cfg(feature = "digit-sequence")]
+mod float;
 mod fraction;
+mod ganzhi;
+mod glyphs;
+#[cfg(feature = "digit-sequence")]
+mod humanized;
+#[cfg(feature = "digit-sequence")]
+mod id_number;
+mod integer_style;
 mod integers;
+mod ji;
 mod left_padder;
+mod license_plate;
 mod measure;
+mod number_engine;
+mod odds;
+mod omissibility;
 mod option;
+mod option_label;
+mod parts;
+mod percent;
 mod placeholders;
+mod politeness;
 mod sign;
 mod strings;
 mod tuple;
+mod typography;
 mod vector;
+mod vector_builder;
+mod version;
+mod width;
+mod zero_policy;
 
+#[cfg(feature = "digit-sequence")]
+pub mod address;
+#[cfg(feature = "bench-internals")]
+pub mod bench_internals;
+pub mod body;
+#[cfg(feature = "icu")]
+pub mod cldr;
 #[cfg(feature = "currency")]
 pub mod currency;
+pub mod data;
+pub mod documents;
+pub mod duration;
+pub mod education;
+pub mod energy;
+#[cfg(feature = "digit-sequence")]
+pub mod finance;
+pub mod frequency;
+pub mod geo;
 #[cfg(feature = "gregorian")]
 pub mod gregorian;
 pub mod length;
+pub mod names;
+#[cfg(feature = "pinyin")]
+pub mod pinyin;
+pub mod power;
+pub mod prelude;
+pub mod recipe;
+pub mod report;
+pub mod sports;
+#[cfg(feature = "ssml")]
+pub mod ssml;
+pub mod temperature;
+#[cfg(feature = "minijinja")]
+pub mod templating;
+#[cfg(feature = "tracing")]
+pub mod trace;
+#[cfg(feature = "gregorian")]
+pub mod travel;
 pub mod weight;
 
+pub use arabic::*;
+pub use cheng::*;
 pub use chinese::*;
+pub use collect_options::*;
+pub use combinators::*;
+pub use comparison::*;
+pub use composition::*;
 pub use count::*;
 #[cfg(feature = "digit-sequence")]
 pub use decimal::*;
+pub use digit_glyphs::*;
+pub use digit_reading::*;
+#[cfg(feature = "digit-sequence")]
+pub use digit_sequences::*;
+pub use dyn_chinese::*;
 pub use financial::*;
+#[cfg(feature = "digit-sequence")]
+pub use float::*;
 pub use fraction::*;
+pub use ganzhi::*;
+pub use glyphs::*;
+#[cfg(feature = "digit-sequence")]
+pub use humanized::*;
+#[cfg(feature = "digit-sequence")]
+pub use id_number::*;
+pub use integer_style::*;
+pub use integers::*;
+pub use ji::*;
 pub use left_padder::*;
+pub use license_plate::*;
 pub use measure::*;
+pub use number_engine::*;
+pub use odds::*;
+pub use omissibility::*;
+pub use option_label::*;
+pub use parts::*;
+pub use percent::*;
 pub use placeholders::*;
+pub use politeness::*;
 pub use sign::*;
+pub use typography::*;
 pub use vector::*;
+pub use vector_builder::*;
+pub use version::*;
+pub use width::*;
+pub use zero_policy::*;
 
 use std::error::Error;
 
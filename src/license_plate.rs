@@ -0,0 +1,254 @@
+use crate::{Chinese, ChineseFormat, ReadingStyle, Variant};
+use std::{error::Error, fmt::Display};
+
+/// Error for when a string cannot be parsed as a [LicensePlate].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     InvalidLicensePlate::UnknownProvince('X').to_string(),
+///     "Unknown license plate province: X"
+/// );
+///
+/// assert_eq!(
+///     InvalidLicensePlate::InvalidAuthority('1').to_string(),
+///     "Invalid license plate authority: 1"
+/// );
+///
+/// assert_eq!(
+///     InvalidLicensePlate::InvalidSerial("12#45".to_string()).to_string(),
+///     "Invalid license plate serial: 12#45"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InvalidLicensePlate {
+    /// The first character is not the abbreviation of a province.
+    UnknownProvince(char),
+
+    /// The second character is not an ASCII letter.
+    InvalidAuthority(char),
+
+    /// The serial does not consist of 5 or 6 ASCII letters and digits.
+    InvalidSerial(String),
+}
+
+impl Display for InvalidLicensePlate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownProvince(province) => {
+                write!(f, "Unknown license plate province: {}", province)
+            }
+
+            Self::InvalidAuthority(authority) => {
+                write!(f, "Invalid license plate authority: {}", authority)
+            }
+
+            Self::InvalidSerial(serial) => write!(f, "Invalid license plate serial: {}", serial),
+        }
+    }
+}
+
+impl Error for InvalidLicensePlate {}
+
+/// How a [LicensePlate] is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum PlateReading {
+    /// The plate as written - as in `京A·12345`.
+    #[default]
+    Written,
+
+    /// The digits of the serial are read one by one - as in `京A一二三四五` -
+    /// according to the given [ReadingStyle], while letters are kept.
+    DigitByDigit(ReadingStyle),
+}
+
+/// The abbreviations of the provinces, in both variants.
+const PROVINCES: [(char, char); 31] = [
+    ('京', '京'),
+    ('津', '津'),
+    ('沪', '滬'),
+    ('渝', '渝'),
+    ('冀', '冀'),
+    ('豫', '豫'),
+    ('云', '雲'),
+    ('辽', '遼'),
+    ('黑', '黑'),
+    ('湘', '湘'),
+    ('皖', '皖'),
+    ('鲁', '魯'),
+    ('新', '新'),
+    ('苏', '蘇'),
+    ('浙', '浙'),
+    ('赣', '贛'),
+    ('鄂', '鄂'),
+    ('桂', '桂'),
+    ('甘', '甘'),
+    ('晋', '晉'),
+    ('蒙', '蒙'),
+    ('陕', '陝'),
+    ('吉', '吉'),
+    ('闽', '閩'),
+    ('贵', '貴'),
+    ('粤', '粵'),
+    ('青', '青'),
+    ('藏', '藏'),
+    ('川', '川'),
+    ('宁', '寧'),
+    ('琼', '瓊'),
+];
+
+/// Chinese vehicle license plate - such as `京A·12345` - for parking and traffic applications.
+///
+/// It can be parsed from a string containing the province abbreviation - in either variant -
+/// the letter of the issuing authority, an optional separator (`·`, `.`, `-` or a space)
+/// and a serial of 5 letters and digits - or 6, for new-energy vehicles;
+/// letters are converted to uppercase.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let plate = LicensePlate::try_from("京A12345")?;
+/// assert_eq!(plate.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "京A·12345".into(),
+///     omissible: false
+/// });
+///
+/// let spoken = plate.with_reading(PlateReading::DigitByDigit(ReadingStyle::Yao));
+/// assert_eq!(spoken.to_chinese(Variant::Simplified), "京A幺二三四五");
+///
+/// let shanghai = LicensePlate::try_from("沪b·d0r73")?;
+/// assert_eq!(shanghai.to_chinese(Variant::Simplified), "沪B·D0R73");
+/// assert_eq!(shanghai.to_chinese(Variant::Traditional), "滬B·D0R73");
+/// assert_eq!(shanghai.province(), '沪');
+/// assert_eq!(shanghai.authority(), 'B');
+/// assert_eq!(shanghai.serial(), "D0R73");
+///
+/// let new_energy = LicensePlate::try_from("粵B D12345")?
+///     .with_reading(PlateReading::DigitByDigit(ReadingStyle::Standard));
+/// assert_eq!(new_energy.to_chinese(Variant::Simplified), "粤BD一二三四五");
+/// assert_eq!(new_energy.to_chinese(Variant::Traditional), "粵BD一二三四五");
+///
+/// assert_eq!(
+///     LicensePlate::try_from("X A12345"),
+///     Err(InvalidLicensePlate::UnknownProvince('X'))
+/// );
+/// assert_eq!(
+///     LicensePlate::try_from("京12345"),
+///     Err(InvalidLicensePlate::InvalidAuthority('1'))
+/// );
+/// assert_eq!(
+///     LicensePlate::try_from("京A·1234"),
+///     Err(InvalidLicensePlate::InvalidSerial("1234".to_string()))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LicensePlate {
+    province: (char, char),
+    authority: char,
+    serial: String,
+    reading: PlateReading,
+}
+
+impl LicensePlate {
+    const SEPARATOR: char = '·';
+
+    /// Sets how the plate is rendered.
+    pub fn with_reading(self, reading: PlateReading) -> Self {
+        Self { reading, ..self }
+    }
+
+    /// The abbreviation of the province, in [Variant::Simplified].
+    pub fn province(&self) -> char {
+        self.province.0
+    }
+
+    pub fn authority(&self) -> char {
+        self.authority
+    }
+
+    pub fn serial(&self) -> &str {
+        &self.serial
+    }
+
+    fn serial_to_chinese(&self, variant: Variant) -> String {
+        match self.reading {
+            PlateReading::Written => format!("{}{}", Self::SEPARATOR, self.serial),
+
+            PlateReading::DigitByDigit(style) => self
+                .serial
+                .chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(|digit| style.digit(digit as u8, variant))
+                        .unwrap_or(c)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// [LicensePlate] can be parsed from a [str], as described in its documentation.
+impl TryFrom<&str> for LicensePlate {
+    type Error = InvalidLicensePlate;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.chars();
+
+        let province_char = chars.next().unwrap_or(' ');
+        let province = PROVINCES
+            .into_iter()
+            .find(|(simplified, traditional)| {
+                province_char == *simplified || province_char == *traditional
+            })
+            .ok_or(InvalidLicensePlate::UnknownProvince(province_char))?;
+
+        let authority = chars.next().unwrap_or(' ');
+        if !authority.is_ascii_alphabetic() {
+            return Err(InvalidLicensePlate::InvalidAuthority(authority));
+        }
+        let authority = authority.to_ascii_uppercase();
+
+        let rest = chars.as_str();
+        let serial = rest
+            .strip_prefix(['·', '.', '-', ' '])
+            .unwrap_or(rest)
+            .to_ascii_uppercase();
+
+        if !(5..=6).contains(&serial.chars().count())
+            || !serial.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(InvalidLicensePlate::InvalidSerial(serial));
+        }
+
+        Ok(Self {
+            province,
+            authority,
+            serial,
+            reading: PlateReading::default(),
+        })
+    }
+}
+
+impl ChineseFormat for LicensePlate {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let province = match variant {
+            Variant::Simplified => self.province.0,
+            Variant::Traditional => self.province.1,
+        };
+
+        Chinese {
+            logograms: format!(
+                "{}{}{}",
+                province,
+                self.authority,
+                self.serial_to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
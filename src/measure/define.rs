@@ -8,10 +8,14 @@
 /// * a dedicated implementation of [Measure](crate::Measure).
 ///
 /// * the derived implementations of [Debug], [Clone], [Copy],
-/// [PartialEq], [Eq], [PartialOrd], [Ord], [Hash].
+///   [PartialEq], [Eq], [PartialOrd], [Ord], [Hash].
 ///
 /// * a [From] conversion to the underlying data type
 ///
+/// Optionally, a final `value_transform = ...` argument - a `fn(Chinese) -> Chinese` -
+/// overrides [transform_value](crate::Measure::transform_value); for example,
+/// [plain_two](crate::plain_two) reads `2` as `二` instead of `两`.
+///
 /// ```
 /// use chinese_format::*;
 ///
@@ -29,14 +33,14 @@
 /// assert_eq!(
 ///     two.to_chinese(Variant::Simplified),
 ///     Chinese {
-///         logograms: "两厘米".to_string(),
+///         logograms: "两厘米".into(),
 ///         omissible: false
 ///     }
 /// );
 /// assert_eq!(
 ///     two.to_chinese(Variant::Traditional),
 ///     Chinese {
-///         logograms: "兩釐米".to_string(),
+///         logograms: "兩釐米".into(),
 ///         omissible: false
 ///     }
 /// );
@@ -50,14 +54,31 @@
 ///     
 ///     let zero = LiMi(Count(0));
 ///     assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
-///         logograms: "零厘米".to_string(),
+///         logograms: "零厘米".into(),
 ///         omissible: true
 ///     });
 ///     assert_eq!(zero.to_chinese(Variant::Traditional), Chinese {
-///         logograms: "零釐米".to_string(),
+///         logograms: "零釐米".into(),
 ///         omissible: true
 ///     });
 /// ```
+///
+/// For example, the traditional weight unit `两` requires `二` instead of `两`:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// define_measure!(pub, Liang, pub(self), Count, ("两", "兩"), value_transform = plain_two);
+///
+/// assert_eq!(Liang(Count(2)).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "二两".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Liang(Count(2)).to_chinese(Variant::Traditional), "二兩");
+///
+/// assert_eq!(Liang(Count(3)).to_chinese(Variant::Simplified), "三两");
+/// assert_eq!(Liang(Count(12)).to_chinese(Variant::Simplified), "十二两");
+/// ```
 #[macro_export]
 macro_rules! define_measure {
     (
@@ -72,6 +93,9 @@ macro_rules! define_measure {
 
         //The unit - implementing ChineseFormat.
         $unit: expr
+
+        //Optional fn(Chinese) -> Chinese, adjusting the value's translation.
+        $(, value_transform = $value_transform: expr)?
     ) => {
         $crate::define_measure!(
             ,
@@ -79,6 +103,7 @@ macro_rules! define_measure {
             $field_visibility,
             $field_type,
             $unit
+            $(, value_transform = $value_transform)?
         );
     };
 
@@ -97,6 +122,9 @@ macro_rules! define_measure {
 
         //The unit - implementing ChineseFormat.
         $unit: expr
+
+        //Optional fn(Chinese) -> Chinese, adjusting the value's translation.
+        $(, value_transform = $value_transform: expr)?
     ) => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
         $type_visibility struct $type($field_visibility $field_type);
@@ -109,6 +137,12 @@ macro_rules! define_measure {
             fn unit(&self) -> &dyn $crate::ChineseFormat {
                 &$unit
             }
+
+            $(
+                fn transform_value(&self, value: $crate::Chinese) -> $crate::Chinese {
+                    ($value_transform)(value)
+                }
+            )?
         }
 
         impl From<$type> for $field_type {
@@ -1,6 +1,8 @@
 /// Defines a struct implementing [Measure](crate::Measure) having a [Count](crate::Count) value.
 ///
-/// Also includes a `new` constructor:
+/// Also includes a `new` constructor, as well as [Add](std::ops::Add) between measures
+/// and [Mul](std::ops::Mul) by a [CountBase](crate::CountBase); the optional `value_transform` argument
+/// works as in [define_measure](crate::define_measure):
 ///
 /// ```
 /// use chinese_format::*;
@@ -15,6 +17,12 @@
 ///
 /// let four_count: Count = four_jin.into();
 /// assert_eq!(four_count, Count(4));
+///
+/// assert_eq!(four_jin + Jin::new(3), Jin::new(7));
+/// assert_eq!(four_jin * 5, Jin::new(20));
+///
+/// define_count_measure!(pub, Liang, ("两", "兩"), value_transform = plain_two);
+/// assert_eq!(Liang::new(2).to_chinese(Variant::Traditional), "二兩");
 /// ```
 #[macro_export]
 macro_rules! define_count_measure {
@@ -24,11 +32,15 @@ macro_rules! define_count_measure {
 
         //The unit - implementing ChineseFormat.
         $unit: expr
+
+        //Optional fn(Chinese) -> Chinese, adjusting the value's translation.
+        $(, value_transform = $value_transform: expr)?
     ) => {
         $crate::define_count_measure!(
             ,
             $type,
             $unit
+            $(, value_transform = $value_transform)?
         );
     };
 
@@ -41,13 +53,39 @@ macro_rules! define_count_measure {
 
         //The unit - implementing ChineseFormat.
         $unit: expr
+
+        //Optional fn(Chinese) -> Chinese, adjusting the value's translation.
+        $(, value_transform = $value_transform: expr)?
     ) => {
-        $crate::define_measure!($type_visibility, $type, pub(self), $crate::Count, $unit);
+        $crate::define_measure!(
+            $type_visibility,
+            $type,
+            pub(self),
+            $crate::Count,
+            $unit
+            $(, value_transform = $value_transform)?
+        );
 
         impl $type {
             pub fn new(value: $crate::CountBase) -> $type {
                 $type($crate::Count(value))
             }
         }
+
+        impl std::ops::Add for $type {
+            type Output = $type;
+
+            fn add(self, other: $type) -> $type {
+                $type::new(self.0 .0 + other.0 .0)
+            }
+        }
+
+        impl std::ops::Mul<$crate::CountBase> for $type {
+            type Output = $type;
+
+            fn mul(self, factor: $crate::CountBase) -> $type {
+                $type::new(self.0 .0 * factor)
+            }
+        }
     };
 }
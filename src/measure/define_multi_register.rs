@@ -13,6 +13,9 @@
 ///
 /// * the [Measure](crate::Measure) implementation, when asked its [unit](crate::Measure::unit), will return one of the given units according to its `formal` field.
 ///
+/// Each unit can be a `(simplified, traditional)` pair, and the optional `value_transform`
+/// argument works as in [define_measure](crate::define_measure).
+///
 /// ```
 /// use chinese_format::*;
 ///
@@ -28,7 +31,7 @@
 /// assert_eq!(two_formal.unit().to_chinese(Variant::Traditional), "角");
 ///
 /// assert_eq!(two_formal.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "两角".to_string(),
+///     logograms: "两角".into(),
 ///     omissible: false
 /// });
 /// assert_eq!(two_formal.to_chinese(Variant::Traditional), "兩角");
@@ -52,7 +55,7 @@
 /// assert_eq!(two_informal.unit().to_chinese(Variant::Traditional), "毛");
 ///
 /// assert_eq!(two_informal.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "两毛".to_string(),
+///     logograms: "两毛".into(),
 ///     omissible: false
 /// });
 /// assert_eq!(two_informal.to_chinese(Variant::Traditional), "兩毛");
@@ -68,9 +71,27 @@
 ///
 /// let zero = Jiao { value: Count(0), formal: true };
 /// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "零角".to_string(),
+///     logograms: "零角".into(),
 ///     omissible: true
 /// });
+///
+///
+/// //Per-variant units for both registers
+/// define_multi_register_measure!(pub, Kuai, pub, Count, ("圆", "圓"), ("块", "塊"));
+///
+/// let two_kuai = Kuai { value: Count(2), formal: false };
+/// assert_eq!(two_kuai.to_chinese(Variant::Simplified), "两块");
+/// assert_eq!(two_kuai.to_chinese(Variant::Traditional), "兩塊");
+///
+/// let two_yuan = Kuai { value: Count(2), formal: true };
+/// assert_eq!(two_yuan.to_chinese(Variant::Traditional), "兩圓");
+///
+///
+/// //Value transform
+/// define_multi_register_measure!(pub, Liang, pub, Count, ("两", "兩"), ("两", "兩"), value_transform = plain_two);
+///
+/// let two_liang = Liang { value: Count(2), formal: true };
+/// assert_eq!(two_liang.to_chinese(Variant::Simplified), "二两");
 /// ```
 #[macro_export]
 macro_rules! define_multi_register_measure {
@@ -89,6 +110,9 @@ macro_rules! define_multi_register_measure {
 
         //Informal unit - implementing ChineseFormat.
         $informal_unit: expr
+
+        //Optional fn(Chinese) -> Chinese, adjusting the value's translation.
+        $(, value_transform = $value_transform: expr)?
     ) => {
         $crate::define_multi_register_measure!(
             ,
@@ -97,6 +121,7 @@ macro_rules! define_multi_register_measure {
             $field_type,
             $formal_unit,
             $informal_unit
+            $(, value_transform = $value_transform)?
         );
     };
 
@@ -118,6 +143,9 @@ macro_rules! define_multi_register_measure {
 
         //Informal unit - implementing ChineseFormat.
         $informal_unit: expr
+
+        //Optional fn(Chinese) -> Chinese, adjusting the value's translation.
+        $(, value_transform = $value_transform: expr)?
     ) => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
         $type_visibility struct $type {
@@ -137,6 +165,12 @@ macro_rules! define_multi_register_measure {
                     &$informal_unit
                 }
             }
+
+            $(
+                fn transform_value(&self, value: $crate::Chinese) -> $crate::Chinese {
+                    ($value_transform)(value)
+                }
+            )?
         }
 
         impl From<$type> for $field_type {
@@ -1,7 +1,8 @@
 /// Like [define_measure](crate::define_measure), but the generated struct is not [Copy].
 ///
 /// As a consequence, the underlying type does not need to implement [Copy],
-/// and the `value()` method returns a *clone*.
+/// and the `value()` method returns a *clone*; the optional `value_transform`
+/// argument works as in [define_measure](crate::define_measure).
 ///
 /// ```
 /// use chinese_format::*;
@@ -21,14 +22,14 @@
 /// assert_eq!(
 ///     year.to_chinese(Variant::Simplified),
 ///     Chinese {
-///         logograms: "2009年".to_string(),
+///         logograms: "2009年".into(),
 ///         omissible: false
 ///     }
 /// );
 /// assert_eq!(
 ///     year.to_chinese(Variant::Traditional),
 ///     Chinese {
-///         logograms: "2009年".to_string(),
+///         logograms: "2009年".into(),
 ///         omissible: false
 ///     }
 /// );
@@ -42,11 +43,11 @@
 ///     
 /// let empty_year = Nian(String::new());
 /// assert_eq!(empty_year.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "年".to_string(),
+///     logograms: "年".into(),
 ///     omissible: true
 /// });
 /// assert_eq!(empty_year.to_chinese(Variant::Traditional), Chinese {
-///     logograms: "年".to_string(),
+///     logograms: "年".into(),
 ///     omissible: true
 /// });
 /// ```
@@ -79,13 +80,17 @@ macro_rules! define_no_copy_measure {
 
         //The unit - implementing ChineseFormat.
         $unit: expr
+
+        //Optional fn(Chinese) -> Chinese, adjusting the value's translation.
+        $(, value_transform = $value_transform: expr)?
     ) => {
-        $crate::define_no_copy_measure(
+        $crate::define_no_copy_measure!(
             ,
             $type,
             $field_visibility,
             $field_type,
             $unit
+            $(, value_transform = $value_transform)?
         );
     };
 
@@ -104,6 +109,9 @@ macro_rules! define_no_copy_measure {
 
         //The unit - implementing ChineseFormat.
         $unit: expr
+
+        //Optional fn(Chinese) -> Chinese, adjusting the value's translation.
+        $(, value_transform = $value_transform: expr)?
     ) => {
         #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         $type_visibility struct $type($field_visibility $field_type);
@@ -116,6 +124,12 @@ macro_rules! define_no_copy_measure {
             fn unit(&self) -> &dyn $crate::ChineseFormat {
                 &$unit
             }
+
+            $(
+                fn transform_value(&self, value: $crate::Chinese) -> $crate::Chinese {
+                    ($value_transform)(value)
+                }
+            )?
         }
 
         impl From<$type> for $field_type {
@@ -0,0 +1,142 @@
+use crate::{Chinese, ChineseFormat, Glyphs, Variant};
+
+/// The prefix introducing the negative values of a signed measure,
+/// which depends on the category of its unit.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(NegativePrefix::Minus.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "负".into(),
+///     omissible: false
+/// });
+/// assert_eq!(NegativePrefix::Minus.to_chinese(Variant::Traditional), "負");
+///
+/// assert_eq!(NegativePrefix::BelowZero.to_chinese(Variant::Simplified), "零下");
+/// assert_eq!(NegativePrefix::BelowSeaLevel.to_chinese(Variant::Simplified), "海平面以下");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NegativePrefix {
+    /// `负` - the arithmetic sign, for balances, changes and generic quantities.
+    Minus,
+
+    /// `零下` - for values on a scale such as temperatures.
+    BelowZero,
+
+    /// `海平面以下` - for altitudes.
+    BelowSeaLevel,
+}
+
+impl ChineseFormat for NegativePrefix {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = match self {
+            Self::Minus => Glyphs::FU,
+            Self::BelowZero => Glyphs::same("零下"),
+            Self::BelowSeaLevel => Glyphs::same("海平面以下"),
+        };
+
+        logograms.to_chinese(variant)
+    }
+}
+
+/// Defines a struct wrapping a signed integer and a unit, whose magnitude is read
+/// like a [Count](crate::Count) and whose negative values are introduced by
+/// the given [NegativePrefix](crate::NegativePrefix).
+///
+/// The resulting struct implements [ChineseFormat](crate::ChineseFormat) - being
+/// [omissible](crate::Chinese::omissible) when its value is zero - and can be converted
+/// back to its underlying type.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// define_signed_measure!(pub, Balance, i64, ("元", "元"), NegativePrefix::Minus);
+/// define_signed_measure!(pub, Altitude, i32, "米", NegativePrefix::BelowSeaLevel);
+/// define_signed_measure!(pub, Fahrenheit, i16, ("华氏度", "華氏度"), NegativePrefix::BelowZero);
+///
+/// assert_eq!(Balance(-2).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "负两元".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Balance(-2).to_chinese(Variant::Traditional), "負兩元");
+/// assert_eq!(Balance(300).to_chinese(Variant::Simplified), "三百元");
+///
+/// assert_eq!(Altitude(-154).to_chinese(Variant::Simplified), "海平面以下一百五十四米");
+///
+/// assert_eq!(Fahrenheit(-40).to_chinese(Variant::Traditional), "零下四十華氏度");
+///
+/// assert_eq!(Balance(0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零元".into(),
+///     omissible: true
+/// });
+///
+/// let balance: i64 = Balance(-90).into();
+/// assert_eq!(balance, -90);
+/// ```
+#[macro_export]
+macro_rules! define_signed_measure {
+    (
+        //The name of the PRIVATE type to create.
+        $type: ident,
+
+        //The signed integer type.
+        $field_type: ty,
+
+        //The unit - implementing ChineseFormat.
+        $unit: expr,
+
+        //The NegativePrefix.
+        $negative_prefix: expr
+    ) => {
+        $crate::define_signed_measure!(, $type, $field_type, $unit, $negative_prefix);
+    };
+
+    (
+        //The visibility of the type to create.
+        $type_visibility: vis,
+
+        //The name of the type to create.
+        $type: ident,
+
+        //The signed integer type.
+        $field_type: ty,
+
+        //The unit - implementing ChineseFormat.
+        $unit: expr,
+
+        //The NegativePrefix.
+        $negative_prefix: expr
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $type_visibility struct $type(pub $field_type);
+
+        impl $crate::ChineseFormat for $type {
+            fn to_chinese(&self, variant: $crate::Variant) -> $crate::Chinese {
+                let magnitude =
+                    $crate::Count(self.0.unsigned_abs() as $crate::CountBase).to_chinese(variant);
+
+                let prefix = if self.0 < 0 {
+                    $crate::ChineseFormat::to_chinese(&$negative_prefix, variant).logograms
+                } else {
+                    "".into()
+                };
+
+                $crate::Chinese {
+                    logograms: format!(
+                        "{}{}{}",
+                        prefix,
+                        magnitude.logograms,
+                        $crate::ChineseFormat::to_chinese(&$unit, variant)
+                    ).into(),
+                    omissible: magnitude.omissible,
+                }
+            }
+        }
+
+        impl From<$type> for $field_type {
+            fn from(value: $type) -> Self {
+                value.0
+            }
+        }
+    };
+}
@@ -0,0 +1,106 @@
+use crate::{Chinese, ChineseFormat, Variant};
+
+/// Unit preceded by a classifier - such as `个星期` - whose [Chinese]
+/// translation is the concatenation of the two parts.
+///
+/// It is the unit of the measures created by [define_measure_with_classifier](crate::define_measure_with_classifier).
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let unit = ClassifiedUnit(("个", "個"), "星期");
+///
+/// assert_eq!(unit.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "个星期".into(),
+///     omissible: false
+/// });
+/// assert_eq!(unit.to_chinese(Variant::Traditional), "個星期");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClassifiedUnit<C: ChineseFormat, U: ChineseFormat>(pub C, pub U);
+
+impl<C: ChineseFormat, U: ChineseFormat> ChineseFormat for ClassifiedUnit<C, U> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!(
+                "{}{}",
+                self.0.to_chinese(variant),
+                self.1.to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Defines a struct implementing [Measure](crate::Measure) having a [Count](crate::Count) value
+/// and a unit noun requiring a classifier - such as `个`, `只` or `张` - between the number and the noun.
+///
+/// Like [define_count_measure](crate::define_count_measure), it includes a `new` constructor;
+/// the unit is a [ClassifiedUnit].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// define_measure_with_classifier!(pub, Weeks, ("个", "個"), "星期");
+/// define_measure_with_classifier!(pub, Months, ("个", "個"), "月");
+///
+/// assert_eq!(Weeks::new(3).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三个星期".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Weeks::new(3).to_chinese(Variant::Traditional), "三個星期");
+///
+/// assert_eq!(Months::new(2).to_chinese(Variant::Simplified), "两个月");
+/// assert_eq!(Months::new(2).to_chinese(Variant::Traditional), "兩個月");
+///
+/// assert_eq!(Months::new(2).unit().to_chinese(Variant::Simplified), "个月");
+///
+/// assert_eq!(Months::new(0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零个月".into(),
+///     omissible: true
+/// });
+///
+/// let two_months: Count = Months::new(2).into();
+/// assert_eq!(two_months, Count(2));
+/// ```
+#[macro_export]
+macro_rules! define_measure_with_classifier {
+    (
+        //The name of the PRIVATE type to create.
+        $type: ident,
+
+        //The classifier - implementing ChineseFormat.
+        $classifier: expr,
+
+        //The unit noun - implementing ChineseFormat.
+        $unit: expr
+    ) => {
+        $crate::define_measure_with_classifier!(
+            ,
+            $type,
+            $classifier,
+            $unit
+        );
+    };
+
+    (
+        //The visibility of the type to create.
+        $type_visibility: vis,
+
+        //The name of the type to create.
+        $type: ident,
+
+        //The classifier - implementing ChineseFormat.
+        $classifier: expr,
+
+        //The unit noun - implementing ChineseFormat.
+        $unit: expr
+    ) => {
+        $crate::define_count_measure!(
+            $type_visibility,
+            $type,
+            $crate::ClassifiedUnit($classifier, $unit)
+        );
+    };
+}
@@ -0,0 +1,69 @@
+use crate::{Chinese, ChineseFormat, Variant};
+
+/// How a [Distributive] expresses "every".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DistributiveForm {
+    /// `每` before the unit - as in `每天` or `每个人`.
+    #[default]
+    Mei,
+
+    /// The unit repeated - as in `天天`, `年年` or `个个` - which
+    /// is idiomatic for monosyllabic classifiers and time units only.
+    Reduplicated,
+}
+
+const MEI: &str = "每";
+
+/// Distributive expression - meaning "every" - built from a classifier,
+/// a [ClassifiedUnit](crate::ClassifiedUnit) or a time unit.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let every_day = Distributive("天", DistributiveForm::Mei);
+/// assert_eq!(every_day.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "每天".into(),
+///     omissible: false
+/// });
+///
+/// let everyone = Distributive(ClassifiedUnit(("个", "個"), "人"), DistributiveForm::Mei);
+/// assert_eq!(everyone.to_chinese(Variant::Simplified), "每个人");
+/// assert_eq!(everyone.to_chinese(Variant::Traditional), "每個人");
+///
+/// let day_after_day = Distributive("天", DistributiveForm::Reduplicated);
+/// assert_eq!(day_after_day.to_chinese(Variant::Simplified), "天天");
+///
+/// assert_eq!(Distributive("年", DistributiveForm::Reduplicated).to_chinese(Variant::Simplified), "年年");
+/// assert_eq!(Distributive("人", DistributiveForm::Reduplicated).to_chinese(Variant::Simplified), "人人");
+///
+/// let each_one = Distributive(("个", "個"), DistributiveForm::Reduplicated);
+/// assert_eq!(each_one.to_chinese(Variant::Simplified), "个个");
+/// assert_eq!(each_one.to_chinese(Variant::Traditional), "個個");
+///
+/// assert_eq!(Distributive("", DistributiveForm::Mei).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Distributive<T: ChineseFormat>(pub T, pub DistributiveForm);
+
+impl<T: ChineseFormat> ChineseFormat for Distributive<T> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let unit = self.0.to_chinese(variant);
+
+        if unit.omissible {
+            return unit;
+        }
+
+        let logograms = match self.1 {
+            DistributiveForm::Mei => format!("{}{}", MEI, unit.logograms),
+            DistributiveForm::Reduplicated => unit.logograms.repeat(2),
+        };
+
+        Chinese {
+            logograms: logograms.into(),
+            omissible: false,
+        }
+    }
+}
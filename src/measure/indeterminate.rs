@@ -0,0 +1,49 @@
+use crate::{Chinese, ChineseFormat, Variant};
+
+/// Non-numeric quantity - such as `适量`, in `适量盐` - usable wherever
+/// a [Measure](crate::Measure) would stand before a noun.
+///
+/// It is never [omissible](Chinese::omissible), as it always conveys information.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(IndeterminateQuantity::ToTaste.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "适量".into(),
+///     omissible: false
+/// });
+/// assert_eq!(IndeterminateQuantity::ToTaste.to_chinese(Variant::Traditional), "適量");
+///
+/// assert_eq!(IndeterminateQuantity::Pinch.to_chinese(Variant::Simplified), "少许");
+/// assert_eq!(IndeterminateQuantity::Pinch.to_chinese(Variant::Traditional), "少許");
+///
+/// assert_eq!(IndeterminateQuantity::Some.to_chinese(Variant::Simplified), "一些");
+/// assert_eq!(IndeterminateQuantity::Few.to_chinese(Variant::Traditional), "幾個");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IndeterminateQuantity {
+    /// `适量` - as much as needed, "to taste".
+    ToTaste,
+
+    /// `少许` - a pinch, a little.
+    Pinch,
+
+    /// `一些` - some.
+    Some,
+
+    /// `几个` - a few.
+    Few,
+}
+
+impl ChineseFormat for IndeterminateQuantity {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = match self {
+            Self::ToTaste => ("适量", "適量"),
+            Self::Pinch => ("少许", "少許"),
+            Self::Some => ("一些", "一些"),
+            Self::Few => ("几个", "幾個"),
+        };
+
+        logograms.to_chinese(variant)
+    }
+}
@@ -2,8 +2,26 @@ mod define;
 mod define_count;
 mod define_multi_register;
 mod define_no_copy;
+mod define_signed;
+mod define_with_classifier;
+mod distributive;
+mod indeterminate;
+mod multi_unit;
+mod scaled;
+mod semantic;
+mod verbal;
 
-use crate::{Chinese, ChineseFormat, Variant};
+use crate::{Chinese, ChineseFormat, ChineseParts, DigitGlyphSet, Variant};
+
+pub use define_signed::*;
+pub use define_with_classifier::*;
+pub use distributive::*;
+pub use indeterminate::*;
+pub use multi_unit::*;
+pub(crate) use scaled::impl_scaled_measure;
+pub use scaled::{Dimension, HumanizedMeasure, ScaledMeasure};
+pub use semantic::*;
+pub use verbal::*;
 
 /// Trait describing a [value](Self::value) combined with a [unit](Self::unit) of measurement.
 pub trait Measure {
@@ -12,6 +30,44 @@ pub trait Measure {
 
     /// The unit of measurement, convertible to [Chinese].
     fn unit(&self) -> &dyn ChineseFormat;
+
+    /// Adjusts the [Chinese] translation of the [value](Self::value)
+    /// before the [unit](Self::unit) is appended.
+    ///
+    /// By default, it returns the value unchanged.
+    fn transform_value(&self, value: Chinese) -> Chinese {
+        value
+    }
+}
+
+/// Value transform - for the measure macros - reading `2` as `二` instead of `两`/`兩`,
+/// as required by units such as the traditional weight unit `两`.
+///
+/// Any other value is returned unchanged.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(plain_two(Count(2).to_chinese(Variant::Simplified)), Chinese {
+///     logograms: "二".into(),
+///     omissible: false
+/// });
+/// assert_eq!(plain_two(Count(2).to_chinese(Variant::Traditional)), "二");
+///
+/// assert_eq!(plain_two(Count(22).to_chinese(Variant::Simplified)), "二十二");
+/// assert_eq!(plain_two(Count(0).to_chinese(Variant::Simplified)), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+/// ```
+pub fn plain_two(value: Chinese) -> Chinese {
+    match value.as_str() {
+        "两" | "兩" => Chinese {
+            logograms: "二".into(),
+            ..value
+        },
+        _ => value,
+    }
 }
 
 /// [Measure] automatically implements [ChineseFormat],
@@ -27,7 +83,7 @@ pub trait Measure {
 /// let three_km = Kilometer::new(3);
 ///
 /// assert_eq!(three_km.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "三公里".to_string(),
+///     logograms: "三公里".into(),
 ///     omissible: false
 /// });
 ///
@@ -35,21 +91,69 @@ pub trait Measure {
 /// let zero_km = Kilometer::new(0);
 ///
 /// assert_eq!(zero_km.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "零公里".to_string(),
+///     logograms: "零公里".into(),
 ///     omissible: true
 /// });
 /// ```
 impl<T: Measure> ChineseFormat for T {
     fn to_chinese(&self, variant: Variant) -> Chinese {
-        let value_chinese = self.value().to_chinese(variant);
+        self.to_chinese_with_glyphs(variant, DigitGlyphSet::STANDARD)
+    }
+
+    fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+        let value_chinese =
+            self.transform_value(self.value().to_chinese_with_glyphs(variant, glyphs));
 
         let logograms = format!("{}{}", value_chinese, self.unit().to_chinese(variant));
 
         let omissible = value_chinese.omissible;
 
         Chinese {
-            logograms,
+            logograms: logograms.into(),
             omissible,
         }
     }
+
+    fn to_chinese_parts(&self, variant: Variant) -> ChineseParts {
+        let value_parts: ChineseParts = self
+            .transform_value(self.value().to_chinese(variant))
+            .into();
+
+        [value_parts, self.unit().to_chinese_parts(variant)]
+            .into_iter()
+            .collect()
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        let value_tokens = self.value().to_chinese_tokens(variant);
+
+        let value_logograms = self
+            .transform_value(self.value().to_chinese(variant))
+            .logograms;
+
+        //The transform - such as plain_two - can replace logograms, keeping their roles
+        let value_tokens = if value_logograms.chars().count() == value_tokens.len() {
+            value_logograms
+                .chars()
+                .zip(value_tokens)
+                .map(|(logogram, token)| crate::pinyin::Token {
+                    logograms: logogram.to_string(),
+                    pinyin: crate::pinyin::pinyin(logogram),
+                    role: token.role,
+                })
+                .collect()
+        } else {
+            crate::pinyin::inferred_tokens(&value_logograms)
+        };
+
+        [
+            value_tokens,
+            crate::pinyin::tokens_with_role(
+                &self.unit().to_chinese(variant).logograms,
+                crate::pinyin::TokenRole::Unit,
+            ),
+        ]
+        .concat()
+    }
 }
@@ -0,0 +1,355 @@
+use crate::{Chinese, ChineseFormat, ChineseVec, Count, CountBase, Variant, ZeroPolicy};
+
+/// How the last - that is, the smallest - unit of a [MultiUnitMeasure] is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TrailingUnit {
+    /// The unit is shown - as in `一米七十五厘米`.
+    #[default]
+    Shown,
+
+    /// The unit is omitted, as in colloquial speech - as in `一小时二十`.
+    Omitted,
+
+    /// The unit is omitted and its value is read as the digits of a fractional part -
+    /// as in `一米七五`; this only applies when the ratio between the last two units
+    /// is a power of 10, otherwise it behaves like [Omitted](Self::Omitted).
+    Digits,
+}
+
+/// Options for a [MultiUnitMeasure].
+///
+/// By default, zero sub-units are handled via [ZeroPolicy::Gap]
+/// and the trailing unit is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MultiUnitStyle {
+    /// Applied to the components whose value is zero.
+    pub zero_policy: ZeroPolicy,
+
+    pub trailing_unit: TrailingUnit,
+}
+
+impl Default for MultiUnitStyle {
+    fn default() -> Self {
+        Self {
+            zero_policy: ZeroPolicy::Gap,
+            trailing_unit: TrailingUnit::Shown,
+        }
+    }
+}
+
+/// Value split across a hierarchy of units - such as `一米七五` or `三斤二两`.
+///
+/// The `value` is expressed in the _smallest_ unit, whereas the `units` - from
+/// the largest to the smallest - declare how many smallest units each of them contains.
+///
+/// The first component is read like a [Count], so `2` becomes `两`/`兩`;
+/// the others are read as plain numbers, as in `三斤二两`.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let height = MultiUnitMeasure {
+///     value: 175,
+///     units: MultiUnitMeasure::METER_CENTIMETER,
+///     style: MultiUnitStyle::default()
+/// };
+/// assert_eq!(height.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一米七十五厘米".into(),
+///     omissible: false
+/// });
+/// assert_eq!(height.to_chinese(Variant::Traditional), "一米七十五釐米");
+///
+/// let colloquial_height = MultiUnitMeasure {
+///     style: MultiUnitStyle {
+///         trailing_unit: TrailingUnit::Digits,
+///         ..MultiUnitStyle::default()
+///     },
+///     ..height
+/// };
+/// assert_eq!(colloquial_height.to_chinese(Variant::Simplified), "一米七五");
+///
+/// let short_distance = MultiUnitMeasure {
+///     value: 105,
+///     ..colloquial_height
+/// };
+/// assert_eq!(short_distance.to_chinese(Variant::Simplified), "一米零五");
+///
+/// let round_height = MultiUnitMeasure {
+///     value: 170,
+///     ..colloquial_height
+/// };
+/// assert_eq!(round_height.to_chinese(Variant::Simplified), "一米七");
+///
+/// let weight = MultiUnitMeasure {
+///     value: 32,
+///     units: MultiUnitMeasure::JIN_LIANG,
+///     style: MultiUnitStyle::default()
+/// };
+/// assert_eq!(weight.to_chinese(Variant::Simplified), "三斤二两");
+/// assert_eq!(weight.to_chinese(Variant::Traditional), "三斤二兩");
+///
+/// let duration = MultiUnitMeasure {
+///     value: 80,
+///     units: MultiUnitMeasure::HOUR_MINUTE,
+///     style: MultiUnitStyle {
+///         trailing_unit: TrailingUnit::Omitted,
+///         ..MultiUnitStyle::default()
+///     }
+/// };
+/// assert_eq!(duration.to_chinese(Variant::Simplified), "一小时二十");
+/// assert_eq!(duration.to_chinese(Variant::Traditional), "一小時二十");
+/// ```
+///
+/// Zero components are handled according to the [ZeroPolicy]:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let two_meters = MultiUnitMeasure {
+///     value: 200,
+///     units: MultiUnitMeasure::METER_CENTIMETER,
+///     style: MultiUnitStyle::default()
+/// };
+/// assert_eq!(two_meters.to_chinese(Variant::Simplified), "两米");
+///
+/// let kept = MultiUnitMeasure {
+///     style: MultiUnitStyle {
+///         zero_policy: ZeroPolicy::Keep,
+///         ..MultiUnitStyle::default()
+///     },
+///     ..two_meters
+/// };
+/// assert_eq!(kept.to_chinese(Variant::Simplified), "两米零厘米");
+///
+/// let minutes = MultiUnitMeasure {
+///     value: 45,
+///     units: MultiUnitMeasure::HOUR_MINUTE,
+///     style: MultiUnitStyle::default()
+/// };
+/// assert_eq!(minutes.to_chinese(Variant::Simplified), "四十五分钟");
+///
+/// let zero = MultiUnitMeasure {
+///     value: 0,
+///     units: MultiUnitMeasure::JIN_LIANG,
+///     style: MultiUnitStyle::default()
+/// };
+/// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零两".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MultiUnitMeasure<'a> {
+    /// The value, expressed in the smallest unit.
+    pub value: CountBase,
+
+    /// The units, from the largest to the smallest, each with the number
+    /// of smallest units it contains; an empty slice results in an empty,
+    /// omissible expression.
+    pub units: &'a [(CountBase, (&'static str, &'static str))],
+
+    pub style: MultiUnitStyle,
+}
+
+impl MultiUnitMeasure<'_> {
+    /// `米` and `厘米` - for a value in centimeters.
+    pub const METER_CENTIMETER: &'static [(CountBase, (&'static str, &'static str))] =
+        &[(100, ("米", "米")), (1, ("厘米", "釐米"))];
+
+    /// `斤` and `两` - for a value in `两`.
+    pub const JIN_LIANG: &'static [(CountBase, (&'static str, &'static str))] =
+        &[(10, ("斤", "斤")), (1, ("两", "兩"))];
+
+    /// `小时` and `分钟` - for a value in minutes.
+    pub const HOUR_MINUTE: &'static [(CountBase, (&'static str, &'static str))] =
+        &[(60, ("小时", "小時")), (1, ("分钟", "分鐘"))];
+
+    fn counts(&self) -> Vec<CountBase> {
+        let mut remainder = self.value;
+
+        self.units
+            .iter()
+            .map(|(factor, _)| {
+                let count = remainder / factor;
+                remainder %= factor;
+                count
+            })
+            .collect()
+    }
+
+    /// The number of digits read by [TrailingUnit::Digits], if applicable.
+    fn trailing_digits_width(&self) -> Option<usize> {
+        if self.style.trailing_unit != TrailingUnit::Digits || self.units.len() < 2 {
+            return None;
+        }
+
+        let ratio = self.units[self.units.len() - 2].0 / self.units[self.units.len() - 1].0;
+
+        let width = ratio.ilog10();
+
+        (10u128.pow(width) == ratio).then_some(width as usize)
+    }
+
+    /// Whether any component but the last one is actually rendered - in which case
+    /// the last one can drop its unit according to [TrailingUnit].
+    fn has_rendered_leading_components(&self, counts: &[CountBase]) -> bool {
+        self.style.zero_policy == ZeroPolicy::Keep
+            || counts[..counts.len() - 1].iter().any(|&count| count != 0)
+    }
+
+    fn component_to_chinese(
+        &self,
+        index: usize,
+        count: CountBase,
+        trailing_unit: TrailingUnit,
+        variant: Variant,
+    ) -> Chinese {
+        let is_last = index == self.units.len() - 1;
+
+        let value = if index == 0 {
+            Count(count).to_chinese(variant)
+        } else {
+            count.to_chinese(variant)
+        };
+
+        if !is_last || index == 0 {
+            return Chinese {
+                logograms: format!("{}{}", value, self.units[index].1.to_chinese(variant)).into(),
+                omissible: value.omissible,
+            };
+        }
+
+        match (trailing_unit, self.trailing_digits_width()) {
+            (TrailingUnit::Shown, _) => Chinese {
+                logograms: format!("{}{}", value, self.units[index].1.to_chinese(variant)).into(),
+                omissible: value.omissible,
+            },
+
+            (_, Some(width)) => {
+                let digits = format!("{:0width$}", count, width = width);
+
+                Chinese {
+                    logograms: digits
+                        .trim_end_matches('0')
+                        .chars()
+                        .map(|digit| {
+                            digit
+                                .to_digit(10)
+                                .expect("Only digits are formatted")
+                                .to_chinese(variant)
+                                .logograms
+                        })
+                        .collect::<String>()
+                        .into(),
+                    omissible: count == 0,
+                }
+            }
+
+            _ => value,
+        }
+    }
+}
+
+impl ChineseFormat for MultiUnitMeasure<'_> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.units.is_empty() {
+            return Chinese {
+                logograms: "".into(),
+                omissible: true,
+            };
+        }
+
+        let counts = self.counts();
+
+        let trailing_unit = if self.has_rendered_leading_components(&counts) {
+            self.style.trailing_unit
+        } else {
+            TrailingUnit::Shown
+        };
+
+        let components: ChineseVec = counts
+            .into_iter()
+            .enumerate()
+            .map(|(index, count)| self.component_to_chinese(index, count, trailing_unit, variant))
+            .collect::<Vec<_>>()
+            .into();
+
+        let result = components
+            .apply_zero_policy(self.style.zero_policy)
+            .collect();
+
+        if self.value == 0 && result.logograms.is_empty() {
+            let (_, smallest_unit) = self.units[self.units.len() - 1];
+
+            return Chinese {
+                logograms: format!(
+                    "{}{}",
+                    Count(0).to_chinese(variant),
+                    smallest_unit.to_chinese(variant)
+                )
+                .into(),
+                omissible: true,
+            };
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Rendering a multi-unit measure" {
+            fn render(
+                value: CountBase,
+                units: &[(CountBase, (&'static str, &'static str))],
+                trailing_unit: TrailingUnit,
+            ) -> Chinese {
+                MultiUnitMeasure {
+                    value,
+                    units,
+                    style: MultiUnitStyle {
+                        trailing_unit,
+                        ..MultiUnitStyle::default()
+                    },
+                }
+                .to_chinese(Variant::Simplified)
+            }
+
+            it "should keep the trailing unit as digits when only the smallest component is rendered" {
+                eq!(
+                    render(5, MultiUnitMeasure::METER_CENTIMETER, TrailingUnit::Digits),
+                    "五厘米"
+                );
+            }
+
+            it "should keep the omitted trailing unit when only the smallest component is rendered" {
+                eq!(
+                    render(2, MultiUnitMeasure::JIN_LIANG, TrailingUnit::Omitted),
+                    "二两"
+                );
+            }
+
+            it "should still drop the trailing unit after a rendered component" {
+                eq!(
+                    render(12, MultiUnitMeasure::JIN_LIANG, TrailingUnit::Omitted),
+                    "一斤二"
+                );
+            }
+
+            it "should render empty units as an empty, omissible expression" {
+                eq!(
+                    render(5, &[], TrailingUnit::Shown),
+                    Chinese {
+                        logograms: "".into(),
+                        omissible: true
+                    }
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Chinese, ChineseFormat, Count, CountBase, Glyphs, Variant};
+
+/// Physical dimension - such as length or mass - shared by several [ScaledMeasure] types.
+pub trait Dimension {
+    /// The units used by [humanize](ScaledMeasure::humanize), from the largest to the smallest:
+    /// each unit is described by its number of base units and its logograms.
+    const HUMANIZED_UNITS: &'static [(CountBase, (&'static str, &'static str))];
+}
+
+/// [Count](crate::Count)-based measure that can be converted to the other
+/// measures of the same [Dimension].
+///
+/// ```
+/// use chinese_format::{*, length::*};
+///
+/// let distance = Kilometer::new(3);
+/// assert_eq!(distance.base_units(), 3_000_000_000_000);
+///
+/// assert_eq!(distance.convert::<Meter>(), Some(Meter::new(3000)));
+/// assert_eq!(Meter::new(1500).convert::<Centimeter>(), Some(Centimeter::new(150_000)));
+///
+/// //Only exact conversions are supported
+/// assert_eq!(Meter::new(1500).convert::<Kilometer>(), None);
+/// assert_eq!(Meter::new(2000).convert::<Kilometer>(), Some(Kilometer::new(2)));
+/// ```
+pub trait ScaledMeasure: Sized {
+    type Dimension: Dimension;
+
+    /// The number of base units - the smallest unit of the [Dimension] - in one unit of this measure.
+    const BASE_FACTOR: CountBase;
+
+    /// The value of the measure.
+    fn count(&self) -> CountBase;
+
+    /// Creates the measure from its value.
+    fn from_count(count: CountBase) -> Self;
+
+    /// The value of the measure, expressed in base units.
+    fn base_units(&self) -> CountBase {
+        self.count() * Self::BASE_FACTOR
+    }
+
+    /// Converts to another measure of the same [Dimension] -
+    /// returning [None] if the conversion is not exact.
+    fn convert<T: ScaledMeasure<Dimension = Self::Dimension>>(&self) -> Option<T> {
+        let base_units = self.base_units();
+
+        if base_units % T::BASE_FACTOR == 0 {
+            Some(T::from_count(base_units / T::BASE_FACTOR))
+        } else {
+            None
+        }
+    }
+
+    /// Expresses the measure via the largest of the [humanized units](Dimension::HUMANIZED_UNITS)
+    /// not exceeding it, with a fractional part if needed; zero is expressed via the
+    /// largest humanized unit not exceeding the unit of the measure itself.
+    ///
+    /// ```
+    /// use chinese_format::{*, length::*, weight::*};
+    ///
+    /// assert_eq!(Meter::new(1500).humanize().to_chinese(Variant::Simplified), Chinese {
+    ///     logograms: "一点五公里".into(),
+    ///     omissible: false
+    /// });
+    ///
+    /// assert_eq!(Centimeter::new(250).humanize().to_chinese(Variant::Simplified), "二点五米");
+    /// assert_eq!(Centimeter::new(200).humanize().to_chinese(Variant::Simplified), "两米");
+    /// assert_eq!(Millimeter::new(35).humanize().to_chinese(Variant::Traditional), "三點五釐米");
+    ///
+    /// assert_eq!(Gram::new(2500).humanize().to_chinese(Variant::Simplified), "二点五公斤");
+    /// assert_eq!(HalfKilogram::new(3).humanize().to_chinese(Variant::Simplified), "一点五公斤");
+    ///
+    /// assert_eq!(Meter::new(0).humanize().to_chinese(Variant::Simplified), Chinese {
+    ///     logograms: "零米".into(),
+    ///     omissible: true
+    /// });
+    /// ```
+    fn humanize(&self) -> HumanizedMeasure {
+        let base_units = self.base_units();
+
+        let units = Self::Dimension::HUMANIZED_UNITS;
+
+        let threshold = if base_units == 0 {
+            Self::BASE_FACTOR
+        } else {
+            base_units
+        };
+
+        let (factor, unit) = units
+            .iter()
+            .find(|(factor, _)| threshold >= *factor)
+            .or(units.last())
+            .copied()
+            .expect("Each dimension has at least one humanized unit");
+
+        let width = factor.ilog10() as usize;
+
+        let remainder = format!("{:0width$}", base_units % factor, width = width);
+
+        HumanizedMeasure {
+            integer: base_units / factor,
+            fractional: remainder.trim_end_matches('0').to_string(),
+            unit,
+        }
+    }
+}
+
+/// Measure returned by [ScaledMeasure::humanize].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanizedMeasure {
+    integer: CountBase,
+    fractional: String,
+    unit: (&'static str, &'static str),
+}
+
+impl ChineseFormat for HumanizedMeasure {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let value = if self.fractional.is_empty() {
+            Count(self.integer).to_chinese(variant)
+        } else {
+            let fractional: String = self
+                .fractional
+                .chars()
+                .map(|digit| {
+                    digit
+                        .to_digit(10)
+                        .expect("Only digits are stored")
+                        .to_chinese(variant)
+                        .logograms
+                })
+                .collect();
+
+            Chinese {
+                logograms: format!(
+                    "{}{}{}",
+                    self.integer.to_chinese(variant),
+                    Glyphs::DIAN.to_chinese(variant),
+                    fractional
+                )
+                .into(),
+                omissible: false,
+            }
+        };
+
+        Chinese {
+            logograms: format!("{}{}", value.logograms, self.unit.to_chinese(variant)).into(),
+            omissible: value.omissible,
+        }
+    }
+}
+
+/// Implements [ScaledMeasure] for a type created via [define_count_measure](crate::define_count_measure).
+macro_rules! impl_scaled_measure {
+    ($dimension: ty, $type: ident, $base_factor: expr) => {
+        impl $crate::ScaledMeasure for $type {
+            type Dimension = $dimension;
+
+            const BASE_FACTOR: $crate::CountBase = $base_factor;
+
+            fn count(&self) -> $crate::CountBase {
+                self.0 .0
+            }
+
+            fn from_count(count: $crate::CountBase) -> Self {
+                $type::new(count)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_scaled_measure;
@@ -0,0 +1,125 @@
+use crate::{Chinese, ChineseFormat, Count, CountBase, Measure, Variant};
+
+/// The meaning of a unit whose logograms are shared by different concepts -
+/// such as `分`, which can be a minute, a cent, a point of a score or the fraction marker.
+///
+/// Stating the meaning makes mixed expressions - for example, a score and a time
+/// in the same sentence - explicit, and lets parsers enumerate the possible readings.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(SemanticUnit::Minute.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "分".into(),
+///     omissible: false
+/// });
+/// assert_eq!(SemanticUnit::Cent.to_chinese(Variant::Traditional), "分");
+/// assert_eq!(SemanticUnit::Fraction.to_chinese(Variant::Simplified), "分之");
+///
+/// assert_eq!(
+///     SemanticUnit::candidates("分"),
+///     vec![SemanticUnit::Minute, SemanticUnit::Cent, SemanticUnit::Point]
+/// );
+/// assert_eq!(SemanticUnit::candidates("分之"), vec![SemanticUnit::Fraction]);
+/// assert_eq!(SemanticUnit::candidates("秒"), vec![]);
+///
+/// assert!(SemanticUnit::Point.is_ambiguous());
+/// assert!(!SemanticUnit::Fraction.is_ambiguous());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SemanticUnit {
+    /// `分` - a minute, as in `三点十分`.
+    Minute,
+
+    /// `分` - a hundredth of a yuan, as in `五角三分`.
+    Cent,
+
+    /// `分` - a point of a score, as in `九十五分`.
+    Point,
+
+    /// `分之` - the fraction marker, as in `三分之一`.
+    Fraction,
+}
+
+impl SemanticUnit {
+    /// All the units, in declaration order.
+    pub const ALL: [SemanticUnit; 4] = [Self::Minute, Self::Cent, Self::Point, Self::Fraction];
+
+    fn logograms(&self) -> &'static str {
+        match self {
+            Self::Minute | Self::Cent | Self::Point => "分",
+            Self::Fraction => "分之",
+        }
+    }
+
+    /// The units - in declaration order - written exactly as the given logograms.
+    pub fn candidates(logograms: &str) -> Vec<SemanticUnit> {
+        Self::ALL
+            .into_iter()
+            .filter(|unit| unit.logograms() == logograms)
+            .collect()
+    }
+
+    /// Whether other units are written via the same logograms.
+    pub fn is_ambiguous(&self) -> bool {
+        Self::candidates(self.logograms()).len() > 1
+    }
+}
+
+impl ChineseFormat for SemanticUnit {
+    fn to_chinese(&self, _variant: Variant) -> Chinese {
+        Chinese::from_static(self.logograms())
+    }
+}
+
+/// [Count] followed by a [SemanticUnit] - whose meaning remains available via [unit](Self::unit).
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let score = SemanticMeasure::new(95, SemanticUnit::Point);
+/// let time = SemanticMeasure::new(30, SemanticUnit::Minute);
+///
+/// let sentence = chinese_vec!(
+///     Variant::Simplified,
+///     ["考了", score, "，用了", time, ("钟", "鐘")]
+/// ).collect();
+/// assert_eq!(sentence, "考了九十五分，用了三十分钟");
+///
+/// assert_eq!(score.semantic_unit(), SemanticUnit::Point);
+/// assert_eq!(time.semantic_unit(), SemanticUnit::Minute);
+///
+/// assert_eq!(SemanticMeasure::new(0, SemanticUnit::Cent).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零分".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SemanticMeasure {
+    value: Count,
+    unit: SemanticUnit,
+}
+
+impl SemanticMeasure {
+    pub fn new(value: CountBase, unit: SemanticUnit) -> Self {
+        Self {
+            value: Count(value),
+            unit,
+        }
+    }
+
+    /// The meaning of the unit.
+    pub fn semantic_unit(&self) -> SemanticUnit {
+        self.unit
+    }
+}
+
+impl Measure for SemanticMeasure {
+    fn value(&self) -> &dyn ChineseFormat {
+        &self.value
+    }
+
+    fn unit(&self) -> &dyn ChineseFormat {
+        &self.unit
+    }
+}
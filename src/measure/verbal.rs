@@ -0,0 +1,113 @@
+use crate::{Chinese, ChineseFormat, Count, CountBase, Variant};
+
+/// Classifier counting the occurrences of an action - as in `三次` or `两遍`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VerbalClassifier {
+    /// `次` - the generic number of times.
+    Ci,
+
+    /// `遍` - a complete pass, from beginning to end.
+    Bian,
+
+    /// `趟` - a round trip.
+    Tang,
+
+    /// `顿`/`頓` - meals, as well as scoldings or beatings.
+    Dun,
+
+    /// `回` - colloquial number of times.
+    Hui,
+}
+
+impl ChineseFormat for VerbalClassifier {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = match self {
+            VerbalClassifier::Ci => ("次", "次"),
+            VerbalClassifier::Bian => ("遍", "遍"),
+            VerbalClassifier::Tang => ("趟", "趟"),
+            VerbalClassifier::Dun => ("顿", "頓"),
+            VerbalClassifier::Hui => ("回", "回"),
+        };
+
+        logograms.to_chinese(variant)
+    }
+}
+
+const DI: &str = "第";
+
+/// Frequency expression based on a [VerbalClassifier].
+///
+/// The cardinal form reads its value like a [Count] - as in `两遍`;
+/// the ordinal form is introduced by `第` and reads its value as a plain number - as in `第二次`.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let three_times = VerbalMeasure::new(3, VerbalClassifier::Ci);
+/// assert_eq!(three_times.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三次".into(),
+///     omissible: false
+/// });
+///
+/// assert_eq!(VerbalMeasure::new(2, VerbalClassifier::Bian).to_chinese(Variant::Simplified), "两遍");
+/// assert_eq!(VerbalMeasure::new(1, VerbalClassifier::Tang).to_chinese(Variant::Simplified), "一趟");
+/// assert_eq!(VerbalMeasure::new(2, VerbalClassifier::Dun).to_chinese(Variant::Traditional), "兩頓");
+///
+/// let third = VerbalMeasure::ordinal(3, VerbalClassifier::Ci);
+/// assert_eq!(third.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "第三次".into(),
+///     omissible: false
+/// });
+/// assert_eq!(VerbalMeasure::ordinal(2, VerbalClassifier::Hui).to_chinese(Variant::Simplified), "第二回");
+///
+/// assert_eq!(VerbalMeasure::new(0, VerbalClassifier::Ci).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零次".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VerbalMeasure {
+    pub value: CountBase,
+    pub classifier: VerbalClassifier,
+    pub ordinal: bool,
+}
+
+impl VerbalMeasure {
+    /// Creates a cardinal measure - such as `三次`.
+    pub fn new(value: CountBase, classifier: VerbalClassifier) -> Self {
+        Self {
+            value,
+            classifier,
+            ordinal: false,
+        }
+    }
+
+    /// Creates an ordinal measure - such as `第三次`.
+    pub fn ordinal(value: CountBase, classifier: VerbalClassifier) -> Self {
+        Self {
+            value,
+            classifier,
+            ordinal: true,
+        }
+    }
+}
+
+impl ChineseFormat for VerbalMeasure {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let classifier = self.classifier.to_chinese(variant);
+
+        if self.ordinal {
+            return Chinese {
+                logograms: format!("{}{}{}", DI, self.value.to_chinese(variant), classifier).into(),
+                omissible: false,
+            };
+        }
+
+        let value = Count(self.value).to_chinese(variant);
+
+        Chinese {
+            logograms: format!("{}{}", value.logograms, classifier).into(),
+            omissible: value.omissible,
+        }
+    }
+}
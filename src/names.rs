@@ -0,0 +1,169 @@
+//! Formatting of person references - a surname followed by a title,
+//! as in `王先生` or `张老师`.
+//!
+//! ```
+//! use chinese_format::{*, names::*};
+//!
+//! let teacher = TitledName {
+//!     surname: &("张", "張"),
+//!     title: Title::Teacher
+//! };
+//!
+//! assert_eq!(teacher.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "张老师".into(),
+//!     omissible: false
+//! });
+//!
+//! assert_eq!(teacher.to_chinese(Variant::Traditional), Chinese {
+//!     logograms: "張老師".into(),
+//!     omissible: false
+//! });
+//! ```
+use crate::{chinese_vec, Chinese, ChineseFormat, Variant};
+
+/// Title following a surname.
+///
+/// Apart from [Mister](Self::Mister), [Madam](Self::Madam) and [Miss](Self::Miss),
+/// all the titles are gender-neutral.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Title {
+    /// `先生`
+    Mister,
+
+    /// `女士`
+    Madam,
+
+    /// `小姐`
+    Miss,
+
+    /// `老师`/`老師`
+    Teacher,
+
+    /// `教授`
+    Professor,
+
+    /// `医生`/`醫生`
+    Doctor,
+
+    /// `律师`/`律師`
+    Lawyer,
+
+    /// `经理`/`經理`
+    Manager,
+
+    /// `主任`
+    Director,
+
+    /// `总`/`總` - the colloquial form for top executives, as in `王总`.
+    Boss,
+
+    /// `同学`/`同學`
+    Classmate,
+
+    /// `师傅`/`師傅` - for skilled workers and drivers.
+    Master,
+}
+
+/// Each [Title] can be converted to [Chinese]:
+///
+/// ```
+/// use chinese_format::{*, names::*};
+///
+/// assert_eq!(Title::Mister.to_chinese(Variant::Simplified), "先生");
+/// assert_eq!(Title::Madam.to_chinese(Variant::Traditional), "女士");
+/// assert_eq!(Title::Miss.to_chinese(Variant::Simplified), "小姐");
+///
+/// assert_eq!(Title::Teacher.to_chinese(Variant::Simplified), "老师");
+/// assert_eq!(Title::Teacher.to_chinese(Variant::Traditional), "老師");
+///
+/// assert_eq!(Title::Professor.to_chinese(Variant::Simplified), "教授");
+///
+/// assert_eq!(Title::Doctor.to_chinese(Variant::Simplified), "医生");
+/// assert_eq!(Title::Doctor.to_chinese(Variant::Traditional), "醫生");
+///
+/// assert_eq!(Title::Lawyer.to_chinese(Variant::Simplified), "律师");
+/// assert_eq!(Title::Lawyer.to_chinese(Variant::Traditional), "律師");
+///
+/// assert_eq!(Title::Manager.to_chinese(Variant::Simplified), "经理");
+/// assert_eq!(Title::Manager.to_chinese(Variant::Traditional), "經理");
+///
+/// assert_eq!(Title::Director.to_chinese(Variant::Simplified), "主任");
+///
+/// assert_eq!(Title::Boss.to_chinese(Variant::Simplified), "总");
+/// assert_eq!(Title::Boss.to_chinese(Variant::Traditional), "總");
+///
+/// assert_eq!(Title::Classmate.to_chinese(Variant::Simplified), "同学");
+/// assert_eq!(Title::Classmate.to_chinese(Variant::Traditional), "同學");
+///
+/// assert_eq!(Title::Master.to_chinese(Variant::Simplified), "师傅");
+/// assert_eq!(Title::Master.to_chinese(Variant::Traditional), "師傅");
+/// ```
+impl ChineseFormat for Title {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Self::Mister => "先生".to_chinese(variant),
+            Self::Madam => "女士".to_chinese(variant),
+            Self::Miss => "小姐".to_chinese(variant),
+            Self::Teacher => ("老师", "老師").to_chinese(variant),
+            Self::Professor => "教授".to_chinese(variant),
+            Self::Doctor => ("医生", "醫生").to_chinese(variant),
+            Self::Lawyer => ("律师", "律師").to_chinese(variant),
+            Self::Manager => ("经理", "經理").to_chinese(variant),
+            Self::Director => "主任".to_chinese(variant),
+            Self::Boss => ("总", "總").to_chinese(variant),
+            Self::Classmate => ("同学", "同學").to_chinese(variant),
+            Self::Master => ("师傅", "師傅").to_chinese(variant),
+        }
+    }
+}
+
+/// A surname followed by a [Title].
+///
+/// The surname can be any [ChineseFormat] - in particular, a pair
+/// can be passed when its Simplified and Traditional forms differ.
+///
+/// When the surname is [omissible](Chinese::omissible), only the title is rendered -
+/// which is still a polite way to address a person; therefore,
+/// the result is never omissible.
+///
+/// ```
+/// use chinese_format::{*, names::*};
+///
+/// let mister = TitledName {
+///     surname: &"王",
+///     title: Title::Mister
+/// };
+/// assert_eq!(mister.to_chinese(Variant::Simplified), "王先生");
+///
+/// let madam = TitledName {
+///     surname: &"李",
+///     title: Title::Madam
+/// };
+/// assert_eq!(madam.to_chinese(Variant::Traditional), "李女士");
+///
+/// let professor = TitledName {
+///     surname: &("刘", "劉"),
+///     title: Title::Professor
+/// };
+/// assert_eq!(professor.to_chinese(Variant::Simplified), "刘教授");
+/// assert_eq!(professor.to_chinese(Variant::Traditional), "劉教授");
+///
+/// let anonymous = TitledName {
+///     surname: &"",
+///     title: Title::Doctor
+/// };
+/// assert_eq!(anonymous.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "医生".into(),
+///     omissible: false
+/// });
+/// ```
+pub struct TitledName<'a> {
+    pub surname: &'a dyn ChineseFormat,
+    pub title: Title,
+}
+
+impl<'a> ChineseFormat for TitledName<'a> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.surname, self.title]).collect()
+    }
+}
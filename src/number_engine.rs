@@ -0,0 +1,664 @@
+use crate::{CountMethod, DigitGlyphSet, FinalUnit, TensOne, Variant, ZeroRun};
+use std::fmt::{Debug, Display};
+
+/// The units from `万` onward, each in its (simplified, traditional) form -
+/// the smaller ones being provided by the [DigitGlyphSet].
+const LARGE_UNITS: [(&str, &str); 12] = [
+    ("万", "萬"),
+    ("亿", "億"),
+    ("兆", "兆"),
+    ("京", "京"),
+    ("垓", "垓"),
+    ("秭", "秭"),
+    ("穰", "穰"),
+    ("沟", "溝"),
+    ("涧", "澗"),
+    ("正", "正"),
+    ("载", "載"),
+    ("极", "極"),
+];
+
+const TEN_THOUSAND_INDEX: usize = 3;
+
+const HUNDRED_MILLION_INDEX: usize = 4;
+
+const TRILLION_INDEX: usize = 5;
+
+/// Options affecting how the number engine writes an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct EngineOptions {
+    /// How the runs of zero digits are read.
+    pub zero_run: ZeroRun,
+
+    /// The logograms of the digits and of the units up to `千`.
+    pub glyphs: DigitGlyphSet,
+
+    /// Whether `一` is read before `十`.
+    pub tens_one: TensOne,
+
+    /// Whether the unit after the last digit is read.
+    pub final_unit: FinalUnit,
+}
+
+impl EngineOptions {
+    fn digit(&self, digit: u128, variant: Variant) -> char {
+        self.glyphs.digit(digit as u8, variant)
+    }
+
+    /// Whether `一` is read before a `十` - which is `dependent` if larger units precede it.
+    fn reads_tens_one(&self, dependent: bool) -> bool {
+        match self.tens_one {
+            TensOne::Standard => dependent,
+            TensOne::Explicit => true,
+            TensOne::Omitted => false,
+        }
+    }
+
+    fn zero(&self, variant: Variant) -> char {
+        self.glyphs.digit(0, variant)
+    }
+
+    /// The logograms standing for `missing_digits` consecutive zero digits.
+    fn zeros(&self, missing_digits: usize, variant: Variant) -> String {
+        let zero = self.zero(variant);
+
+        match self.zero_run {
+            ZeroRun::Single => zero.to_string(),
+            ZeroRun::PerDigit => zero.to_string().repeat(missing_digits),
+        }
+    }
+
+    /// The unit at the given index - `十` being at index 0.
+    fn unit(&self, index: usize, variant: Variant) -> String {
+        if index < TEN_THOUSAND_INDEX {
+            return self.glyphs.small_unit(index, variant).to_string();
+        }
+
+        let (simplified, traditional) = LARGE_UNITS[index - TEN_THOUSAND_INDEX];
+
+        match variant {
+            Variant::Simplified => simplified,
+            Variant::Traditional => traditional,
+        }
+        .to_string()
+    }
+}
+
+/// Exclusive upper bound for the absolute values supported by [CountMethod::Low].
+const LOW_LIMIT: u128 = 10u128.pow(16);
+
+/// Integer types supported by the number engine - that is, all the primitive integer types.
+pub trait ChineseInteger: Copy + PartialEq + Display + Debug {
+    /// Whether the value is negative.
+    fn is_negative(self) -> bool;
+
+    /// The absolute value - which never overflows.
+    fn magnitude(self) -> u128;
+}
+
+macro_rules! impl_unsigned_chinese_integer {
+    ($($type: ty),+) => {
+        $(
+            impl ChineseInteger for $type {
+                fn is_negative(self) -> bool {
+                    false
+                }
+
+                fn magnitude(self) -> u128 {
+                    self as u128
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_signed_chinese_integer {
+    ($($type: ty),+) => {
+        $(
+            impl ChineseInteger for $type {
+                fn is_negative(self) -> bool {
+                    self < 0
+                }
+
+                fn magnitude(self) -> u128 {
+                    self.unsigned_abs() as u128
+                }
+            }
+        )+
+    };
+}
+
+impl_unsigned_chinese_integer!(u128, u64, u32, u16, u8, usize);
+impl_signed_chinese_integer!(i128, i64, i32, i16, i8, isize);
+
+/// The number of digits of the value - 0 for zero.
+fn digit_count(value: u128) -> usize {
+    if value == 0 {
+        0
+    } else {
+        value.ilog10() as usize + 1
+    }
+}
+
+/// The number of zero digits after the lowest non-zero digit of the value - 0 for zero.
+fn trailing_zero_count(value: u128) -> usize {
+    let digit_at = |position: u32| value / 10u128.pow(position) % 10;
+
+    (0..digit_count(value) as u32)
+        .take_while(|position| digit_at(*position) == 0)
+        .count()
+}
+
+/// Reads a value less than [LOW_LIMIT], each unit being 10 times the previous one.
+///
+/// When `dependent` - that is, when larger groups precede it - a leading `十`
+/// is read as `一十`, as in `十万零一十` - unless stated otherwise by [TensOne].
+fn low_to_logograms(
+    mut value: u128,
+    variant: Variant,
+    dependent: bool,
+    options: &EngineOptions,
+) -> String {
+    let mut result = String::new();
+
+    let lowest_digit = value % 10;
+    value /= 10;
+
+    if lowest_digit > 0 {
+        result.push(options.digit(lowest_digit, variant));
+    } else if value == 0 {
+        return options.zero(variant).to_string();
+    }
+
+    let tens_digit = value % 10;
+    value /= 10;
+
+    if tens_digit > 0 {
+        result.insert_str(0, &options.unit(0, variant));
+
+        if tens_digit > 1 || options.reads_tens_one(value > 0 || dependent) {
+            result.insert(0, options.digit(tens_digit, variant));
+        }
+    }
+
+    //The zero digits between the current digit and the highest digit read so far
+    let mut missing_digits = if tens_digit == 0 && !result.is_empty() {
+        1
+    } else {
+        0
+    };
+    let mut unit_index = 1;
+
+    while value > 0 {
+        let digit = value % 10;
+        value /= 10;
+
+        if digit > 0 {
+            if missing_digits > 0 {
+                result.insert_str(0, &options.zeros(missing_digits, variant));
+            }
+
+            result.insert_str(0, &options.unit(unit_index, variant));
+            result.insert(0, options.digit(digit, variant));
+
+            missing_digits = 0;
+        } else if !result.is_empty() {
+            missing_digits += 1;
+        }
+
+        unit_index += 1;
+    }
+
+    result
+}
+
+/// Reads a value split into groups of `group_size` - each group being read by `read_group`
+/// and followed by the unit at `first_unit_index` onward.
+fn grouped_to_logograms(
+    mut value: u128,
+    variant: Variant,
+    dependent: bool,
+    group_size: u128,
+    first_unit_index: usize,
+    options: &EngineOptions,
+    read_group: fn(u128, Variant, bool, &EngineOptions) -> String,
+) -> String {
+    let group_digits = digit_count(group_size) - 1;
+
+    let lowest_group = value % group_size;
+    value /= group_size;
+
+    let mut result = if lowest_group > 0 {
+        read_group(lowest_group, variant, dependent || value > 0, options)
+    } else if value == 0 {
+        return options.zero(variant).to_string();
+    } else {
+        String::new()
+    };
+
+    //The zero digits between the current group and the highest digit read so far
+    let mut missing_digits = if lowest_group > 0 {
+        group_digits - digit_count(lowest_group)
+    } else {
+        0
+    };
+    let mut unit_index = first_unit_index;
+
+    while value > 0 {
+        let group = value % group_size;
+        value /= group_size;
+
+        if group > 0 {
+            if missing_digits > 0 {
+                //The trailing zero digits of the group are skipped as well
+                result.insert_str(
+                    0,
+                    &options.zeros(missing_digits + trailing_zero_count(group), variant),
+                );
+            }
+
+            result.insert_str(0, &options.unit(unit_index, variant));
+            result.insert_str(
+                0,
+                &read_group(group, variant, dependent || value > 0, options),
+            );
+
+            missing_digits = group_digits - digit_count(group);
+        } else if !result.is_empty() {
+            missing_digits += group_digits;
+        }
+
+        unit_index += 1;
+    }
+
+    result
+}
+
+fn ten_thousand_to_logograms(
+    value: u128,
+    variant: Variant,
+    dependent: bool,
+    options: &EngineOptions,
+) -> String {
+    grouped_to_logograms(
+        value,
+        variant,
+        dependent,
+        10_000,
+        TEN_THOUSAND_INDEX,
+        options,
+        low_to_logograms,
+    )
+}
+
+fn middle_to_logograms(
+    value: u128,
+    variant: Variant,
+    dependent: bool,
+    options: &EngineOptions,
+) -> String {
+    grouped_to_logograms(
+        value,
+        variant,
+        dependent,
+        100_000_000,
+        HUNDRED_MILLION_INDEX,
+        options,
+        ten_thousand_to_logograms,
+    )
+}
+
+/// Starting from `兆` - worth 10^16 - each unit is the square of the previous one.
+fn high_to_logograms(
+    mut value: u128,
+    variant: Variant,
+    dependent: bool,
+    options: &EngineOptions,
+) -> String {
+    let mut group_size = 10u128.pow(16);
+
+    let lowest_group = value % group_size;
+    value /= group_size;
+
+    let mut result = if lowest_group > 0 {
+        middle_to_logograms(lowest_group, variant, dependent || value > 0, options)
+    } else if value == 0 {
+        return options.zero(variant).to_string();
+    } else {
+        String::new()
+    };
+
+    //The zero digits between the current group and the highest digit read so far
+    let mut missing_digits = if lowest_group > 0 {
+        digit_count(group_size) - 1 - digit_count(lowest_group)
+    } else {
+        0
+    };
+    let mut unit_index = TRILLION_INDEX;
+
+    while value > 0 {
+        let group_digits = digit_count(group_size) - 1;
+
+        let group = value % group_size;
+        value /= group_size;
+
+        if group > 0 {
+            if missing_digits > 0 {
+                //The trailing zero digits of the group are skipped as well
+                result.insert_str(
+                    0,
+                    &options.zeros(missing_digits + trailing_zero_count(group), variant),
+                );
+            }
+
+            result.insert_str(0, &options.unit(unit_index, variant));
+            result.insert_str(
+                0,
+                &high_to_logograms(group, variant, dependent || value > 0, options),
+            );
+
+            missing_digits = group_digits - digit_count(group);
+        } else if !result.is_empty() {
+            missing_digits += group_digits;
+        }
+
+        if value == 0 {
+            break;
+        }
+
+        unit_index += 1;
+        group_size *= group_size;
+    }
+
+    result
+}
+
+fn magnitude_to_logograms(
+    magnitude: u128,
+    variant: Variant,
+    count_method: CountMethod,
+    options: &EngineOptions,
+) -> String {
+    match count_method {
+        CountMethod::Low => low_to_logograms(magnitude, variant, false, options),
+        CountMethod::TenThousand => ten_thousand_to_logograms(magnitude, variant, false, options),
+        CountMethod::Middle => middle_to_logograms(magnitude, variant, false, options),
+        CountMethod::High => high_to_logograms(magnitude, variant, false, options),
+    }
+}
+
+/// When the unit after the last digit can be dropped - that is, when it is `十`, `百` or `千`
+/// and the digit immediately precedes a non-zero digit - splits the value into
+/// the higher digits - read as usual - and the last digit, to be read without unit.
+fn dropped_final_unit(magnitude: u128, final_unit: FinalUnit) -> Option<(u128, u128)> {
+    if final_unit == FinalUnit::Voiced || magnitude == 0 {
+        return None;
+    }
+
+    let digit_at = |position: u32| magnitude / 10u128.pow(position) % 10;
+
+    let position = (0..).find(|position| digit_at(*position) != 0)?;
+    let last_digit = digit_at(position);
+
+    ((1..=3).contains(&position) && digit_at(position + 1) != 0)
+        .then_some((magnitude - last_digit * 10u128.pow(position), last_digit))
+}
+
+/// Converts an integer to lowercase Chinese logograms using the given [CountMethod].
+///
+/// Returns [None] when the value cannot be expressed via the count method -
+/// which only happens with [CountMethod::Low], for absolute values not less than `10^16`.
+pub(crate) fn integer_to_logograms<T: ChineseInteger>(
+    value: T,
+    variant: Variant,
+    count_method: CountMethod,
+) -> Option<String> {
+    integer_to_logograms_with_options(value, variant, count_method, EngineOptions::default())
+}
+
+/// Like [integer_to_logograms], but writing the integer according to the given [EngineOptions].
+pub(crate) fn integer_to_logograms_with_options<T: ChineseInteger>(
+    value: T,
+    variant: Variant,
+    count_method: CountMethod,
+    options: EngineOptions,
+) -> Option<String> {
+    let magnitude = value.magnitude();
+
+    if count_method == CountMethod::Low && magnitude >= LOW_LIMIT {
+        return None;
+    }
+
+    let magnitude_logograms = match dropped_final_unit(magnitude, options.final_unit) {
+        Some((head, last_digit)) => format!(
+            "{}{}",
+            magnitude_to_logograms(head, variant, count_method, &options),
+            options.digit(last_digit, variant)
+        ),
+
+        None => magnitude_to_logograms(magnitude, variant, count_method, &options),
+    };
+
+    Some(if value.is_negative() {
+        let minus = match variant {
+            Variant::Simplified => "负",
+            Variant::Traditional => "負",
+        };
+
+        format!("{}{}", minus, magnitude_logograms)
+    } else {
+        magnitude_logograms
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Converting an integer via the number engine" {
+            fn test_case(value: i128, count_method: CountMethod, expected: Option<(&str, &str)>) {
+                eq!(
+                    integer_to_logograms(value, Variant::Simplified, count_method),
+                    expected.map(|(simplified, _)| simplified.to_string())
+                );
+
+                eq!(
+                    integer_to_logograms(value, Variant::Traditional, count_method),
+                    expected.map(|(_, traditional)| traditional.to_string())
+                );
+            }
+
+            describe "with the low count method" {
+                it "should make each unit 10 times the previous one" {
+                    test_case(100_010, CountMethod::Low, Some(("一亿零一十", "一億零一十")));
+                    test_case(1_000_000_001, CountMethod::Low, Some(("一秭零一", "一秭零一")));
+                }
+
+                it "should reject values from 10^16" {
+                    test_case(10i128.pow(16), CountMethod::Low, None);
+                    test_case(-10i128.pow(16), CountMethod::Low, None);
+                }
+            }
+
+            describe "with the ten-thousand count method" {
+                it "should read a leading ten as 一十 only when dependent" {
+                    test_case(10, CountMethod::TenThousand, Some(("十", "十")));
+                    test_case(110, CountMethod::TenThousand, Some(("一百一十", "一百一十")));
+                    test_case(100_010, CountMethod::TenThousand, Some(("十万零一十", "十萬零一十")));
+                }
+
+                it "should insert 零 for the gaps" {
+                    test_case(1001, CountMethod::TenThousand, Some(("一千零一", "一千零一")));
+                    test_case(
+                        100_010_000_000_000_000_010,
+                        CountMethod::TenThousand,
+                        Some(("一垓零一京零一十", "一垓零一京零一十"))
+                    );
+                }
+
+                it "should support the whole range of i128" {
+                    test_case(
+                        -10i128.pow(32) - 1,
+                        CountMethod::TenThousand,
+                        Some(("负一沟零一", "負一溝零一"))
+                    );
+                    eq!(
+                        integer_to_logograms(u128::MAX, Variant::Simplified, CountMethod::TenThousand),
+                        Some("三百四十涧二千八百二十三沟六千六百九十二穰零九百三十八秭四千六百三十四垓六千三百三十七京四千六百零七兆四千三百一十七亿六千八百二十一万一千四百五十五".to_string())
+                    );
+                }
+            }
+
+            describe "with the middle count method" {
+                it "should make each unit from 亿 10^8 times the previous one" {
+                    test_case(10i128.pow(16), CountMethod::Middle, Some(("一兆", "一兆")));
+                    test_case(
+                        100_010_000_000_000_000_010,
+                        CountMethod::Middle,
+                        Some(("一万零一兆零一十", "一萬零一兆零一十"))
+                    );
+                    test_case(-10i128.pow(32) - 1, CountMethod::Middle, Some(("负一垓零一", "負一垓零一")));
+                }
+            }
+
+            describe "with the high count method" {
+                it "should make each unit from 兆 the square of the previous one" {
+                    test_case(10i128.pow(16), CountMethod::High, Some(("一兆", "一兆")));
+                    test_case(
+                        12_3456_7890_1234_5678_9012_3456,
+                        CountMethod::High,
+                        Some((
+                            "十二亿三千四百五十六万七千八百九十兆一千二百三十四万五千六百七十八亿九千零一十二万三千四百五十六",
+                            "十二億三千四百五十六萬七千八百九十兆一千二百三十四萬五千六百七十八億九千零一十二萬三千四百五十六"
+                        ))
+                    );
+                    test_case(-10i128.pow(32) - 1, CountMethod::High, Some(("负一京零一", "負一京零一")));
+                }
+            }
+
+            describe "with per-digit zero runs" {
+                fn per_digit(value: i128, count_method: CountMethod) -> Option<String> {
+                    integer_to_logograms_with_options(
+                        value,
+                        Variant::Simplified,
+                        count_method,
+                        EngineOptions {
+                            zero_run: ZeroRun::PerDigit,
+                            ..EngineOptions::default()
+                        }
+                    )
+                }
+
+                it "should read a 零 for each zero digit" {
+                    eq!(per_digit(3005, CountMethod::TenThousand), Some("三千零零五".to_string()));
+                    eq!(per_digit(10_100, CountMethod::Low), Some("一万零一百".to_string()));
+                    eq!(per_digit(1_000_001, CountMethod::Low), Some("一兆零零零零零一".to_string()));
+                }
+
+                it "should count the zero digits across groups" {
+                    eq!(
+                        per_digit(100_000_005, CountMethod::TenThousand),
+                        Some("一亿零零零零零零零五".to_string())
+                    );
+                    eq!(
+                        per_digit(1_000_500, CountMethod::TenThousand),
+                        Some("一百万零零零五百".to_string())
+                    );
+                    eq!(
+                        per_digit(10i128.pow(16) + 1, CountMethod::Middle),
+                        Some(format!("一兆{}一", "零".repeat(15)))
+                    );
+                    eq!(
+                        per_digit(10i128.pow(16) + 1, CountMethod::High),
+                        Some(format!("一兆{}一", "零".repeat(15)))
+                    );
+                }
+
+                it "should count the trailing zero digits of the higher group" {
+                    eq!(per_digit(100_005, CountMethod::TenThousand), Some("十万零零零零五".to_string()));
+                    eq!(per_digit(10_005, CountMethod::TenThousand), Some("一万零零零五".to_string()));
+                    eq!(
+                        per_digit(1_000_000_005, CountMethod::TenThousand),
+                        Some(format!("十亿{}五", "零".repeat(8)))
+                    );
+                    eq!(
+                        per_digit(10i128.pow(17) + 1, CountMethod::Middle),
+                        Some(format!("十兆{}一", "零".repeat(16)))
+                    );
+                    eq!(
+                        per_digit(10i128.pow(17) + 1, CountMethod::High),
+                        Some(format!("十兆{}一", "零".repeat(16)))
+                    );
+                }
+
+                it "should not read the trailing zero digits" {
+                    eq!(per_digit(3000, CountMethod::TenThousand), Some("三千".to_string()));
+                    eq!(per_digit(1_050_000, CountMethod::TenThousand), Some("一百零五万".to_string()));
+                }
+            }
+
+            describe "with custom options" {
+                fn styled(value: i128, options: EngineOptions) -> Option<String> {
+                    integer_to_logograms_with_options(
+                        value,
+                        Variant::Simplified,
+                        CountMethod::TenThousand,
+                        options
+                    )
+                }
+
+                it "should omit 一 before every 十" {
+                    let options = EngineOptions {
+                        tens_one: TensOne::Omitted,
+                        ..EngineOptions::default()
+                    };
+
+                    eq!(styled(100_100_010, options), Some("一亿零十万零十".to_string()));
+                }
+
+                it "should read 一 before every 十" {
+                    let options = EngineOptions {
+                        tens_one: TensOne::Explicit,
+                        ..EngineOptions::default()
+                    };
+
+                    eq!(styled(-10, options), Some("负一十".to_string()));
+                }
+
+                it "should drop the final unit across groups" {
+                    let options = EngineOptions {
+                        final_unit: FinalUnit::Dropped,
+                        ..EngineOptions::default()
+                    };
+
+                    eq!(styled(25_000, options), Some("二万五".to_string()));
+                    eq!(styled(200_000_150, options), Some("二亿零一百五".to_string()));
+                    eq!(styled(250_000, options), Some("二十五万".to_string()));
+                }
+
+                it "should keep the last digit when dropping the final unit" {
+                    let options = EngineOptions {
+                        tens_one: TensOne::Omitted,
+                        final_unit: FinalUnit::Dropped,
+                        ..EngineOptions::default()
+                    };
+
+                    eq!(styled(110, options), Some("一百一".to_string()));
+                }
+
+                it "should write the digits via the glyph set" {
+                    let options = EngineOptions {
+                        glyphs: DigitGlyphSet::FINANCIAL,
+                        tens_one: TensOne::Omitted,
+                        ..EngineOptions::default()
+                    };
+
+                    eq!(styled(117, options), Some("壹佰拾柒".to_string()));
+                }
+            }
+        }
+    }
+}
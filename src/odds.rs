@@ -0,0 +1,85 @@
+use crate::{Chinese, ChineseFormat, Variant};
+
+/// Betting odds - as in `一赔三` - stating the payout for the given stake.
+///
+/// Both amounts are read as integers - so `2` is always `二`.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(Odds(1, 3).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "一赔三".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Odds(1, 3).to_chinese(Variant::Traditional), "一賠三");
+///
+/// assert_eq!(Odds(2, 15).to_chinese(Variant::Simplified), "二赔十五");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Odds(pub u32, pub u32);
+
+impl Odds {
+    const PEI: (&'static str, &'static str) = ("赔", "賠");
+}
+
+impl ChineseFormat for Odds {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!(
+                "{}{}{}",
+                self.0.to_chinese(variant),
+                Self::PEI.to_chinese(variant),
+                self.1.to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Probability phrase - as in `概率为百分之二十五` - whose value can be
+/// any [ChineseFormat], such as a [Percent](crate::Percent) or a [Fraction](crate::Fraction).
+///
+/// The phrase is never [omissible](Chinese::omissible) - even when the probability is 0.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let chance = Probability(Percent(25));
+/// assert_eq!(chance.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "概率为百分之二十五".into(),
+///     omissible: false
+/// });
+/// assert_eq!(chance.to_chinese(Variant::Traditional), "概率為百分之二十五");
+///
+/// let third = Probability(Fraction::try_new(3, 1)?);
+/// assert_eq!(third.to_chinese(Variant::Simplified), "概率为三分之一");
+///
+/// assert_eq!(Probability(Percent(0)).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "概率为百分之零".into(),
+///     omissible: false
+/// });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Probability<T: ChineseFormat>(pub T);
+
+impl<T: ChineseFormat> Probability<T> {
+    const GAILV_WEI: (&'static str, &'static str) = ("概率为", "概率為");
+}
+
+impl<T: ChineseFormat> ChineseFormat for Probability<T> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!(
+                "{}{}",
+                Self::GAILV_WEI.to_chinese(variant),
+                self.0.to_chinese(variant)
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{Chinese, ChineseFormat, CompositionStyle, Variant};
+
+/// Decides whether a [Chinese] outcome is [omissible](Chinese::omissible).
+///
+/// Closures taking a `&Chinese` and returning a [bool] are policies, too.
+pub trait OmissibilityPolicy {
+    fn is_omissible(&self, chinese: &Chinese) -> bool;
+}
+
+/// Ready-made [OmissibilityPolicy] values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Omissibility {
+    /// Keeps the omissibility decided by the wrapped value - usually, for zero or empty values.
+    #[default]
+    Inherited,
+
+    /// The value is never omissible - for example, `零元` on a receipt.
+    Never,
+
+    /// The value is always omissible.
+    Always,
+}
+
+impl OmissibilityPolicy for Omissibility {
+    fn is_omissible(&self, chinese: &Chinese) -> bool {
+        match self {
+            Omissibility::Inherited => chinese.omissible,
+            Omissibility::Never => false,
+            Omissibility::Always => true,
+        }
+    }
+}
+
+impl<F: Fn(&Chinese) -> bool> OmissibilityPolicy for F {
+    fn is_omissible(&self, chinese: &Chinese) -> bool {
+        self(chinese)
+    }
+}
+
+/// Wraps a [ChineseFormat] value, overriding its [omissible](Chinese::omissible)
+/// property via an [OmissibilityPolicy] - while its logograms are left unchanged.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let zero = OmissibleWhen::new(Count(0), Omissibility::Never);
+/// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: false
+/// });
+///
+/// let always = OmissibleWhen::new("备注", Omissibility::Always);
+/// assert_eq!(always.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "备注".into(),
+///     omissible: true
+/// });
+///
+/// let inherited = OmissibleWhen::new(Count(0), Omissibility::Inherited);
+/// assert_eq!(inherited.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零".into(),
+///     omissible: true
+/// });
+///
+/// //Custom policy
+/// let at_most_one = |chinese: &Chinese| chinese.logograms == "零" || chinese.logograms == "一";
+/// assert!(OmissibleWhen::new(Count(1), at_most_one).to_chinese(Variant::Simplified).omissible);
+/// assert!(!OmissibleWhen::new(Count(2), at_most_one).to_chinese(Variant::Simplified).omissible);
+/// ```
+///
+/// The policy affects all the constructs relying on omissibility - such as
+/// [ChineseVec::trim_start](crate::ChineseVec::trim_start) and [placeholders](crate::Placeholder):
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let placeholder = Placeholder::empty(OmissibleWhen::new(Count(0), Omissibility::Never));
+/// assert_eq!(placeholder.to_chinese(Variant::Simplified), "零");
+///
+/// let trimmed = chinese_vec!(Variant::Simplified, [
+///     Count(0),
+///     OmissibleWhen::new(Count(0), Omissibility::Never),
+///     "元"
+/// ])
+/// .trim_start();
+/// assert_eq!(trimmed.collect(), "零元");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OmissibleWhen<T: ChineseFormat, P: OmissibilityPolicy> {
+    pub value: T,
+    pub policy: P,
+}
+
+impl<T: ChineseFormat, P: OmissibilityPolicy> OmissibleWhen<T, P> {
+    pub fn new(value: T, policy: P) -> Self {
+        Self { value, policy }
+    }
+}
+
+impl<T: ChineseFormat, P: OmissibilityPolicy> ChineseFormat for OmissibleWhen<T, P> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let chinese = self.value.to_chinese_composed(variant, style);
+
+        Chinese {
+            omissible: self.policy.is_omissible(&chinese),
+            ..chinese
+        }
+    }
+}
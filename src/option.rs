@@ -1,4 +1,4 @@
-use crate::{Chinese, ChineseFormat, Variant};
+use crate::{Chinese, ChineseFormat, ChineseParts, Variant};
 
 /// The [Option] of a [ChineseFormat] is a [ChineseFormat] itself.
 ///
@@ -13,7 +13,7 @@ use crate::{Chinese, ChineseFormat, Variant};
 ///
 /// let non_zero = Some(90u8);
 /// assert_eq!(non_zero.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "九十".to_string(),
+///     logograms: "九十".into(),
 ///     omissible: false
 /// });
 ///
@@ -21,7 +21,7 @@ use crate::{Chinese, ChineseFormat, Variant};
 /// assert_eq!(
 ///     zero.to_chinese(Variant::Simplified),
 ///     Chinese {
-///         logograms: "零".to_string(),
+///         logograms: "零".into(),
 ///         omissible: true
 ///     }
 /// );
@@ -30,7 +30,7 @@ use crate::{Chinese, ChineseFormat, Variant};
 /// assert_eq!(
 ///     none.to_chinese(Variant::Simplified),
 ///     Chinese {
-///         logograms: "".to_string(),
+///         logograms: "".into(),
 ///         omissible: true
 ///     }
 /// );
@@ -40,9 +40,24 @@ impl<T: ChineseFormat> ChineseFormat for Option<T> {
         match self {
             Some(value) => value.to_chinese(variant),
             None => Chinese {
-                logograms: String::new(),
+                logograms: "".into(),
                 omissible: true,
             },
         }
     }
+
+    fn to_chinese_parts(&self, variant: Variant) -> ChineseParts {
+        match self {
+            Some(value) => value.to_chinese_parts(variant),
+            None => ChineseParts::default(),
+        }
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        match self {
+            Some(value) => value.to_chinese_tokens(variant),
+            None => vec![],
+        }
+    }
 }
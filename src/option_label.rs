@@ -0,0 +1,206 @@
+use crate::{Chinese, ChineseFormat, Tiangan, Variant};
+use std::{error::Error, fmt::Display};
+
+/// Error for when an [OptionLabel] index exceeds the labels available for its style.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     OptionIndexOutOfRange(10).to_string(),
+///     "Option index out of range: 10"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OptionIndexOutOfRange(pub usize);
+
+impl Display for OptionIndexOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Option index out of range: {}", self.0)
+    }
+}
+
+impl Error for OptionIndexOutOfRange {}
+
+/// Error for when a character is not an [OptionLabel].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     InvalidOptionLabel('?').to_string(),
+///     "Invalid option label: ?"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidOptionLabel(pub char);
+
+impl Display for InvalidOptionLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid option label: {}", self.0)
+    }
+}
+
+impl Error for InvalidOptionLabel {}
+
+/// How an [OptionLabel] is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum OptionLabelStyle {
+    /// `甲`, `乙`, `丙`, `丁`, ... - up to 10 labels.
+    #[default]
+    Tiangan,
+
+    /// `A`, `B`, `C`, `D`, ... - up to 26 labels.
+    Letter,
+
+    /// Like [Letter](Self::Letter), but preceded by `选项`/`選項` - as in `选项A`.
+    PrefixedLetter,
+}
+
+impl OptionLabelStyle {
+    /// The number of available labels.
+    pub fn capacity(&self) -> usize {
+        match self {
+            Self::Tiangan => Tiangan::ALL.len(),
+            Self::Letter | Self::PrefixedLetter => 26,
+        }
+    }
+}
+
+const XUANXIANG: (&str, &str) = ("选项", "選項");
+
+/// Label of an option - such as `甲` or `选项A` - for questionnaire generation.
+///
+/// It is created from a 0-based index - and can be parsed back from its label:
+/// the same index can therefore be mapped across styles, as in `A` ⇄ `甲`.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let third = OptionLabel::try_new(2, OptionLabelStyle::Tiangan)?;
+/// assert_eq!(third.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "丙".into(),
+///     omissible: false
+/// });
+///
+/// let letter = third.with_style(OptionLabelStyle::Letter)?;
+/// assert_eq!(letter.to_chinese(Variant::Simplified), "C");
+///
+/// let prefixed = OptionLabel::try_new(0, OptionLabelStyle::PrefixedLetter)?;
+/// assert_eq!(prefixed.to_chinese(Variant::Simplified), "选项A");
+/// assert_eq!(prefixed.to_chinese(Variant::Traditional), "選項A");
+///
+/// let labels: Vec<String> = (0..4)
+///     .map(|index| OptionLabel::try_new(index, OptionLabelStyle::Tiangan))
+///     .collect::<Result<Vec<_>, _>>()?
+///     .iter()
+///     .map(|label| label.to_chinese(Variant::Simplified).logograms.into_owned())
+///     .collect();
+/// assert_eq!(labels, vec!["甲", "乙", "丙", "丁"]);
+///
+/// let parsed = OptionLabel::try_from('D')?;
+/// assert_eq!(parsed.index(), 3);
+/// assert_eq!(parsed.style(), OptionLabelStyle::Letter);
+/// assert_eq!(
+///     parsed.with_style(OptionLabelStyle::Tiangan)?.to_chinese(Variant::Simplified),
+///     "丁"
+/// );
+///
+/// assert_eq!(OptionLabel::try_from('戊')?.index(), 4);
+/// assert_eq!(OptionLabel::try_from('?'), Err(InvalidOptionLabel('?')));
+///
+/// assert_eq!(
+///     OptionLabel::try_new(10, OptionLabelStyle::Tiangan),
+///     Err(OptionIndexOutOfRange(10))
+/// );
+/// assert_eq!(
+///     OptionLabel::try_new(11, OptionLabelStyle::Letter)?.with_style(OptionLabelStyle::Tiangan),
+///     Err(OptionIndexOutOfRange(11))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OptionLabel {
+    index: usize,
+    style: OptionLabelStyle,
+}
+
+impl OptionLabel {
+    /// Tries to create a label from its 0-based index - failing with
+    /// [OptionIndexOutOfRange] if the style has not enough labels.
+    pub fn try_new(
+        index: usize,
+        style: OptionLabelStyle,
+    ) -> Result<OptionLabel, OptionIndexOutOfRange> {
+        if index >= style.capacity() {
+            return Err(OptionIndexOutOfRange(index));
+        }
+
+        Ok(Self { index, style })
+    }
+
+    /// Tries to render the same index via another style.
+    pub fn with_style(self, style: OptionLabelStyle) -> Result<Self, OptionIndexOutOfRange> {
+        Self::try_new(self.index, style)
+    }
+
+    /// The 0-based index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn style(&self) -> OptionLabelStyle {
+        self.style
+    }
+
+    fn label(&self) -> char {
+        match self.style {
+            OptionLabelStyle::Tiangan => Tiangan::from_index(self.index).to_char(),
+            OptionLabelStyle::Letter | OptionLabelStyle::PrefixedLetter => {
+                (b'A' + self.index as u8) as char
+            }
+        }
+    }
+}
+
+/// [OptionLabel] can be obtained from an uppercase ASCII letter -
+/// having [OptionLabelStyle::Letter] - or from a [Tiangan] -
+/// having [OptionLabelStyle::Tiangan].
+impl TryFrom<char> for OptionLabel {
+    type Error = InvalidOptionLabel;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if value.is_ascii_uppercase() {
+            return Ok(Self {
+                index: (value as u8 - b'A') as usize,
+                style: OptionLabelStyle::Letter,
+            });
+        }
+
+        Tiangan::from_char(value)
+            .map(|tiangan| Self {
+                index: tiangan.index(),
+                style: OptionLabelStyle::Tiangan,
+            })
+            .ok_or(InvalidOptionLabel(value))
+    }
+}
+
+impl ChineseFormat for OptionLabel {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = match self.style {
+            OptionLabelStyle::PrefixedLetter => {
+                format!("{}{}", XUANXIANG.to_chinese(variant), self.label())
+            }
+
+            _ => self.label().to_string(),
+        };
+
+        Chinese {
+            logograms: logograms.into(),
+            omissible: false,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::Chinese;
+use std::{borrow::Cow, vec};
+
+/// Iterator over the logogram segments returned by
+/// [ChineseFormat::to_chinese_parts](crate::ChineseFormat::to_chinese_parts).
+///
+/// Concatenating its segments yields the logograms of the related [Chinese];
+/// empty segments are never returned.
+///
+/// ```
+/// use chinese_format::*;
+/// use std::borrow::Cow;
+///
+/// let parts: ChineseParts = vec![Cow::Borrowed("三"), Cow::Borrowed(""), Cow::Borrowed("元")].into();
+/// assert_eq!(parts.collect::<Vec<_>>(), vec!["三", "元"]);
+///
+/// let parts: ChineseParts = Chinese::from_static("元").into();
+/// assert_eq!(parts.collect::<String>(), "元");
+///
+/// let empty: ChineseParts = "".to_chinese(Variant::Simplified).into();
+/// assert_eq!(empty.count(), 0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChineseParts(vec::IntoIter<Cow<'static, str>>);
+
+impl Iterator for ChineseParts {
+    type Item = Cow<'static, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ChineseParts {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for ChineseParts {}
+
+impl From<Vec<Cow<'static, str>>> for ChineseParts {
+    fn from(segments: Vec<Cow<'static, str>>) -> Self {
+        segments.into_iter().collect()
+    }
+}
+
+impl From<Chinese> for ChineseParts {
+    fn from(chinese: Chinese) -> Self {
+        vec![chinese.logograms].into()
+    }
+}
+
+impl FromIterator<Cow<'static, str>> for ChineseParts {
+    fn from_iter<I: IntoIterator<Item = Cow<'static, str>>>(segments: I) -> Self {
+        Self(
+            segments
+                .into_iter()
+                .filter(|segment| !segment.is_empty())
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+    }
+}
+
+/// Chaining [ChineseParts] yields the segments of each one, in order.
+impl FromIterator<ChineseParts> for ChineseParts {
+    fn from_iter<I: IntoIterator<Item = ChineseParts>>(parts: I) -> Self {
+        parts.into_iter().flatten().collect()
+    }
+}
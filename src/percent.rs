@@ -0,0 +1,133 @@
+use crate::{chinese_vec, Chinese, ChineseFormat, Sign, Variant};
+
+/// Integer percentage, rendered as `百分之` followed by the number.
+///
+/// The sign, if negative, precedes the whole expression;
+/// the result is [omissible](Chinese::omissible) only when the value is 0.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(Percent(30).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "百分之三十".into(),
+///     omissible: false
+/// });
+/// assert_eq!(Percent(30).to_chinese(Variant::Traditional), "百分之三十");
+///
+/// assert_eq!(Percent(2).to_chinese(Variant::Simplified), "百分之二");
+///
+/// assert_eq!(Percent(100).to_chinese(Variant::Simplified), "百分之一百");
+///
+/// assert_eq!(Percent(-5).to_chinese(Variant::Simplified), "负百分之五");
+/// assert_eq!(Percent(-5).to_chinese(Variant::Traditional), "負百分之五");
+///
+/// assert_eq!(Percent(0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "百分之零".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Percent(pub i128);
+
+impl From<Percent> for i128 {
+    fn from(source: Percent) -> Self {
+        source.0
+    }
+}
+
+impl ChineseFormat for Percent {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = chinese_vec!(variant, [Sign(self.0), "百分之", self.0.unsigned_abs()])
+            .collect()
+            .logograms;
+
+        Chinese {
+            logograms,
+            omissible: self.0 == 0,
+        }
+    }
+}
+
+/// Percentage having a fractional part - such as `百分之三点八五` -
+/// rendered like [Percent], but based on a [Decimal](crate::Decimal).
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let rate = DecimalPercent(Decimal {
+///     integer: 3,
+///     fractional: 85u8.into()
+/// });
+/// assert_eq!(rate.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "百分之三点八五".into(),
+///     omissible: false
+/// });
+/// assert_eq!(rate.to_chinese(Variant::Traditional), "百分之三點八五");
+///
+/// let loss = DecimalPercent(Decimal {
+///     integer: -12,
+///     fractional: 5u8.into()
+/// });
+/// assert_eq!(loss.to_chinese(Variant::Simplified), "负百分之十二点五");
+///
+/// let zero = DecimalPercent(Decimal {
+///     integer: 0,
+///     fractional: "0".parse().unwrap()
+/// });
+/// assert_eq!(zero.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "百分之零点零".into(),
+///     omissible: true
+/// });
+/// ```
+///
+/// **REQUIRED FEATURE**: `digit-sequence`.
+#[cfg(feature = "digit-sequence")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecimalPercent(pub crate::Decimal);
+
+#[cfg(feature = "digit-sequence")]
+impl ChineseFormat for DecimalPercent {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = chinese_vec!(
+            variant,
+            [
+                Sign(self.0.integer),
+                "百分之",
+                self.0.magnitude_to_chinese(variant).logograms
+            ]
+        )
+        .collect()
+        .logograms;
+
+        Chinese {
+            logograms,
+            omissible: self.0.is_zero(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "digit-sequence"))]
+mod tests {
+    use super::*;
+    use crate::Decimal;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Converting a decimal percent" {
+            it "should support the minimum integer part" {
+                let percent = DecimalPercent(Decimal {
+                    integer: i128::MIN,
+                    fractional: 5u8.into()
+                });
+
+                let chinese = percent.to_chinese(Variant::Simplified);
+
+                eq!(
+                    chinese,
+                    "负百分之一百七十涧一千四百一十一沟八千三百四十六穰零四百六十九秭二千三百一十七垓三千一百六十八京七千三百零三兆七千一百五十八亿八千四百一十万五千七百二十八点五"
+                );
+            }
+        }
+    }
+}
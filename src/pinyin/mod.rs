@@ -0,0 +1,517 @@
+//! Pinyin transliteration of the logograms produced by this crate.
+//!
+//! The lookup table covers the vocabulary emitted by the crate's own
+//! conversions - digits, large units, measure units, date/time, traditional time,
+//! currency and finance words, titles, places and so on;
+//! any other logogram has no pinyin and is left unannotated.
+//!
+//! ```
+//! use chinese_format::pinyin::*;
+//!
+//! assert_eq!(pinyin('三'), Some("sān"));
+//! assert_eq!(pinyin('點'), Some("diǎn"));
+//! assert_eq!(pinyin('X'), None);
+//! ```
+//!
+//! Furthermore, the module provides structured outputs, such as:
+//!
+//! * HTML `<ruby>` annotations, via [RubyHtml].
+//!
+//! * sequences of [Token], via [Tokenize].
+//!
+//! **REQUIRED FEATURE**: `pinyin`.
+mod tokens;
+
+pub use tokens::*;
+
+use crate::{ChineseFormat, Variant};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref PINYIN_BY_LOGOGRAM: HashMap<char, &'static str> = HashMap::from([
+        //Digits
+        ('零', "líng"),
+        ('〇', "líng"),
+        ('一', "yī"),
+        ('幺', "yāo"),
+        ('二', "èr"),
+        ('两', "liǎng"),
+        ('兩', "liǎng"),
+        ('三', "sān"),
+        ('四', "sì"),
+        ('五', "wǔ"),
+        ('六', "liù"),
+        ('七', "qī"),
+        ('八', "bā"),
+        ('九', "jiǔ"),
+        //Financial digits
+        ('壹', "yī"),
+        ('贰', "èr"),
+        ('貳', "èr"),
+        ('叁', "sān"),
+        ('參', "sān"),
+        ('肆', "sì"),
+        ('伍', "wǔ"),
+        ('陆', "liù"),
+        ('陸', "liù"),
+        ('柒', "qī"),
+        ('捌', "bā"),
+        ('玖', "jiǔ"),
+        //Units
+        ('十', "shí"),
+        ('拾', "shí"),
+        ('百', "bǎi"),
+        ('佰', "bǎi"),
+        ('千', "qiān"),
+        ('仟', "qiān"),
+        ('万', "wàn"),
+        ('萬', "wàn"),
+        ('亿', "yì"),
+        ('億', "yì"),
+        ('兆', "zhào"),
+        ('京', "jīng"),
+        ('垓', "gāi"),
+        ('秭', "zǐ"),
+        ('穰', "ráng"),
+        ('沟', "gōu"),
+        ('溝', "gōu"),
+        ('涧', "jiàn"),
+        ('澗', "jiàn"),
+        ('正', "zhèng"),
+        ('载', "zài"),
+        ('載', "zài"),
+        ('极', "jí"),
+        ('極', "jí"),
+        //Numeric connectors
+        ('负', "fù"),
+        ('負', "fù"),
+        ('点', "diǎn"),
+        ('點', "diǎn"),
+        ('分', "fēn"),
+        ('之', "zhī"),
+        ('第', "dì"),
+        //Measures
+        ('公', "gōng"),
+        ('里', "lǐ"),
+        ('米', "mǐ"),
+        ('厘', "lí"),
+        ('釐', "lí"),
+        ('毫', "háo"),
+        ('斤', "jīn"),
+        //Currency
+        ('人', "rén"),
+        ('民', "mín"),
+        ('币', "bì"),
+        ('幣', "bì"),
+        ('元', "yuán"),
+        ('圆', "yuán"),
+        ('圓', "yuán"),
+        ('块', "kuài"),
+        ('塊', "kuài"),
+        ('角', "jiǎo"),
+        ('毛', "máo"),
+        ('整', "zhěng"),
+        //Date
+        ('年', "nián"),
+        ('月', "yuè"),
+        ('日', "rì"),
+        ('号', "hào"),
+        ('號', "hào"),
+        ('星', "xīng"),
+        ('期', "qī"),
+        ('礼', "lǐ"),
+        ('禮', "lǐ"),
+        ('拜', "bài"),
+        ('周', "zhōu"),
+        ('天', "tiān"),
+        //Time
+        ('早', "zǎo"),
+        ('上', "shàng"),
+        ('午', "wǔ"),
+        ('中', "zhōng"),
+        ('下', "xià"),
+        ('傍', "bàng"),
+        ('晚', "wǎn"),
+        ('夜', "yè"),
+        ('深', "shēn"),
+        ('钟', "zhōng"),
+        ('鐘', "zhōng"),
+        ('过', "guò"),
+        ('過', "guò"),
+        ('刻', "kè"),
+        ('半', "bàn"),
+        ('差', "chà"),
+        ('秒', "miǎo"),
+        //Letter cases
+        ('大', "dà"),
+        ('小', "xiǎo"),
+        ('写', "xiě"),
+        ('寫', "xiě"),
+        //Colloquial numerals
+        ('仨', "sā"),
+        ('俩', "liǎ"),
+        ('倆', "liǎ"),
+        ('廿', "niàn"),
+        ('卅', "sà"),
+        ('几', "jǐ"),
+        ('幾', "jǐ"),
+        ('勾', "gōu"),
+        ('洞', "dòng"),
+        ('拐', "guǎi"),
+        //More measures
+        ('个', "gè"),
+        ('個', "gè"),
+        ('克', "kè"),
+        ('吨', "dūn"),
+        ('噸', "dūn"),
+        ('光', "guāng"),
+        ('微', "wēi"),
+        ('纳', "nà"),
+        ('納', "nà"),
+        ('升', "shēng"),
+        ('勺', "sháo"),
+        ('杯', "bēi"),
+        ('碗', "wǎn"),
+        ('片', "piàn"),
+        ('瓣', "bàn"),
+        ('瓦', "wǎ"),
+        ('赫', "hè"),
+        ('兹', "zī"),
+        ('茲', "zī"),
+        ('吉', "jí"),
+        ('太', "tài"),
+        ('字', "zì"),
+        ('节', "jié"),
+        ('節', "jié"),
+        ('度', "dù"),
+        ('电', "diàn"),
+        ('電', "diàn"),
+        ('些', "xiē"),
+        ('许', "xǔ"),
+        ('許', "xǔ"),
+        ('适', "shì"),
+        ('適', "shì"),
+        ('量', "liàng"),
+        ('回', "huí"),
+        ('遍', "biàn"),
+        ('趟', "tàng"),
+        ('顿', "dùn"),
+        ('頓', "dùn"),
+        ('每', "měi"),
+        ('海', "hǎi"),
+        ('面', "miàn"),
+        ('以', "yǐ"),
+        //Finance
+        ('涨', "zhǎng"),
+        ('漲', "zhǎng"),
+        ('跌', "diē"),
+        ('停', "tíng"),
+        ('增', "zēng"),
+        ('减', "jiǎn"),
+        ('減', "jiǎn"),
+        ('降', "jiàng"),
+        ('基', "jī"),
+        ('本', "běn"),
+        ('利', "lì"),
+        ('率', "lǜ"),
+        ('息', "xī"),
+        ('赔', "péi"),
+        ('賠', "péi"),
+        //Dates and durations
+        ('季', "jì"),
+        ('岁', "suì"),
+        ('歲', "suì"),
+        ('週', "zhōu"),
+        ('至', "zhì"),
+        ('时', "shí"),
+        ('時', "shí"),
+        ('前', "qián"),
+        ('后', "hòu"),
+        ('後', "hòu"),
+        ('刚', "gāng"),
+        ('剛', "gāng"),
+        ('还', "hái"),
+        ('還', "hái"),
+        ('有', "yǒu"),
+        ('马', "mǎ"),
+        ('馬', "mǎ"),
+        ('版', "bǎn"),
+        //Traditional time
+        ('凌', "líng"),
+        ('晨', "chén"),
+        ('更', "gēng"),
+        ('甲', "jiǎ"),
+        ('乙', "yǐ"),
+        ('丙', "bǐng"),
+        ('丁', "dīng"),
+        ('戊', "wù"),
+        ('己', "jǐ"),
+        ('庚', "gēng"),
+        ('辛', "xīn"),
+        ('壬', "rén"),
+        ('癸', "guǐ"),
+        ('子', "zǐ"),
+        ('丑', "chǒu"),
+        ('寅', "yín"),
+        ('卯', "mǎo"),
+        ('辰', "chén"),
+        ('巳', "sì"),
+        ('未', "wèi"),
+        ('申', "shēn"),
+        ('酉', "yǒu"),
+        ('戌', "xū"),
+        ('亥', "hài"),
+        //Comparisons and math
+        ('与', "yǔ"),
+        ('與', "yǔ"),
+        ('比', "bǐ"),
+        ('多', "duō"),
+        ('少', "shǎo"),
+        ('高', "gāo"),
+        ('低', "dī"),
+        ('长', "cháng"),
+        ('長', "cháng"),
+        ('短', "duǎn"),
+        ('便', "pián"),
+        ('宜', "yí"),
+        ('持', "chí"),
+        ('平', "píng"),
+        ('到', "dào"),
+        ('最', "zuì"),
+        ('等', "děng"),
+        ('成', "chéng"),
+        ('乘', "chéng"),
+        ('的', "de"),
+        ('次', "cì"),
+        ('方', "fāng"),
+        ('非', "fēi"),
+        ('数', "shù"),
+        ('數', "shù"),
+        ('无', "wú"),
+        ('無', "wú"),
+        ('穷', "qióng"),
+        ('窮', "qióng"),
+        ('为', "wéi"),
+        ('為', "wéi"),
+        ('概', "gài"),
+        //Places and addresses
+        ('东', "dōng"),
+        ('東', "dōng"),
+        ('西', "xī"),
+        ('南', "nán"),
+        ('北', "běi"),
+        ('偏', "piān"),
+        ('经', "jīng"),
+        ('經', "jīng"),
+        ('纬', "wěi"),
+        ('緯', "wěi"),
+        ('室', "shì"),
+        ('楼', "lóu"),
+        ('樓', "lóu"),
+        ('转', "zhuǎn"),
+        ('轉', "zhuǎn"),
+        ('尾', "wěi"),
+        ('津', "jīn"),
+        ('沪', "hù"),
+        ('滬', "hù"),
+        ('渝', "yú"),
+        ('冀', "jì"),
+        ('豫', "yù"),
+        ('云', "yún"),
+        ('雲', "yún"),
+        ('辽', "liáo"),
+        ('遼', "liáo"),
+        ('黑', "hēi"),
+        ('湘', "xiāng"),
+        ('皖', "wǎn"),
+        ('鲁', "lǔ"),
+        ('魯', "lǔ"),
+        ('新', "xīn"),
+        ('苏', "sū"),
+        ('蘇', "sū"),
+        ('浙', "zhè"),
+        ('赣', "gàn"),
+        ('贛', "gàn"),
+        ('鄂', "è"),
+        ('桂', "guì"),
+        ('甘', "gān"),
+        ('晋', "jìn"),
+        ('晉', "jìn"),
+        ('蒙', "méng"),
+        ('陕', "shǎn"),
+        ('陝', "shǎn"),
+        ('闽', "mǐn"),
+        ('閩', "mǐn"),
+        ('贵', "guì"),
+        ('貴', "guì"),
+        ('粤', "yuè"),
+        ('粵', "yuè"),
+        ('青', "qīng"),
+        ('藏', "zàng"),
+        ('川', "chuān"),
+        ('宁', "níng"),
+        ('寧', "níng"),
+        ('琼', "qióng"),
+        ('瓊', "qióng"),
+        //Titles, grades and courtesy
+        ('先', "xiān"),
+        ('生', "shēng"),
+        ('女', "nǚ"),
+        ('士', "shì"),
+        ('姐', "jiě"),
+        ('老', "lǎo"),
+        ('师', "shī"),
+        ('師', "shī"),
+        ('同', "tóng"),
+        ('学', "xué"),
+        ('學', "xué"),
+        ('主', "zhǔ"),
+        ('任', "rèn"),
+        ('医', "yī"),
+        ('醫', "yī"),
+        ('律', "lǜ"),
+        ('傅', "fù"),
+        ('教', "jiào"),
+        ('授', "shòu"),
+        ('理', "lǐ"),
+        ('总', "zǒng"),
+        ('總', "zǒng"),
+        ('不', "bù"),
+        ('及', "jí"),
+        ('格', "gé"),
+        ('线', "xiàn"),
+        ('線', "xiàn"),
+        ('优', "yōu"),
+        ('優', "yōu"),
+        ('秀', "xiù"),
+        ('良', "liáng"),
+        ('好', "hǎo"),
+        ('满', "mǎn"),
+        ('滿', "mǎn"),
+        ('你', "nǐ"),
+        ('您', "nín"),
+        ('请', "qǐng"),
+        ('請', "qǐng"),
+        ('吧', "ba"),
+        //Travel, sports and options
+        ('航', "háng"),
+        ('班', "bān"),
+        ('列', "liè"),
+        ('车', "chē"),
+        ('車', "chē"),
+        ('加', "jiā"),
+        ('赛', "sài"),
+        ('賽', "sài"),
+        ('场', "chǎng"),
+        ('場', "chǎng"),
+        ('选', "xuǎn"),
+        ('選', "xuǎn"),
+        ('项', "xiàng"),
+        ('項', "xiàng"),
+    ]);
+}
+
+/// Returns the pinyin - with tone marks - of the given logogram,
+/// if it belongs to the vocabulary of the crate.
+///
+/// Tone sandhi is **not** applied: each logogram has its citation tone.
+pub fn pinyin(logogram: char) -> Option<&'static str> {
+    PINYIN_BY_LOGOGRAM.get(&logogram).copied()
+}
+
+pub(crate) fn escape_html(source: char) -> String {
+    match source {
+        '&' => "&amp;".to_string(),
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '"' => "&quot;".to_string(),
+        '\'' => "&#39;".to_string(),
+        _ => source.to_string(),
+    }
+}
+
+/// Conversion to HTML `<ruby>` markup, pairing each logogram with its [pinyin].
+///
+/// It is automatically implemented by every [ChineseFormat];
+/// logograms without pinyin are emitted as plain - escaped - text.
+///
+/// ```
+/// use chinese_format::{*, pinyin::*};
+///
+/// assert_eq!(
+///     Count(2).to_ruby_html(Variant::Simplified),
+///     "<ruby>两<rt>liǎng</rt></ruby>"
+/// );
+///
+/// assert_eq!(
+///     ("三点", "三點").to_ruby_html(Variant::Traditional),
+///     "<ruby>三<rt>sān</rt></ruby><ruby>點<rt>diǎn</rt></ruby>"
+/// );
+///
+/// assert_eq!(
+///     "<G一>".to_ruby_html(Variant::Simplified),
+///     "&lt;G<ruby>一<rt>yī</rt></ruby>&gt;"
+/// );
+/// ```
+pub trait RubyHtml {
+    fn to_ruby_html(&self, variant: Variant) -> String;
+}
+
+impl<T: ChineseFormat + ?Sized> RubyHtml for T {
+    fn to_ruby_html(&self, variant: Variant) -> String {
+        self.to_chinese(variant)
+            .logograms
+            .chars()
+            .map(|logogram| match pinyin(logogram) {
+                Some(pinyin) => format!("<ruby>{}<rt>{}</rt></ruby>", logogram, pinyin),
+                None => escape_html(logogram),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+    use std::{fs, path::Path};
+
+    fn is_logogram(source: char) -> bool {
+        ('\u{4E00}'..='\u{9FFF}').contains(&source) || source == '〇'
+    }
+
+    fn collect_missing_pinyin(path: &Path, missing: &mut Vec<(char, String)>) {
+        if path.is_dir() {
+            for entry in fs::read_dir(path).unwrap() {
+                collect_missing_pinyin(&entry.unwrap().path(), missing);
+            }
+            return;
+        }
+
+        let source = fs::read_to_string(path).unwrap();
+
+        //Only the code that can emit logograms - no docs, comments or tests
+        let code = source.split("#[cfg(test)]").next().unwrap();
+
+        code.lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .flat_map(|line| line.split("//").next().unwrap().chars())
+            .filter(|logogram| is_logogram(*logogram) && pinyin(*logogram).is_none())
+            .for_each(|logogram| missing.push((logogram, path.display().to_string())));
+    }
+
+    speculate! {
+        it "should have pinyin for every logogram the crate can emit" {
+            let mut missing = vec![];
+
+            collect_missing_pinyin(
+                &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+                &mut missing
+            );
+
+            eq!(missing, vec![]);
+        }
+    }
+}
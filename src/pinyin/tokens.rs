@@ -0,0 +1,197 @@
+use super::pinyin;
+use crate::{ChineseFormat, DigitGlyphSet, Variant};
+
+/// The semantic role of a [Token].
+///
+/// **REQUIRED FEATURE**: `pinyin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenRole {
+    /// A numeral - such as `三`, `两` or the financial `叁`.
+    Digit,
+
+    /// A power of ten - such as `十`, `百` or `万`.
+    Magnitude,
+
+    /// The negative sign - `负`/`負`.
+    Sign,
+
+    /// A linking logogram between numbers - such as `点` or `分之`.
+    Connector,
+
+    /// A unit of measurement, date or currency - such as `米`, `月` or `元`.
+    Unit,
+
+    /// Any other logogram.
+    Other,
+}
+
+/// A single logogram of a [Chinese](crate::Chinese) expression,
+/// enriched with metadata - to color-code or animate each part.
+///
+/// **REQUIRED FEATURE**: `pinyin`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Token {
+    /// The logogram, as a string.
+    pub logograms: String,
+
+    /// The [pinyin](super::pinyin) of the logogram, if available.
+    pub pinyin: Option<&'static str>,
+
+    /// The semantic role of the logogram.
+    pub role: TokenRole,
+}
+
+impl Token {
+    fn new(logogram: char, role: TokenRole) -> Self {
+        Self {
+            logograms: logogram.to_string(),
+            pinyin: pinyin(logogram),
+            role,
+        }
+    }
+}
+
+/// Tokens for logograms sharing the same role - such as the unit of a measure.
+pub(crate) fn tokens_with_role(logograms: &str, role: TokenRole) -> Vec<Token> {
+    logograms
+        .chars()
+        .map(|logogram| Token::new(logogram, role))
+        .collect()
+}
+
+/// Tokens for the logograms of a number written via the given [DigitGlyphSet]:
+/// apart from the sign and the decimal point, every logogram
+/// is either a digit or a power of ten.
+pub(crate) fn number_tokens(
+    logograms: &str,
+    variant: Variant,
+    glyphs: DigitGlyphSet,
+) -> Vec<Token> {
+    logograms
+        .chars()
+        .map(|logogram| {
+            let role = match logogram {
+                '负' | '負' => TokenRole::Sign,
+
+                '点' | '點' => TokenRole::Connector,
+
+                _ if logogram == glyphs.counted_two(variant)
+                    || (0..10).any(|digit| glyphs.digit(digit, variant) == logogram) =>
+                {
+                    TokenRole::Digit
+                }
+
+                _ => TokenRole::Magnitude,
+            };
+
+            Token::new(logogram, role)
+        })
+        .collect()
+}
+
+fn role_of(logogram: char, next_logogram: Option<char>) -> TokenRole {
+    match logogram {
+        '零' | '〇' | '一' | '幺' | '二' | '两' | '兩' | '三' | '四' | '五' | '六' | '七'
+        | '八' | '九' | '壹' | '贰' | '貳' | '叁' | '參' | '肆' | '伍' | '陆' | '陸' | '柒'
+        | '捌' | '玖' => TokenRole::Digit,
+
+        '十' | '拾' | '百' | '佰' | '千' | '仟' | '万' | '萬' | '亿' | '億' | '兆' | '京'
+        | '垓' | '秭' | '穰' | '沟' | '溝' | '涧' | '澗' | '正' | '载' | '載' | '极' | '極' => {
+            TokenRole::Magnitude
+        }
+
+        '负' | '負' => TokenRole::Sign,
+
+        '分' if next_logogram == Some('之') => TokenRole::Connector,
+
+        '点' | '點' | '之' | '第' | '过' | '過' | '差' => TokenRole::Connector,
+
+        '公' | '里' | '米' | '厘' | '釐' | '毫' | '斤' | '分' | '元' | '圆' | '圓' | '块'
+        | '塊' | '角' | '毛' | '年' | '月' | '日' | '号' | '號' | '钟' | '鐘' | '刻' | '秒' => {
+            TokenRole::Unit
+        }
+
+        _ => TokenRole::Other,
+    }
+}
+
+/// Tokens whose roles are inferred from the logograms alone - for text
+/// whose producer does not assign roles, such as plain strings.
+pub(crate) fn inferred_tokens(logograms: &str) -> Vec<Token> {
+    let logograms: Vec<char> = logograms.chars().collect();
+
+    logograms
+        .iter()
+        .enumerate()
+        .map(|(index, logogram)| {
+            Token::new(
+                *logogram,
+                role_of(*logogram, logograms.get(index + 1).copied()),
+            )
+        })
+        .collect()
+}
+
+/// Conversion to a sequence of [Token] - one for each logogram.
+///
+/// It is automatically implemented by every [ChineseFormat], via
+/// [to_chinese_tokens](ChineseFormat::to_chinese_tokens): the roles are
+/// assigned by the types producing the logograms - numbers, measures,
+/// fractions and decimals - so that, for example, the weight unit `两`
+/// is a [TokenRole::Unit] while the count `两` is a [TokenRole::Digit].
+///
+/// The roles of logograms produced by any other type - such as plain strings -
+/// are inferred from the logograms themselves, by their most common usage in the crate.
+///
+/// ```
+/// use chinese_format::{*, pinyin::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let fraction = Fraction::try_new(3, -20)?;
+///
+/// assert_eq!(
+///     fraction.to_tokens(Variant::Simplified),
+///     vec![
+///         Token { logograms: "负".into(), pinyin: Some("fù"), role: TokenRole::Sign },
+///         Token { logograms: "三".into(), pinyin: Some("sān"), role: TokenRole::Digit },
+///         Token { logograms: "分".into(), pinyin: Some("fēn"), role: TokenRole::Connector },
+///         Token { logograms: "之".into(), pinyin: Some("zhī"), role: TokenRole::Connector },
+///         Token { logograms: "二".into(), pinyin: Some("èr"), role: TokenRole::Digit },
+///         Token { logograms: "十".into(), pinyin: Some("shí"), role: TokenRole::Magnitude },
+///     ]
+/// );
+///
+/// let roles: Vec<TokenRole> = length::Kilometer::new(2)
+///     .to_tokens(Variant::Traditional)
+///     .into_iter()
+///     .map(|token| token.role)
+///     .collect();
+/// assert_eq!(roles, vec![TokenRole::Digit, TokenRole::Unit, TokenRole::Unit]);
+///
+/// assert_eq!(
+///     weight::Liang::new(2).to_tokens(Variant::Simplified),
+///     vec![
+///         Token { logograms: "二".into(), pinyin: Some("èr"), role: TokenRole::Digit },
+///         Token { logograms: "两".into(), pinyin: Some("liǎng"), role: TokenRole::Unit },
+///     ]
+/// );
+///
+/// assert_eq!(
+///     "Z".to_tokens(Variant::Simplified),
+///     vec![Token { logograms: "Z".into(), pinyin: None, role: TokenRole::Other }]
+/// );
+///
+/// assert_eq!("".to_tokens(Variant::Simplified), vec![]);
+///
+/// # Ok(())
+/// # }
+/// ```
+pub trait Tokenize {
+    fn to_tokens(&self, variant: Variant) -> Vec<Token>;
+}
+
+impl<T: ChineseFormat + ?Sized> Tokenize for T {
+    fn to_tokens(&self, variant: Variant) -> Vec<Token> {
+        self.to_chinese_tokens(variant)
+    }
+}
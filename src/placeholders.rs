@@ -1,5 +1,8 @@
 /// Defines a new **placeholder** type.
 ///
+/// **DEPRECATED**: the generated types can only _borrow_ the wrapped value -
+/// which makes storing them awkward; please, use the generic [Placeholder] instead.
+///
 /// Every *placeholder* wraps a [ChineseFormat](crate::ChineseFormat) instance and implements [ChineseFormat](crate::ChineseFormat) as follows:
 ///
 ///   * first, convert the wrapped [ChineseFormat](crate::ChineseFormat) instance to [Chinese](crate::Chinese)
@@ -7,7 +10,7 @@
 ///   * if the result is not [omissible](crate::Chinese::omissible), return it unchanged
 ///
 ///     * otherwise, return a [Chinese](crate::Chinese) that is still [omissible](crate::Chinese::omissible),
-///       but whose [logograms](crate::Chinese::logograms) are the obtained by calling `$replacement_logograms.to_string()`.
+///       but whose [logograms](crate::Chinese::logograms) are the obtained by calling `$replacement_logograms.into()`.
 ///
 /// Anyway, the `omissible` property of any placeholder always reflects the [Chinese](crate::Chinese) produced by the wrapped [ChineseFormat](crate::ChineseFormat) instance.
 ///
@@ -25,7 +28,7 @@
 /// let placeholder_with_non_omissible = LingPlaceholder::new(&"二九零四");
 ///
 /// assert_eq!(placeholder_with_non_omissible.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "二九零四".to_string(),
+///     logograms: "二九零四".into(),
 ///     omissible: false
 /// });
 ///
@@ -35,7 +38,7 @@
 /// let placeholder_with_omissible = LingPlaceholder::new(&"");
 ///
 /// assert_eq!(placeholder_with_omissible.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "零".to_string(),
+///     logograms: "零".into(),
 ///     omissible: true
 /// });
 ///
@@ -52,7 +55,7 @@
 /// let placeholder_with_non_omissible = EmptyPlaceholder::new(&"二九零四");
 ///
 /// assert_eq!(placeholder_with_non_omissible.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "二九零四".to_string(),
+///     logograms: "二九零四".into(),
 ///     omissible: false
 /// });
 ///
@@ -62,12 +65,13 @@
 /// let placeholder_with_omissible = EmptyPlaceholder::new(&"");
 ///
 /// assert_eq!(placeholder_with_omissible.to_chinese(Variant::Simplified), Chinese {
-///     logograms: "".to_string(),
+///     logograms: "".into(),
 ///     omissible: true
 /// });
 ///
 /// assert_eq!(placeholder_with_omissible.to_chinese(Variant::Traditional), "");
 /// ```
+#[deprecated(note = "please, use Placeholder instead")]
 #[macro_export]
 macro_rules! define_string_placeholder {
     (
@@ -97,7 +101,7 @@ macro_rules! define_string_placeholder {
                 let wrapped_chinese = self.0.to_chinese(variant);
 
                 let result_logograms = if wrapped_chinese.omissible {
-                    $replacement_logograms.to_string()
+                    $replacement_logograms.into()
                 } else {
                     wrapped_chinese.logograms
                 };
@@ -111,16 +115,117 @@ macro_rules! define_string_placeholder {
     };
 }
 
-define_string_placeholder!(
-    pub,
-    LingPlaceholder,
-    "零",
-    "[Placeholder](crate::define_string_placeholder) replacing an *omissible* value with `零`."
-);
-
-define_string_placeholder!(
-    pub,
-    EmptyPlaceholder,
-    "",
-    "[Placeholder](crate::define_string_placeholder) replacing an *omissible* value with an empty string."
-);
+#[allow(deprecated)]
+mod string_placeholders {
+    define_string_placeholder!(
+        pub,
+        LingPlaceholder,
+        "零",
+        "[Placeholder](crate::define_string_placeholder) replacing an *omissible* value with `零`."
+    );
+
+    define_string_placeholder!(
+        pub,
+        EmptyPlaceholder,
+        "",
+        "[Placeholder](crate::define_string_placeholder) replacing an *omissible* value with an empty string."
+    );
+}
+
+pub use string_placeholders::*;
+
+use crate::{Chinese, ChineseFormat, CompositionStyle, Variant};
+
+const LING: &str = "零";
+
+/// Owned **placeholder**, wrapping a [ChineseFormat] value - by value, or even as a `Box<dyn ChineseFormat>` -
+/// and replacing it with the logograms of its `replacement` when the value is [omissible](Chinese::omissible).
+///
+/// The `omissible` property of the result always reflects the wrapped value.
+///
+/// Unlike the borrowing placeholders, it is [Send] and [Sync] whenever its components are:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// fn assert_thread_safe<T: Send + Sync + 'static>(_value: T) {}
+///
+/// assert_thread_safe(Placeholder::ling(Count(0)));
+/// assert_thread_safe(Placeholder::<DynChinese, DynChinese>::new(Box::new(7), "无".into()));
+/// ```
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let placeholder = Placeholder::new(Count(0), "无");
+/// assert_eq!(placeholder.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "无".into(),
+///     omissible: true
+/// });
+///
+/// let placeholder = Placeholder::new(Count(2), "无");
+/// assert_eq!(placeholder.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "两".into(),
+///     omissible: false
+/// });
+///
+/// //The replacement can be chosen at runtime - and can depend on the variant
+/// let verbose = true;
+/// let replacement = if verbose { ("没有", "沒有") } else { ("无", "無") };
+/// let placeholder = Placeholder::new(Count(0), replacement);
+/// assert_eq!(placeholder.to_chinese(Variant::Traditional), "沒有");
+///
+/// //Trait objects can be stored, too
+/// let values: Vec<Placeholder<Box<dyn ChineseFormat>>> = vec![
+///     Placeholder::ling(Box::new("")),
+///     Placeholder::empty(Box::new(Count(0))),
+///     Placeholder::empty(Box::new(7)),
+/// ];
+/// let logograms: Vec<String> = values
+///     .iter()
+///     .map(|placeholder| placeholder.to_chinese(Variant::Simplified).into())
+///     .collect();
+/// assert_eq!(logograms, vec!["零", "", "七"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Placeholder<T: ChineseFormat, R: ChineseFormat = &'static str> {
+    pub value: T,
+    pub replacement: R,
+}
+
+impl<T: ChineseFormat, R: ChineseFormat> Placeholder<T, R> {
+    pub fn new(value: T, replacement: R) -> Self {
+        Self { value, replacement }
+    }
+}
+
+impl<T: ChineseFormat> Placeholder<T> {
+    /// Placeholder replacing an omissible value with `零` - like [LingPlaceholder].
+    pub fn ling(value: T) -> Self {
+        Self::new(value, LING)
+    }
+
+    /// Placeholder replacing an omissible value with an empty string - like [EmptyPlaceholder].
+    pub fn empty(value: T) -> Self {
+        Self::new(value, "")
+    }
+}
+
+impl<T: ChineseFormat, R: ChineseFormat> ChineseFormat for Placeholder<T, R> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let wrapped_chinese = self.value.to_chinese_composed(variant, style);
+
+        if !wrapped_chinese.omissible {
+            return wrapped_chinese;
+        }
+
+        Chinese {
+            logograms: self.replacement.to_chinese(variant).logograms,
+            omissible: true,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{Chinese, ChineseFormat, CompositionStyle, Glyphs, Variant};
+
+/// How a [Polite] phrase is framed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Politeness {
+    /// `请`/`請` before the phrase - as in `请稍等`.
+    #[default]
+    Request,
+
+    /// `请您`/`請您` before the phrase - as in `请您稍等`.
+    RespectfulRequest,
+
+    /// `你` replaced by the respectful `您` - as in `您的订单已发货`.
+    Respectful,
+
+    /// The sentence-final `吧` - as in `我们走吧`.
+    Suggestion,
+}
+
+impl Politeness {
+    const QING: Glyphs = Glyphs::new("请", "請");
+
+    const QING_NIN: Glyphs = Glyphs::new("请您", "請您");
+
+    const NI: &'static str = "你";
+
+    const NIN: &'static str = "您";
+
+    const BA: &'static str = "吧";
+
+    fn frame(&self, phrase: &str, variant: Variant) -> String {
+        match self {
+            Self::Request => format!("{}{}", Self::QING.pick(variant), phrase),
+            Self::RespectfulRequest => format!("{}{}", Self::QING_NIN.pick(variant), phrase),
+            Self::Respectful => phrase.replace(Self::NI, Self::NIN),
+            Self::Suggestion => format!("{}{}", phrase, Self::BA),
+        }
+    }
+}
+
+/// Frames a phrase according to a [Politeness] style - as expected
+/// by voice assistants and customer-facing messages.
+///
+/// An [omissible](Chinese::omissible) phrase is returned unchanged.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let arrival = Surround("在", Count(3), ("点前到达", "點前到達"));
+///
+/// let request = Polite(arrival, Politeness::Request);
+/// assert_eq!(request.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "请在三点前到达".into(),
+///     omissible: false
+/// });
+/// assert_eq!(request.to_chinese(Variant::Traditional), "請在三點前到達");
+///
+/// let wait = Polite("稍等", Politeness::RespectfulRequest);
+/// assert_eq!(wait.to_chinese(Variant::Simplified), "请您稍等");
+/// assert_eq!(wait.to_chinese(Variant::Traditional), "請您稍等");
+///
+/// let order = Polite(("你的订单已发货", "你的訂單已發貨"), Politeness::Respectful);
+/// assert_eq!(order.to_chinese(Variant::Simplified), "您的订单已发货");
+/// assert_eq!(order.to_chinese(Variant::Traditional), "您的訂單已發貨");
+///
+/// let suggestion = Polite(("我们走", "我們走"), Politeness::Suggestion);
+/// assert_eq!(suggestion.to_chinese(Variant::Simplified), "我们走吧");
+///
+/// assert_eq!(Polite("", Politeness::Request).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Polite<T: ChineseFormat>(pub T, pub Politeness);
+
+impl<T: ChineseFormat> ChineseFormat for Polite<T> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let phrase = self.0.to_chinese_composed(variant, style);
+
+        if phrase.omissible {
+            return phrase;
+        }
+
+        Chinese {
+            logograms: self.1.frame(&phrase.logograms, variant).into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Phrase whose [Glyphs] contain `{}` slots - filled, in order, by the given values.
+///
+/// Slots without a value are rendered as empty, while the exceeding values are ignored.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let count = Count(2);
+/// let hour = Count(3);
+///
+/// let reminder = PhraseTemplate::new(Glyphs::new(
+///     "您有{}条新消息，请在{}点前回复",
+///     "您有{}條新消息，請在{}點前回覆"
+/// ))
+/// .with_value(&count)
+/// .with_value(&hour);
+///
+/// assert_eq!(reminder.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "您有两条新消息，请在三点前回复".into(),
+///     omissible: false
+/// });
+/// assert_eq!(reminder.to_chinese(Variant::Traditional), "您有兩條新消息，請在三點前回覆");
+///
+/// let arrival = PhraseTemplate::new(Glyphs::new("在{}点前到达", "在{}點前到達")).with_value(&hour);
+/// assert_eq!(
+///     Polite(arrival, Politeness::Request).to_chinese(Variant::Simplified),
+///     "请在三点前到达"
+/// );
+///
+/// let unfilled = PhraseTemplate::new(Glyphs::same("第{}名"));
+/// assert_eq!(unfilled.to_chinese(Variant::Simplified), "第名");
+/// ```
+#[derive(Clone)]
+pub struct PhraseTemplate<'a> {
+    pattern: Glyphs,
+    values: Vec<&'a dyn ChineseFormat>,
+}
+
+impl<'a> PhraseTemplate<'a> {
+    /// The placeholder marking each slot in the pattern.
+    pub const SLOT: &'static str = "{}";
+
+    /// Creates a template with no values.
+    pub fn new(pattern: Glyphs) -> Self {
+        Self {
+            pattern,
+            values: vec![],
+        }
+    }
+
+    /// Fills the next slot.
+    pub fn with_value(mut self, value: &'a dyn ChineseFormat) -> Self {
+        self.values.push(value);
+        self
+    }
+}
+
+impl<'a> ChineseFormat for PhraseTemplate<'a> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let mut values = self.values.iter();
+
+        let logograms = self
+            .pattern
+            .pick(variant)
+            .split(Self::SLOT)
+            .enumerate()
+            .map(|(index, fragment)| {
+                if index == 0 {
+                    return fragment.to_string();
+                }
+
+                let value = values
+                    .next()
+                    .map(|value| value.to_chinese(variant).logograms.into_owned())
+                    .unwrap_or_default();
+
+                format!("{}{}", value, fragment)
+            })
+            .collect::<String>();
+
+        Chinese {
+            omissible: logograms.is_empty(),
+            logograms: logograms.into(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Power measures.
+//!
+//! ```
+//! use chinese_format::{*, power::*};
+//!
+//! let two_kw = Kilowatt::new(2);
+//!
+//! assert_eq!(
+//!     two_kw.to_chinese(Variant::Simplified),
+//!     Chinese {
+//!         logograms: "两千瓦".into(),
+//!         omissible: false
+//!     }
+//! );
+//!
+//! assert_eq!(Watt::new(60).to_chinese(Variant::Traditional), "六十瓦");
+//!
+//! assert_eq!(Watt::new(1500).humanize().to_chinese(Variant::Simplified), "一点五千瓦");
+//! assert_eq!(Megawatt::new(3).convert::<Kilowatt>(), Some(Kilowatt::new(3000)));
+//!
+//! assert_eq!(
+//!     Watt::new(0).to_chinese(Variant::Simplified),
+//!     Chinese {
+//!         logograms: "零瓦".into(),
+//!         omissible: true
+//!     }
+//! );
+//! ```
+use crate::{define_count_measure, impl_scaled_measure, CountBase, Dimension};
+
+/// The [Dimension] of the power measures, whose base unit is [Watt].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Power;
+
+impl Dimension for Power {
+    const HUMANIZED_UNITS: &'static [(CountBase, (&'static str, &'static str))] = &[
+        (1_000_000, ("兆瓦", "兆瓦")),
+        (1_000, ("千瓦", "千瓦")),
+        (1, ("瓦", "瓦")),
+    ];
+}
+
+define_count_measure!(pub, Megawatt, "兆瓦");
+
+define_count_measure!(pub, Kilowatt, "千瓦");
+
+define_count_measure!(pub, Watt, "瓦");
+
+impl_scaled_measure!(Power, Megawatt, 1_000_000);
+
+impl_scaled_measure!(Power, Kilowatt, 1_000);
+
+impl_scaled_measure!(Power, Watt, 1);
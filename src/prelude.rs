@@ -0,0 +1,20 @@
+//! The most common items, importable at once.
+//!
+//! ```
+//! use chinese_format::prelude::*;
+//!
+//! let values: Vec<DynChinese> = vec![Count(2).into(), "个".into(), "人".into()];
+//!
+//! let sentence = ChineseVec::from(
+//!     Variant::Simplified,
+//!     values.iter().map(|value| value as &dyn ChineseFormat).collect(),
+//! )
+//! .collect();
+//!
+//! assert_eq!(sentence, "两个人");
+//! ```
+pub use crate::{
+    chinese_vec, Chinese, ChineseFormat, ChineseVec, ChineseVecBuilder, CompositionStyle, Count,
+    CountBase, DynChinese, GenericResult, MapLogograms, Measure, OrElse, Placeholder, Surround,
+    Variant,
+};
@@ -0,0 +1,78 @@
+//! Recipe quantities - such as `两勺糖`, `三百克面粉` or `适量盐`.
+//!
+//! ```
+//! use chinese_format::{*, recipe::*, weight::Gram};
+//!
+//! let ingredients = [
+//!     Ingredient::new(Spoon::new(2), ("糖", "糖")).to_chinese(Variant::Simplified),
+//!     Ingredient::new(Gram::new(300), ("面粉", "麵粉")).to_chinese(Variant::Simplified),
+//!     Ingredient::new(IndeterminateQuantity::ToTaste, ("盐", "鹽")).to_chinese(Variant::Simplified),
+//! ];
+//!
+//! assert_eq!(ingredients, ["两勺糖", "三百克面粉", "适量盐"]);
+//! ```
+use crate::{define_count_measure, Chinese, ChineseFormat, Variant};
+
+define_count_measure!(pub, Spoon, "勺");
+
+define_count_measure!(pub, Teaspoon, "小勺");
+
+define_count_measure!(pub, Cup, "杯");
+
+define_count_measure!(pub, Bowl, "碗");
+
+define_count_measure!(pub, Milliliter, "毫升");
+
+define_count_measure!(pub, Slice, "片");
+
+define_count_measure!(pub, Clove, "瓣");
+
+/// Quantity followed by the name of an ingredient.
+///
+/// The quantity can be any [ChineseFormat] - especially a [Measure](crate::Measure)
+/// or an [IndeterminateQuantity](crate::IndeterminateQuantity).
+///
+/// ```
+/// use chinese_format::{*, recipe::*};
+///
+/// let garlic = Ingredient::new(Clove::new(3), ("蒜", "蒜"));
+/// assert_eq!(garlic.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三瓣蒜".into(),
+///     omissible: false
+/// });
+///
+/// let soy_sauce = Ingredient::new(Milliliter::new(15), ("生抽", "生抽"));
+/// assert_eq!(soy_sauce.to_chinese(Variant::Traditional), "十五毫升生抽");
+///
+/// let pepper = Ingredient::new(IndeterminateQuantity::Pinch, ("胡椒粉", "胡椒粉"));
+/// assert_eq!(pepper.to_chinese(Variant::Traditional), "少許胡椒粉");
+///
+/// //The omissibility depends on the quantity
+/// let nothing = Ingredient::new(Spoon::new(0), "糖");
+/// assert_eq!(nothing.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零勺糖".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ingredient<Q: ChineseFormat, N: ChineseFormat> {
+    pub quantity: Q,
+    pub name: N,
+}
+
+impl<Q: ChineseFormat, N: ChineseFormat> Ingredient<Q, N> {
+    pub fn new(quantity: Q, name: N) -> Self {
+        Self { quantity, name }
+    }
+}
+
+impl<Q: ChineseFormat, N: ChineseFormat> ChineseFormat for Ingredient<Q, N> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let quantity = self.quantity.to_chinese(variant);
+
+        Chinese {
+            logograms: format!("{}{}", quantity.logograms, self.name.to_chinese(variant)).into(),
+            omissible: quantity.omissible,
+        }
+    }
+}
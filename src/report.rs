@@ -0,0 +1,169 @@
+//! Plain-text reports - such as CLI or financial printouts - whose cells are
+//! aligned according to their [display width](crate::display_width), so that
+//! logograms and full-width characters count as 2 terminal cells.
+//!
+//! ```
+//! use chinese_format::{*, report::*};
+//!
+//! let table = Table::new()
+//!     .with_header(vec![&"项目", &"数量"])
+//!     .with_row(vec![&"苹果", &Count(12)])
+//!     .with_row(vec![&"西瓜", &Count(2)])
+//!     .with_alignments(vec![Alignment::Left, Alignment::Right]);
+//!
+//! assert_eq!(
+//!     table.to_chinese(Variant::Simplified).logograms,
+//!     "项目  数量\n苹果  十二\n西瓜    两"
+//! );
+//! ```
+use crate::{display_width, pad_to_display_width, Alignment, Chinese, ChineseFormat, Variant};
+
+/// Table of [ChineseFormat] cells - rendered as lines of aligned text.
+///
+/// Each column is as wide as its widest cell; missing cells are rendered as empty,
+/// and the trailing spaces of each line are removed.
+///
+/// ```
+/// use chinese_format::{*, report::*};
+///
+/// let table = Table::new()
+///     .with_header(vec![&("科目", "科目"), &("金额", "金額")])
+///     .with_row(vec![&("差旅费", "差旅費"), &"三百元"])
+///     .with_row(vec![&("餐费", "餐費")])
+///     .with_alignments(vec![Alignment::Left, Alignment::Right])
+///     .with_column_separator(" | ")
+///     .with_rule('-');
+///
+/// assert_eq!(
+///     table.to_chinese(Variant::Traditional).logograms,
+///     [
+///         "科目   |   金額",
+///         "---------------",
+///         "差旅費 | 三百元",
+///         "餐費   |",
+///     ].join("\n")
+/// );
+///
+/// assert_eq!(Table::new().to_chinese(Variant::Simplified), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Clone, Default)]
+pub struct Table<'a> {
+    header: Option<Vec<&'a dyn ChineseFormat>>,
+    rows: Vec<Vec<&'a dyn ChineseFormat>>,
+    alignments: Vec<Alignment>,
+    column_separator: Option<&'static str>,
+    rule: Option<char>,
+}
+
+impl<'a> Table<'a> {
+    /// The default separator between two columns.
+    pub const DEFAULT_COLUMN_SEPARATOR: &'static str = "  ";
+
+    /// Creates an empty table - with left-aligned columns separated by
+    /// [DEFAULT_COLUMN_SEPARATOR](Self::DEFAULT_COLUMN_SEPARATOR).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the first line of the table.
+    pub fn with_header(mut self, cells: Vec<&'a dyn ChineseFormat>) -> Self {
+        self.header = Some(cells);
+        self
+    }
+
+    /// Appends a line to the table.
+    pub fn with_row(mut self, cells: Vec<&'a dyn ChineseFormat>) -> Self {
+        self.rows.push(cells);
+        self
+    }
+
+    /// Sets the [Alignment] of each column - the columns without an alignment being left-aligned.
+    pub fn with_alignments(mut self, alignments: Vec<Alignment>) -> Self {
+        self.alignments = alignments;
+        self
+    }
+
+    /// Sets the text between two columns.
+    pub fn with_column_separator(mut self, column_separator: &'static str) -> Self {
+        self.column_separator = Some(column_separator);
+        self
+    }
+
+    /// Sets the character repeated - across the whole width - to separate the header from the rows.
+    pub fn with_rule(mut self, rule: char) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    fn column_separator(&self) -> &'static str {
+        self.column_separator
+            .unwrap_or(Self::DEFAULT_COLUMN_SEPARATOR)
+    }
+
+    fn render_line(&self, cells: &[String], widths: &[usize]) -> String {
+        widths
+            .iter()
+            .enumerate()
+            .map(|(index, width)| {
+                pad_to_display_width(
+                    cells.get(index).map(String::as_str).unwrap_or(""),
+                    *width,
+                    self.alignments.get(index).copied().unwrap_or_default(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(self.column_separator())
+            .trim_end()
+            .to_string()
+    }
+}
+
+impl<'a> ChineseFormat for Table<'a> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let render_cells = |cells: &Vec<&'a dyn ChineseFormat>| -> Vec<String> {
+            cells
+                .iter()
+                .map(|cell| cell.to_chinese(variant).into())
+                .collect()
+        };
+
+        let header = self.header.as_ref().map(render_cells);
+        let rows: Vec<Vec<String>> = self.rows.iter().map(render_cells).collect();
+
+        let mut widths: Vec<usize> = vec![];
+
+        for cells in header.iter().chain(rows.iter()) {
+            for (index, cell) in cells.iter().enumerate() {
+                let width = display_width(cell);
+
+                match widths.get_mut(index) {
+                    Some(current) => *current = (*current).max(width),
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        let mut lines: Vec<String> = vec![];
+
+        if let Some(header) = &header {
+            lines.push(self.render_line(header, &widths));
+
+            if let Some(rule) = self.rule {
+                let full_width = widths.iter().sum::<usize>()
+                    + display_width(self.column_separator()) * widths.len().saturating_sub(1);
+
+                lines.push(rule.to_string().repeat(full_width));
+            }
+        }
+
+        lines.extend(rows.iter().map(|cells| self.render_line(cells, &widths)));
+
+        Chinese {
+            omissible: lines.is_empty(),
+            logograms: lines.join("\n").into(),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::hash::Hash;
 
-use crate::{Chinese, ChineseFormat, Variant};
+use crate::{Chinese, ChineseFormat, Glyphs, Variant};
 
 /// Sign of a number.
 #[derive(Debug, Clone, Copy)]
@@ -103,21 +103,21 @@ impl Hash for Sign {
 ///
 /// //Positive numbers
 /// assert_eq!(Sign(90).to_chinese(Variant::Simplified), Chinese {
-///     logograms: "".to_string(),
+///     logograms: "".into(),
 ///     omissible: true
 /// });
 /// assert_eq!(Sign(90).to_chinese(Variant::Traditional), "");
 ///
 /// //Zero
 /// assert_eq!(Sign(0).to_chinese(Variant::Simplified), Chinese {
-///     logograms: "".to_string(),
+///     logograms: "".into(),
 ///     omissible: true
 /// });
 /// assert_eq!(Sign(0).to_chinese(Variant::Traditional), "");
 ///
 /// //Negative numbers
 /// assert_eq!(Sign(-7).to_chinese(Variant::Simplified), Chinese {
-///     logograms: "负".to_string(),
+///     logograms: "负".into(),
 ///     omissible: false
 /// });
 /// assert_eq!(Sign(-7).to_chinese(Variant::Traditional), "負");
@@ -127,7 +127,15 @@ impl ChineseFormat for Sign {
         if self.0 >= 0 {
             "".to_chinese(variant)
         } else {
-            ("负", "負").to_chinese(variant)
+            Glyphs::FU.to_chinese(variant)
         }
     }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        crate::pinyin::tokens_with_role(
+            &self.to_chinese(variant).logograms,
+            crate::pinyin::TokenRole::Sign,
+        )
+    }
 }
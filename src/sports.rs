@@ -0,0 +1,111 @@
+//! Match clock expressions - such as `上半场第十五分钟` - for live commentary.
+//!
+//! ```
+//! use chinese_format::{*, sports::*};
+//!
+//! let goal = MatchClock {
+//!     period: Some(MatchPeriod::FirstHalf),
+//!     minute: 15
+//! };
+//!
+//! assert_eq!(goal.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "上半场第十五分钟".into(),
+//!     omissible: false
+//! });
+//! assert_eq!(goal.to_chinese(Variant::Traditional), "上半場第十五分鐘");
+//! ```
+use crate::{chinese_vec, Chinese, ChineseFormat, CompositionStyle, Variant};
+
+/// Period of a match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchPeriod {
+    /// `上半场`
+    FirstHalf,
+
+    /// `下半场`
+    SecondHalf,
+
+    /// `加时赛上半场`
+    ExtraTimeFirstHalf,
+
+    /// `加时赛下半场`
+    ExtraTimeSecondHalf,
+}
+
+const EXTRA_TIME: (&str, &str) = ("加时赛", "加時賽");
+
+const FIRST_HALF: (&str, &str) = ("上半场", "上半場");
+
+const SECOND_HALF: (&str, &str) = ("下半场", "下半場");
+
+/// ```
+/// use chinese_format::{*, sports::*};
+///
+/// assert_eq!(MatchPeriod::SecondHalf.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "下半场".into(),
+///     omissible: false
+/// });
+/// assert_eq!(MatchPeriod::ExtraTimeFirstHalf.to_chinese(Variant::Simplified), "加时赛上半场");
+/// assert_eq!(MatchPeriod::ExtraTimeSecondHalf.to_chinese(Variant::Traditional), "加時賽下半場");
+/// ```
+impl ChineseFormat for MatchPeriod {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Self::FirstHalf => FIRST_HALF.to_chinese(variant),
+            Self::SecondHalf => SECOND_HALF.to_chinese(variant),
+            Self::ExtraTimeFirstHalf => chinese_vec!(variant, [EXTRA_TIME, FIRST_HALF]).collect(),
+            Self::ExtraTimeSecondHalf => chinese_vec!(variant, [EXTRA_TIME, SECOND_HALF]).collect(),
+        }
+    }
+}
+
+const DI: &str = "第";
+
+const MINUTE: (&str, &str) = ("分钟", "分鐘");
+
+/// Minute of a match - read as an ordinal - optionally preceded by its [MatchPeriod].
+///
+/// ```
+/// use chinese_format::{*, sports::*};
+///
+/// let late_goal = MatchClock {
+///     period: None,
+///     minute: 89
+/// };
+/// assert_eq!(late_goal.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "第八十九分钟".into(),
+///     omissible: false
+/// });
+///
+/// let early_goal = MatchClock {
+///     period: Some(MatchPeriod::SecondHalf),
+///     minute: 2
+/// };
+/// assert_eq!(early_goal.to_chinese(Variant::Simplified), "下半场第二分钟");
+///
+/// let extra_time = MatchClock {
+///     period: Some(MatchPeriod::ExtraTimeFirstHalf),
+///     minute: 97
+/// };
+/// assert_eq!(
+///     extra_time.to_chinese_composed(Variant::Simplified, CompositionStyle::Spaced),
+///     "加时赛上半场 第九十七分钟"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MatchClock {
+    pub period: Option<MatchPeriod>,
+    pub minute: u8,
+}
+
+impl ChineseFormat for MatchClock {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.to_chinese_composed(variant, CompositionStyle::default())
+    }
+
+    fn to_chinese_composed(&self, variant: Variant, style: CompositionStyle) -> Chinese {
+        let minute = chinese_vec!(variant, [DI, self.minute, MINUTE]);
+
+        chinese_vec!(variant, [self.period, minute]).collect_composed(style)
+    }
+}
@@ -0,0 +1,210 @@
+//! [SSML](https://www.w3.org/TR/speech-synthesis11/) output - for text-to-speech engines.
+//!
+//! An [Ssml] document is a sequence of Chinese expressions, each optionally
+//! wrapped in a `<say-as>` element, interleaved by pauses; furthermore, each
+//! logogram can be annotated with its [pinyin](crate::pinyin::pinyin) via
+//! a `<phoneme>` element - so that engines never guess the reading of `两` or `幺`.
+//!
+//! ```
+//! use chinese_format::{*, ssml::*};
+//!
+//! let count = Count(2);
+//! let document = Ssml::new()
+//!     .with_text(&"共")
+//!     .with_say_as(&count, InterpretAs::Cardinal)
+//!     .with_text(&("件", "件"))
+//!     .with_pause(300);
+//!
+//! assert_eq!(
+//!     document.to_ssml(Variant::Simplified),
+//!     concat!(
+//!         r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="zh-CN">"#,
+//!         "共",
+//!         r#"<say-as interpret-as="cardinal">两</say-as>"#,
+//!         "件",
+//!         r#"<break time="300ms"/>"#,
+//!         "</speak>"
+//!     )
+//! );
+//!
+//! let hinted = Ssml::new()
+//!     .with_say_as(&count, InterpretAs::Cardinal)
+//!     .with_pinyin(true);
+//!
+//! assert_eq!(
+//!     hinted.to_ssml(Variant::Traditional),
+//!     concat!(
+//!         r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="zh-TW">"#,
+//!         r#"<say-as interpret-as="cardinal">"#,
+//!         r#"<phoneme alphabet="x-pinyin" ph="liǎng">兩</phoneme>"#,
+//!         "</say-as>",
+//!         "</speak>"
+//!     )
+//! );
+//! ```
+//!
+//! **REQUIRED FEATURE**: `ssml`.
+use crate::{
+    pinyin::{escape_html, pinyin},
+    ChineseFormat, Variant,
+};
+
+/// The `interpret-as` attribute of a `<say-as>` element.
+///
+/// **REQUIRED FEATURE**: `ssml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InterpretAs {
+    /// A number - such as `三百二十`.
+    Cardinal,
+
+    /// A position - such as `第三`.
+    Ordinal,
+
+    /// A sequence of characters read one by one - such as verification codes.
+    Characters,
+
+    /// A phone number.
+    Telephone,
+
+    /// A calendar date.
+    Date,
+
+    /// A time of the day.
+    Time,
+
+    /// A monetary amount.
+    Currency,
+}
+
+impl InterpretAs {
+    fn attribute(&self) -> &'static str {
+        match self {
+            Self::Cardinal => "cardinal",
+            Self::Ordinal => "ordinal",
+            Self::Characters => "characters",
+            Self::Telephone => "telephone",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::Currency => "currency",
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Segment<'a> {
+    Text(&'a dyn ChineseFormat, Option<InterpretAs>),
+    Pause(u32),
+}
+
+/// SSML document - built incrementally via its `with_` methods.
+///
+/// Its language is `zh-CN` for [Variant::Simplified] and `zh-TW` for [Variant::Traditional];
+/// [omissible](crate::Chinese::omissible) expressions are skipped.
+///
+/// ```
+/// use chinese_format::{*, ssml::*};
+///
+/// let nothing = Placeholder::empty(Count(0));
+/// let empty = Ssml::new().with_text(&nothing);
+/// assert_eq!(
+///     empty.to_ssml(Variant::Simplified),
+///     r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="zh-CN"></speak>"#
+/// );
+///
+/// let escaped = Ssml::new().with_text(&"<A&B>");
+/// assert!(escaped.to_ssml(Variant::Simplified).contains("&lt;A&amp;B&gt;"));
+/// ```
+///
+/// **REQUIRED FEATURE**: `ssml`.
+#[derive(Clone, Default)]
+pub struct Ssml<'a> {
+    segments: Vec<Segment<'a>>,
+    pinyin: bool,
+}
+
+impl<'a> Ssml<'a> {
+    /// Creates an empty document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an expression to be read as plain text.
+    pub fn with_text(mut self, value: &'a dyn ChineseFormat) -> Self {
+        self.segments.push(Segment::Text(value, None));
+        self
+    }
+
+    /// Appends an expression wrapped in a `<say-as>` element.
+    pub fn with_say_as(mut self, value: &'a dyn ChineseFormat, interpret_as: InterpretAs) -> Self {
+        self.segments.push(Segment::Text(value, Some(interpret_as)));
+        self
+    }
+
+    /// Appends a `<break>` lasting the given milliseconds.
+    pub fn with_pause(mut self, milliseconds: u32) -> Self {
+        self.segments.push(Segment::Pause(milliseconds));
+        self
+    }
+
+    /// Sets whether each logogram having a pinyin is wrapped in a `<phoneme>` element.
+    pub fn with_pinyin(mut self, pinyin: bool) -> Self {
+        self.pinyin = pinyin;
+        self
+    }
+
+    fn language(variant: Variant) -> &'static str {
+        match variant {
+            Variant::Simplified => "zh-CN",
+            Variant::Traditional => "zh-TW",
+        }
+    }
+
+    fn text_to_ssml(&self, logograms: &str) -> String {
+        logograms
+            .chars()
+            .map(|logogram| match pinyin(logogram) {
+                Some(pinyin) if self.pinyin => format!(
+                    r#"<phoneme alphabet="x-pinyin" ph="{}">{}</phoneme>"#,
+                    pinyin, logogram
+                ),
+                _ => escape_html(logogram),
+            })
+            .collect()
+    }
+
+    /// Renders the whole `<speak>` document.
+    pub fn to_ssml(&self, variant: Variant) -> String {
+        let body: String = self
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(value, interpret_as) => {
+                    let chinese = value.to_chinese(variant);
+
+                    if chinese.omissible {
+                        return String::new();
+                    }
+
+                    let text = self.text_to_ssml(&chinese.logograms);
+
+                    match interpret_as {
+                        Some(interpret_as) => format!(
+                            r#"<say-as interpret-as="{}">{}</say-as>"#,
+                            interpret_as.attribute(),
+                            text
+                        ),
+                        None => text,
+                    }
+                }
+
+                Segment::Pause(milliseconds) => format!(r#"<break time="{}ms"/>"#, milliseconds),
+            })
+            .collect();
+
+        format!(
+            r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="{}">{}</speak>"#,
+            Self::language(variant),
+            body
+        )
+    }
+}
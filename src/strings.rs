@@ -1,4 +1,5 @@
 use crate::{Chinese, ChineseFormat, Variant};
+use std::borrow::Cow;
 
 /// Any &[str] can be infallibly converted to Chinese.
 ///
@@ -35,7 +36,7 @@ use crate::{Chinese, ChineseFormat, Variant};
 impl ChineseFormat for &str {
     fn to_chinese(&self, _variant: Variant) -> Chinese {
         Chinese {
-            logograms: (*self).into(),
+            logograms: self.to_string().into(),
             omissible: self.is_empty(),
         }
     }
@@ -74,3 +75,21 @@ impl ChineseFormat for String {
         self.as_str().to_chinese(variant)
     }
 }
+
+/// [Cow] strings - such as [Chinese::logograms] - can be infallibly converted to Chinese,
+/// just like [String].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let logograms = 7.to_chinese(Variant::Simplified).logograms;
+/// assert_eq!(logograms.to_chinese(Variant::Simplified), "七");
+///
+/// let empty = 0.to_chinese(Variant::Simplified).logograms;
+/// assert!(!empty.to_chinese(Variant::Simplified).omissible);
+/// ```
+impl ChineseFormat for Cow<'_, str> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        self.as_ref().to_chinese(variant)
+    }
+}
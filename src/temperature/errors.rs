@@ -0,0 +1,33 @@
+use std::{error::Error, fmt::Display};
+
+/// Error for when the lower bound of a [TemperatureRange](crate::temperature::TemperatureRange)
+/// is greater than its upper bound.
+///
+/// ```
+/// use chinese_format::temperature::*;
+///
+/// assert_eq!(
+///     InvalidTemperatureRange {
+///         low: 22,
+///         high: 15
+///     }.to_string(),
+///     "Invalid temperature range: 22 to 15"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidTemperatureRange {
+    pub low: i16,
+    pub high: i16,
+}
+
+impl Display for InvalidTemperatureRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid temperature range: {} to {}",
+            self.low, self.high
+        )
+    }
+}
+
+impl Error for InvalidTemperatureRange {}
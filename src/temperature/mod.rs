@@ -0,0 +1,222 @@
+//! Temperatures and weather reports.
+//!
+//! ```
+//! use chinese_format::{*, temperature::*};
+//!
+//! # fn main() -> GenericResult<()> {
+//! let today = Forecast::Range(TemperatureRange::try_new(15, 22)?);
+//! assert_eq!(today.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "十五到二十二度".into(),
+//!     omissible: false
+//! });
+//!
+//! let tonight = Forecast::Min(Celsius(-3));
+//! assert_eq!(tonight.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "最低零下三度".into(),
+//!     omissible: false
+//! });
+//! # Ok(())
+//! # }
+//! ```
+mod errors;
+
+use crate::{Chinese, ChineseFormat, Count, Variant};
+
+pub use errors::*;
+
+const DEGREE: &str = "度";
+
+const BELOW_ZERO: &str = "零下";
+
+/// Temperature in degrees Celsius - the default scale when just saying `度`.
+///
+/// Negative values are introduced by `零下`, while `2` becomes `两`/`兩`
+/// just like in [Count]; zero degrees are _not_ [omissible](Chinese::omissible),
+/// as they convey a meaningful temperature:
+///
+/// ```
+/// use chinese_format::{*, temperature::*};
+///
+/// assert_eq!(Celsius(22).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "二十二度".into(),
+///     omissible: false
+/// });
+///
+/// assert_eq!(Celsius(-3).to_chinese(Variant::Simplified), "零下三度");
+///
+/// assert_eq!(Celsius(2).to_chinese(Variant::Simplified), "两度");
+/// assert_eq!(Celsius(-2).to_chinese(Variant::Traditional), "零下兩度");
+///
+/// assert_eq!(Celsius(0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零度".into(),
+///     omissible: false
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Celsius(pub i16);
+
+impl Celsius {
+    fn value_to_logograms(&self, variant: Variant) -> String {
+        let magnitude = Count(self.0.unsigned_abs() as u128)
+            .to_chinese(variant)
+            .logograms
+            .into_owned();
+
+        if self.0 < 0 {
+            format!("{}{}", BELOW_ZERO, magnitude)
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl From<Celsius> for i16 {
+    fn from(source: Celsius) -> Self {
+        source.0
+    }
+}
+
+impl ChineseFormat for Celsius {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        Chinese {
+            logograms: format!("{}{}", self.value_to_logograms(variant), DEGREE).into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Range of [Celsius] temperatures, joined by `到` and
+/// followed by a single `度`.
+///
+/// Must be created via [try_new](Self::try_new), which ensures that the bounds are in order;
+/// when they are equal, the range renders as a single temperature.
+///
+/// ```
+/// use chinese_format::{*, temperature::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let spring = TemperatureRange::try_new(15, 22)?;
+/// assert_eq!(spring.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "十五到二十二度".into(),
+///     omissible: false
+/// });
+/// assert_eq!(spring.low(), Celsius(15));
+/// assert_eq!(spring.high(), Celsius(22));
+///
+/// let winter = TemperatureRange::try_new(-8, 2)?;
+/// assert_eq!(winter.to_chinese(Variant::Simplified), "零下八到两度");
+/// assert_eq!(winter.to_chinese(Variant::Traditional), "零下八到兩度");
+///
+/// let frost = TemperatureRange::try_new(-8, -2)?;
+/// assert_eq!(frost.to_chinese(Variant::Simplified), "零下八到零下两度");
+///
+/// let steady = TemperatureRange::try_new(30, 30)?;
+/// assert_eq!(steady.to_chinese(Variant::Simplified), "三十度");
+///
+/// assert_eq!(
+///     TemperatureRange::try_new(22, 15),
+///     Err(InvalidTemperatureRange { low: 22, high: 15 })
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TemperatureRange {
+    low: Celsius,
+    high: Celsius,
+}
+
+impl TemperatureRange {
+    const CONNECTOR: &'static str = "到";
+
+    pub fn try_new(low: i16, high: i16) -> Result<TemperatureRange, InvalidTemperatureRange> {
+        if low > high {
+            return Err(InvalidTemperatureRange { low, high });
+        }
+
+        Ok(Self {
+            low: Celsius(low),
+            high: Celsius(high),
+        })
+    }
+
+    pub fn low(&self) -> Celsius {
+        self.low
+    }
+
+    pub fn high(&self) -> Celsius {
+        self.high
+    }
+}
+
+impl ChineseFormat for TemperatureRange {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.low == self.high {
+            return self.low.to_chinese(variant);
+        }
+
+        Chinese {
+            logograms: format!(
+                "{}{}{}{}",
+                self.low.value_to_logograms(variant),
+                Self::CONNECTOR,
+                self.high.value_to_logograms(variant),
+                DEGREE
+            )
+            .into(),
+            omissible: false,
+        }
+    }
+}
+
+/// Temperature forecast, as shown in weather reports and dashboards.
+///
+/// ```
+/// use chinese_format::{*, temperature::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let range = Forecast::Range(TemperatureRange::try_new(-3, 4)?);
+/// assert_eq!(range.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零下三到四度".into(),
+///     omissible: false
+/// });
+///
+/// let min = Forecast::Min(Celsius(-3));
+/// assert_eq!(min.to_chinese(Variant::Simplified), "最低零下三度");
+/// assert_eq!(min.to_chinese(Variant::Traditional), "最低零下三度");
+///
+/// let max = Forecast::Max(Celsius(35));
+/// assert_eq!(max.to_chinese(Variant::Simplified), "最高三十五度");
+/// assert_eq!(max.to_chinese(Variant::Traditional), "最高三十五度");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Forecast {
+    /// Both the lowest and the highest temperature.
+    Range(TemperatureRange),
+
+    /// The lowest temperature only - introduced by `最低`.
+    Min(Celsius),
+
+    /// The highest temperature only - introduced by `最高`.
+    Max(Celsius),
+}
+
+impl ChineseFormat for Forecast {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Self::Range(range) => range.to_chinese(variant),
+
+            Self::Min(temperature) => Chinese {
+                logograms: format!("最低{}", temperature.to_chinese(variant).logograms).into(),
+                omissible: false,
+            },
+
+            Self::Max(temperature) => Chinese {
+                logograms: format!("最高{}", temperature.to_chinese(variant).logograms).into(),
+                omissible: false,
+            },
+        }
+    }
+}
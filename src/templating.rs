@@ -0,0 +1,177 @@
+//! Filters exposing the crate's conversions to [minijinja](https://crates.io/crates/minijinja) templates.
+//!
+//! [register_filters] adds the following filters to an [Environment] - each accepting an
+//! optional variant argument, `"simplified"` (the default) or `"traditional"`:
+//!
+//! - `chinese_number`: converts an integer - as in `{{ 42 | chinese_number }}`.
+//!
+//! - `chinese_count`: converts a non-negative integer as a [Count] - reading `2` as `两`.
+//!
+//! - `chinese_date` - requiring the `gregorian` feature: converts a `YYYY-MM-DD` string.
+//!
+//! - `chinese_currency` - requiring the `currency` feature: converts a renminbi amount,
+//!   given as a number or a string with up to 2 fractional digits; a second argument
+//!   selects the style - `"formal"` (the default), `"informal"` or `"financial"`.
+//!
+//! ```
+//! use chinese_format::templating::*;
+//! use minijinja::{context, Environment};
+//!
+//! let mut env = Environment::new();
+//! register_filters(&mut env);
+//!
+//! let template = "{{ count | chinese_count }}个{{ item }}";
+//! assert_eq!(
+//!     env.render_str(template, context! { count => 2, item => "苹果" }).unwrap(),
+//!     "两个苹果"
+//! );
+//!
+//! assert_eq!(env.render_str("{{ 2 | chinese_number }}", ()).unwrap(), "二");
+//!
+//! assert_eq!(
+//!     env.render_str("{{ -302 | chinese_number('traditional') }}", ()).unwrap(),
+//!     "負三百零二"
+//! );
+//!
+//! assert!(env.render_str("{{ 5 | chinese_number('klingon') }}", ()).is_err());
+//! ```
+//!
+//! ```
+//! # #[cfg(all(feature = "gregorian", feature = "currency"))]
+//! # {
+//! use chinese_format::templating::*;
+//! use minijinja::{context, Environment};
+//!
+//! let mut env = Environment::new();
+//! register_filters(&mut env);
+//!
+//! assert_eq!(
+//!     env.render_str("{{ '2024-03-05' | chinese_date }}", ()).unwrap(),
+//!     "二零二四年三月五号"
+//! );
+//! assert!(env.render_str("{{ '2023-02-29' | chinese_date }}", ()).is_err());
+//!
+//! assert_eq!(
+//!     env.render_str("{{ price | chinese_currency }}", context! { price => 9.38 }).unwrap(),
+//!     "九元三角八分"
+//! );
+//! assert_eq!(
+//!     env.render_str("{{ '7.45' | chinese_currency('simplified', 'informal') }}", ()).unwrap(),
+//!     "七块四毛五分"
+//! );
+//! assert_eq!(
+//!     env.render_str("{{ 2.6 | chinese_currency('traditional', 'financial') }}", ()).unwrap(),
+//!     "貳元陸角整"
+//! );
+//! # }
+//! ```
+//!
+//! **REQUIRED FEATURE**: `minijinja`.
+use crate::{ChineseFormat, Count, Variant};
+use minijinja::{Environment, Error, ErrorKind};
+
+#[cfg(feature = "currency")]
+use crate::currency::{CurrencyStyle, RenminbiCurrencyBuilder};
+#[cfg(feature = "currency")]
+use minijinja::Value;
+
+/// Registers all the filters described in the [module documentation](self).
+pub fn register_filters(env: &mut Environment) {
+    env.add_filter("chinese_number", chinese_number);
+
+    env.add_filter("chinese_count", chinese_count);
+
+    #[cfg(feature = "gregorian")]
+    env.add_filter("chinese_date", chinese_date);
+
+    #[cfg(feature = "currency")]
+    env.add_filter("chinese_currency", chinese_currency);
+}
+
+fn invalid_argument(detail: String) -> Error {
+    Error::new(ErrorKind::InvalidOperation, detail)
+}
+
+fn parse_variant(variant: Option<&str>) -> Result<Variant, Error> {
+    match variant.unwrap_or("simplified") {
+        "simplified" => Ok(Variant::Simplified),
+        "traditional" => Ok(Variant::Traditional),
+        other => Err(invalid_argument(format!(
+            "Unknown Chinese variant: {}",
+            other
+        ))),
+    }
+}
+
+fn chinese_number(value: i64, variant: Option<&str>) -> Result<String, Error> {
+    Ok(value.to_chinese(parse_variant(variant)?).into())
+}
+
+fn chinese_count(value: u64, variant: Option<&str>) -> Result<String, Error> {
+    Ok(Count(value as u128)
+        .to_chinese(parse_variant(variant)?)
+        .into())
+}
+
+#[cfg(feature = "gregorian")]
+fn chinese_date(value: &str, variant: Option<&str>) -> Result<String, Error> {
+    use crate::gregorian::DateBuilder;
+
+    let invalid_date = || invalid_argument(format!("Invalid YYYY-MM-DD date: {}", value));
+
+    let components: Vec<&str> = value.split('-').collect();
+
+    let [year, month, day] = components[..] else {
+        return Err(invalid_date());
+    };
+
+    let date = DateBuilder::new()
+        .with_year(year.parse().map_err(|_| invalid_date())?)
+        .with_month(month.parse().map_err(|_| invalid_date())?)
+        .with_day(day.parse().map_err(|_| invalid_date())?)
+        .build()
+        .map_err(|err| invalid_argument(err.to_string()))?;
+
+    Ok(date.to_chinese(parse_variant(variant)?).into())
+}
+
+#[cfg(feature = "currency")]
+fn chinese_currency(
+    value: Value,
+    variant: Option<&str>,
+    style: Option<&str>,
+) -> Result<String, Error> {
+    let amount = value.to_string();
+
+    let invalid_amount = || invalid_argument(format!("Invalid currency amount: {}", amount));
+
+    let (yuan, fractional) = amount.split_once('.').unwrap_or((&amount, ""));
+
+    if fractional.len() > 2 || !fractional.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid_amount());
+    }
+
+    let mut fractional_digits = fractional.chars().filter_map(|c| c.to_digit(10));
+
+    let style = match style.unwrap_or("formal") {
+        "formal" => CurrencyStyle::Everyday { formal: true },
+        "informal" => CurrencyStyle::Everyday { formal: false },
+        "financial" => CurrencyStyle::Financial,
+        other => {
+            return Err(invalid_argument(format!(
+                "Unknown currency style: {}",
+                other
+            )))
+        }
+    };
+
+    let currency = RenminbiCurrencyBuilder::new()
+        .with_yuan(yuan.parse().map_err(|_| invalid_amount())?)
+        .with_dimes(fractional_digits.next().unwrap_or(0) as u8)
+        .with_cents(fractional_digits.next().unwrap_or(0) as u8)
+        .with_style(style)
+        .build()
+        .map_err(|err| invalid_argument(err.to_string()))?;
+
+    Ok(currency.to_chinese(parse_variant(variant)?).into())
+}
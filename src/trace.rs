@@ -0,0 +1,193 @@
+//! Helpers for recording values in [tracing] spans - both as their Chinese rendering
+//! and as their raw numeric fields.
+//!
+//! Each type implementing [RawFields] can be passed to [record_in_span], which records:
+//!
+//! - the Chinese logograms, in the field having the given name;
+//!
+//! - each raw numeric field, in the field named `{name}.{raw field}`.
+//!
+//! As usual in [tracing], the fields must be declared when creating the span -
+//! the undeclared ones being simply ignored:
+//!
+//! ```
+//! use chinese_format::{*, trace::*};
+//! use tracing::{field::Empty, info_span};
+//!
+//! # fn main() -> GenericResult<()> {
+//! let fraction = Fraction::try_new(4, 3)?;
+//! assert_eq!(fraction.raw_fields(), vec![
+//!     ("denominator", RawValue::Unsigned(4)),
+//!     ("numerator", RawValue::Signed(3))
+//! ]);
+//!
+//! let span = info_span!(
+//!     "split",
+//!     share = Empty,
+//!     share.denominator = Empty,
+//!     share.numerator = Empty
+//! );
+//! record_in_span(&span, "share", &fraction, Variant::Simplified);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Composite values expose all their numeric components:
+//!
+//! ```
+//! # #[cfg(all(feature = "currency", feature = "gregorian"))]
+//! # {
+//! use chinese_format::{*, currency::*, gregorian::*, trace::*};
+//!
+//! # fn main() -> GenericResult<()> {
+//! let amount = RenminbiCurrencyBuilder::new()
+//!     .with_yuan(15)
+//!     .with_dimes(3)
+//!     .build()?;
+//! assert_eq!(amount.raw_fields(), vec![
+//!     ("yuan", RawValue::Unsigned(15)),
+//!     ("dimes", RawValue::Unsigned(3)),
+//!     ("cents", RawValue::Unsigned(0))
+//! ]);
+//!
+//! let date = DateBuilder::new()
+//!     .with_month(6)
+//!     .with_day(13)
+//!     .with_week_day(WeekDay::Saturday)
+//!     .build()?;
+//! assert_eq!(date.raw_fields(), vec![
+//!     ("month", RawValue::Unsigned(6)),
+//!     ("day", RawValue::Unsigned(13)),
+//!     ("week_day", RawValue::Unsigned(6))
+//! ]);
+//!
+//! let time = LinearTime {
+//!     day_part: true,
+//!     hour: 19.try_into()?,
+//!     minute: 24.try_into()?,
+//!     second: None
+//! };
+//! assert_eq!(time.raw_fields(), vec![
+//!     ("hour", RawValue::Unsigned(19)),
+//!     ("minute", RawValue::Unsigned(24))
+//! ]);
+//! # Ok(())
+//! # }
+//! # main().unwrap();
+//! # }
+//! ```
+//!
+//! **REQUIRED FEATURE**: `tracing`.
+use crate::{ChineseFormat, Count, Fraction, Variant};
+use tracing::{field::display, Span};
+
+#[cfg(feature = "currency")]
+use crate::currency::RenminbiCurrency;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::{Date, DeltaTime, LinearTime};
+
+/// Raw numeric value of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RawValue {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+/// Exposes the raw numeric components of a value - as `(name, value)` pairs.
+pub trait RawFields {
+    fn raw_fields(&self) -> Vec<(&'static str, RawValue)>;
+}
+
+/// Records in the given span both the Chinese rendering and the [raw fields](RawFields)
+/// of a value, as described in the [module documentation](self).
+pub fn record_in_span<T: ChineseFormat + RawFields>(
+    span: &Span,
+    name: &str,
+    value: &T,
+    variant: Variant,
+) {
+    span.record(name, display(value.to_chinese(variant)));
+
+    for (raw_name, raw_value) in value.raw_fields() {
+        let field_name = format!("{}.{}", name, raw_name);
+
+        match raw_value {
+            RawValue::Signed(signed) => span.record(field_name.as_str(), signed),
+            RawValue::Unsigned(unsigned) => span.record(field_name.as_str(), unsigned),
+        };
+    }
+}
+
+impl RawFields for Count {
+    fn raw_fields(&self) -> Vec<(&'static str, RawValue)> {
+        vec![("value", RawValue::Unsigned(self.0))]
+    }
+}
+
+impl RawFields for Fraction {
+    fn raw_fields(&self) -> Vec<(&'static str, RawValue)> {
+        vec![
+            ("denominator", RawValue::Unsigned(self.denominator())),
+            ("numerator", RawValue::Signed(self.numerator())),
+        ]
+    }
+}
+
+#[cfg(feature = "currency")]
+impl RawFields for RenminbiCurrency {
+    fn raw_fields(&self) -> Vec<(&'static str, RawValue)> {
+        vec![
+            ("yuan", RawValue::Unsigned(self.yuan() as u128)),
+            ("dimes", RawValue::Unsigned(self.dimes() as u128)),
+            ("cents", RawValue::Unsigned(self.cents() as u128)),
+        ]
+    }
+}
+
+#[cfg(feature = "gregorian")]
+impl RawFields for Date {
+    fn raw_fields(&self) -> Vec<(&'static str, RawValue)> {
+        [
+            ("year", self.year().map(u128::from)),
+            ("month", self.month().map(u128::from)),
+            ("day", self.day().map(u128::from)),
+            ("week_day", self.week_day().map(|week_day| week_day as u128)),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name, RawValue::Unsigned(value))))
+        .collect()
+    }
+}
+
+#[cfg(feature = "gregorian")]
+impl RawFields for LinearTime {
+    fn raw_fields(&self) -> Vec<(&'static str, RawValue)> {
+        let mut fields = vec![
+            ("hour", RawValue::Unsigned(u8::from(self.hour) as u128)),
+            ("minute", RawValue::Unsigned(u8::from(self.minute) as u128)),
+        ];
+
+        if let Some(second) = self.second {
+            fields.push(("second", RawValue::Unsigned(u8::from(second) as u128)));
+        }
+
+        fields
+    }
+}
+
+#[cfg(feature = "gregorian")]
+impl RawFields for DeltaTime {
+    fn raw_fields(&self) -> Vec<(&'static str, RawValue)> {
+        let mut fields = vec![
+            ("hour", RawValue::Unsigned(u8::from(self.hour) as u128)),
+            ("minute", RawValue::Unsigned(u8::from(self.minute) as u128)),
+        ];
+
+        if let Some(second) = self.second {
+            fields.push(("second", RawValue::Unsigned(u8::from(second) as u128)));
+        }
+
+        fields
+    }
+}
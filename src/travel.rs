@@ -0,0 +1,207 @@
+//! Itinerary sentences - composing a [Date], a [LinearTime] and
+//! the identifier of a train or a flight.
+//!
+//! ```
+//! use chinese_format::{*, gregorian::*, travel::*};
+//!
+//! # fn main() -> GenericResult<()> {
+//! let date = DateBuilder::new()
+//!     .with_month(3)
+//!     .with_day(5)
+//!     .build()?;
+//!
+//! let time = LinearTime {
+//!     day_part: true,
+//!     hour: 9.try_into()?,
+//!     minute: 20.try_into()?,
+//!     second: None
+//! };
+//!
+//! let train = Itinerary {
+//!     date: &date,
+//!     time: &time,
+//!     service: &"G1024",
+//!     transport: Transport::Train
+//! };
+//!
+//! assert_eq!(train.to_chinese(Variant::Simplified), Chinese {
+//!     logograms: "三月五号上午九点二十的G1024次列车".into(),
+//!     omissible: false
+//! });
+//! assert_eq!(train.to_chinese(Variant::Traditional), "三月五號上午九點二十的G1024次列車");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **REQUIRED FEATURE**: `gregorian`.
+use crate::{
+    chinese_vec,
+    gregorian::{Date, LinearTime},
+    Chinese, ChineseFormat, EmptyPlaceholder, LeftPadder, Variant,
+};
+
+/// The kind of service in an [Itinerary].
+///
+/// **REQUIRED FEATURE**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Transport {
+    /// `次列车`/`次列車` - after the train number.
+    Train,
+
+    /// `航班` - after the flight number.
+    Flight,
+}
+
+impl ChineseFormat for Transport {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Self::Train => ("次列车", "次列車").to_chinese(variant),
+            Self::Flight => "航班".to_chinese(variant),
+        }
+    }
+}
+
+/// Departure of a train or a flight - rendered as
+/// _date_, _time_, `的`, _service identifier_ and [Transport].
+///
+/// As in announcements, the time is read without the trailing `分` -
+/// unless the seconds are declared.
+///
+/// The service identifier can be any [ChineseFormat]: a plain string
+/// keeps it as written, whereas an [IdNumber](crate::IdNumber) reads it
+/// character by character - as in announcements:
+///
+/// ```
+/// use chinese_format::{*, gregorian::*, travel::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let date = DateBuilder::new()
+///     .with_month(12)
+///     .with_day(1)
+///     .build()?;
+///
+/// let time = LinearTime {
+///     day_part: false,
+///     hour: 18.try_into()?,
+///     minute: 5.try_into()?,
+///     second: None
+/// };
+///
+/// let flight_number = IdNumber::try_new("CA1831", IdNumberStyle {
+///     letter_style: LetterStyle::AsIs,
+///     reading_style: ReadingStyle::Yao,
+///     ..IdNumberStyle::default()
+/// })?;
+///
+/// let flight = Itinerary {
+///     date: &date,
+///     time: &time,
+///     service: &flight_number,
+///     transport: Transport::Flight
+/// };
+///
+/// assert_eq!(flight.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "十二月一号十八点零五的CA幺八三幺航班".into(),
+///     omissible: false
+/// });
+/// assert_eq!(flight.to_chinese(Variant::Traditional), "十二月一號十八點零五的CA幺八三幺航班");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **REQUIRED FEATURE**: `gregorian`.
+pub struct Itinerary<'a> {
+    pub date: &'a Date,
+    pub time: &'a LinearTime,
+    pub service: &'a dyn ChineseFormat,
+    pub transport: Transport,
+}
+
+impl<'a> ChineseFormat for Itinerary<'a> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.date,
+                SpokenTime(self.time),
+                "的",
+                self.service,
+                self.transport
+            ]
+        )
+        .collect()
+    }
+}
+
+/// [LinearTime] whose minutes - when no second is declared -
+/// are read without unit, as in `九点二十` or `十八点零五`.
+struct SpokenTime<'a>(&'a LinearTime);
+
+impl ChineseFormat for SpokenTime<'_> {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let time = self.0;
+
+        if time.second.is_some() {
+            return time.to_chinese(variant);
+        }
+
+        let hour = LinearTime {
+            minute: 0.try_into().expect("Zero should be a valid minute!"),
+            ..*time
+        };
+
+        chinese_vec!(
+            variant,
+            [
+                hour,
+                EmptyPlaceholder::new(&LeftPadder {
+                    logogram: '零',
+                    min_width: 2,
+                    source: &u8::from(time.minute)
+                })
+            ]
+        )
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gregorian::DateBuilder;
+    use pretty_assertions::assert_eq as eq;
+    use speculate2::*;
+
+    speculate! {
+        describe "Reading the time of an itinerary" {
+            fn itinerary_time(minute: u8, second: Option<u8>) -> String {
+                let date = DateBuilder::new().with_month(3).with_day(5).build().unwrap();
+
+                let time = LinearTime {
+                    day_part: false,
+                    hour: 9.try_into().unwrap(),
+                    minute: minute.try_into().unwrap(),
+                    second: second.map(|second| second.try_into().unwrap()),
+                };
+
+                Itinerary {
+                    date: &date,
+                    time: &time,
+                    service: &"G1",
+                    transport: Transport::Train,
+                }
+                .to_chinese(Variant::Simplified)
+                .logograms
+                .into_owned()
+            }
+
+            it "should omit the minute on the hour" {
+                eq!(itinerary_time(0, None), "三月五号九点的G1次列车");
+            }
+
+            it "should keep the units when the seconds are declared" {
+                eq!(itinerary_time(20, Some(7)), "三月五号九点二十分零七秒的G1次列车");
+            }
+        }
+    }
+}
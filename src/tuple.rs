@@ -1,4 +1,4 @@
-use crate::{Chinese, ChineseFormat, Variant};
+use crate::{Chinese, ChineseFormat, ChineseParts, DigitGlyphSet, Variant};
 
 /// Any pair of types implementing [ChineseFormat] can be infallibly converted to Chinese.
 ///
@@ -19,7 +19,7 @@ use crate::{Chinese, ChineseFormat, Variant};
 /// let integer_tuple = (92, 0);
 /// assert_eq!(integer_tuple.to_chinese(Variant::Simplified), "九十二");
 /// assert_eq!(integer_tuple.to_chinese(Variant::Traditional), Chinese {
-///     logograms: "零".to_string(),
+///     logograms: "零".into(),
 ///     omissible: true
 /// });
 /// ```
@@ -30,4 +30,26 @@ impl<T1: ChineseFormat, T2: ChineseFormat> ChineseFormat for (T1, T2) {
             Variant::Traditional => self.1.to_chinese(variant),
         }
     }
+
+    fn to_chinese_with_glyphs(&self, variant: Variant, glyphs: DigitGlyphSet) -> Chinese {
+        match variant {
+            Variant::Simplified => self.0.to_chinese_with_glyphs(variant, glyphs),
+            Variant::Traditional => self.1.to_chinese_with_glyphs(variant, glyphs),
+        }
+    }
+
+    fn to_chinese_parts(&self, variant: Variant) -> ChineseParts {
+        match variant {
+            Variant::Simplified => self.0.to_chinese_parts(variant),
+            Variant::Traditional => self.1.to_chinese_parts(variant),
+        }
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn to_chinese_tokens(&self, variant: Variant) -> Vec<crate::pinyin::Token> {
+        match variant {
+            Variant::Simplified => self.0.to_chinese_tokens(variant),
+            Variant::Traditional => self.1.to_chinese_tokens(variant),
+        }
+    }
 }
@@ -0,0 +1,86 @@
+/// Width of the glyphs emitted for ASCII characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum GlyphWidth {
+    /// Standard ASCII glyphs - as in `35`.
+    #[default]
+    Half,
+
+    /// Full-width glyphs, aligned with the logograms - as in `３５`.
+    Full,
+}
+
+/// Typographic options for the ASCII characters - digits, letters and
+/// punctuation - that can appear in the output, for example when
+/// rendering Arabic numerals or serial numbers.
+///
+/// By default, all the characters are kept half-width:
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let default_typography = Typography::default();
+/// assert_eq!(default_typography.apply("A-35.7%"), "A-35.7%");
+///
+/// assert_eq!(Typography::FULL_WIDTH.apply("A-35.7%"), "Ａ－３５．７％");
+///
+/// let digits_only = Typography {
+///     digits: GlyphWidth::Full,
+///     ..Typography::default()
+/// };
+/// assert_eq!(digits_only.apply("A-35.7%"), "A-３５.７%");
+///
+/// let punctuation_only = Typography {
+///     punctuation: GlyphWidth::Full,
+///     ..Typography::default()
+/// };
+/// assert_eq!(punctuation_only.apply("A-35.7% 人"), "A－35．7％　人");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Typography {
+    pub digits: GlyphWidth,
+
+    pub letters: GlyphWidth,
+
+    /// Applies to ASCII punctuation and to the space - which becomes `　` (U+3000).
+    pub punctuation: GlyphWidth,
+}
+
+impl Typography {
+    /// All the ASCII characters are converted to full-width.
+    pub const FULL_WIDTH: Typography = Typography {
+        digits: GlyphWidth::Full,
+        letters: GlyphWidth::Full,
+        punctuation: GlyphWidth::Full,
+    };
+
+    const FULL_WIDTH_OFFSET: u32 = 0xFEE0;
+
+    const FULL_WIDTH_SPACE: char = '\u{3000}';
+
+    /// Converts the ASCII characters of the given text according to the options;
+    /// any other character is left untouched.
+    pub fn apply(&self, text: &str) -> String {
+        text.chars().map(|c| self.apply_to_char(c)).collect()
+    }
+
+    fn apply_to_char(&self, c: char) -> char {
+        let width = if c.is_ascii_digit() {
+            self.digits
+        } else if c.is_ascii_alphabetic() {
+            self.letters
+        } else if c.is_ascii_punctuation() || c == ' ' {
+            self.punctuation
+        } else {
+            return c;
+        };
+
+        match width {
+            GlyphWidth::Half => c,
+
+            GlyphWidth::Full if c == ' ' => Self::FULL_WIDTH_SPACE,
+
+            GlyphWidth::Full => char::from_u32(c as u32 + Self::FULL_WIDTH_OFFSET)
+                .expect("Printable ASCII characters have full-width counterparts"),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Chinese, ChineseFormat, Variant};
+use crate::{
+    Chinese, ChineseFormat, ChineseParts, CollectOptions, CompositionStyle, Variant, ZeroPolicy,
+};
 
 /// Creates [ChineseVec] instances with elegant simplicity.
 ///
@@ -10,7 +12,7 @@ use crate::{Chinese, ChineseFormat, Variant};
 /// let empty_vec = chinese_vec!(Variant::Simplified, []);
 ///
 /// assert_eq!(empty_vec.collect(), Chinese {
-///     logograms: "".to_string(),
+///     logograms: "".into(),
 ///     omissible: true
 /// });
 ///
@@ -18,7 +20,7 @@ use crate::{Chinese, ChineseFormat, Variant};
 /// let one_item_vec = chinese_vec!(Variant::Simplified, [90]);
 ///
 /// assert_eq!(one_item_vec.collect(), Chinese {
-///     logograms: "九十".to_string(),
+///     logograms: "九十".into(),
 ///     omissible: false
 /// });
 ///
@@ -29,7 +31,7 @@ use crate::{Chinese, ChineseFormat, Variant};
 /// ]);
 ///
 /// assert_eq!(two_item_vec.collect(), Chinese {
-///     logograms: "你好三十八".to_string(),
+///     logograms: "你好三十八".into(),
 ///     omissible: false
 /// });
 /// ```
@@ -63,17 +65,17 @@ macro_rules! chinese_vec {
 ///
 /// let first_vec: ChineseVec = vec![
 ///     Chinese {
-///         logograms: "很".to_string(),
+///         logograms: "很".into(),
 ///         omissible: false
 ///     },
 ///     Chinese {
-///         logograms: "好".to_string(),
+///         logograms: "好".into(),
 ///         omissible: false
 ///     }
 /// ].into();
 ///
 /// assert_eq!(first_vec.collect(), Chinese {
-///     logograms: "很好".to_string(),
+///     logograms: "很好".into(),
 ///     omissible: false
 /// });
 ///
@@ -84,7 +86,7 @@ macro_rules! chinese_vec {
 /// ]);
 ///
 /// assert_eq!(second_vec.collect(), Chinese {
-///     logograms: "兩釐米".to_string(),
+///     logograms: "兩釐米".into(),
 ///     omissible: false
 /// });
 ///
@@ -95,7 +97,7 @@ macro_rules! chinese_vec {
 /// ]);
 ///
 /// assert_eq!(third_vec.collect(), Chinese {
-///     logograms: "七釐米".to_string(),
+///     logograms: "七釐米".into(),
 ///     omissible: false
 /// });
 /// ```
@@ -123,7 +125,7 @@ impl ChineseVec {
     /// );
     ///
     /// assert_eq!(chinese_vec.collect(), Chinese {
-    ///     logograms: "负七分之五".to_string(),
+    ///     logograms: "负七分之五".into(),
     ///     omissible: false
     /// });
     /// ```
@@ -154,7 +156,7 @@ impl ChineseVec {
     /// ]).trim_start();
     ///
     /// assert_eq!(chinese_vec.collect(), Chinese {
-    ///     logograms: "八好零零".to_string(),
+    ///     logograms: "八好零零".into(),
     ///     omissible: false
     /// });
     /// ```
@@ -186,7 +188,7 @@ impl ChineseVec {
     /// ]).trim_end();
     ///
     /// assert_eq!(chinese_vec.collect(), Chinese {
-    ///     logograms: "零零八好".to_string(),
+    ///     logograms: "零零八好".into(),
     ///     omissible: false
     /// });
     /// ```
@@ -202,6 +204,86 @@ impl ChineseVec {
         ChineseVec(result)
     }
 
+    /// Returns a copy of the vector whose [omissible](Chinese::omissible)
+    /// items are handled according to the given [ZeroPolicy].
+    ///
+    /// ```
+    /// use chinese_format::{*, length::*};
+    ///
+    /// let components = chinese_vec!(Variant::Simplified, [
+    ///     Kilometer::new(0),
+    ///     Kilometer::new(3),
+    ///     Meter::new(0),
+    ///     Centimeter::new(0),
+    ///     Millimeter::new(5),
+    ///     Millimeter::new(0)
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     components.apply_zero_policy(ZeroPolicy::Keep).collect(),
+    ///     components.collect()
+    /// );
+    ///
+    /// assert_eq!(
+    ///     components.apply_zero_policy(ZeroPolicy::Omit).collect(),
+    ///     Chinese {
+    ///         logograms: "三公里五毫米".into(),
+    ///         omissible: false
+    ///     }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     components.apply_zero_policy(ZeroPolicy::Gap).collect(),
+    ///     Chinese {
+    ///         logograms: "三公里零五毫米".into(),
+    ///         omissible: false
+    ///     }
+    /// );
+    ///
+    /// let all_omissible = chinese_vec!(Variant::Simplified, [
+    ///     Meter::new(0),
+    ///     Centimeter::new(0)
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     all_omissible.apply_zero_policy(ZeroPolicy::Gap).collect(),
+    ///     Chinese {
+    ///         logograms: "".into(),
+    ///         omissible: true
+    ///     }
+    /// );
+    /// ```
+    pub fn apply_zero_policy(&self, policy: ZeroPolicy) -> Self {
+        match policy {
+            ZeroPolicy::Keep => self.clone(),
+
+            ZeroPolicy::Omit => ChineseVec(
+                self.0
+                    .iter()
+                    .filter(|item| !item.omissible)
+                    .cloned()
+                    .collect(),
+            ),
+
+            ZeroPolicy::Gap => {
+                let mut result: Vec<Chinese> = vec![];
+
+                for item in self.trim_start().trim_end().0 {
+                    if !item.omissible {
+                        result.push(item);
+                    } else if result.last().is_some_and(|last| !last.omissible) {
+                        result.push(Chinese {
+                            logograms: ZeroPolicy::LING.into(),
+                            omissible: true,
+                        });
+                    }
+                }
+
+                ChineseVec(result)
+            }
+        }
+    }
+
     /// Concatenates all the [Chinese] expressions into a single one.
     ///
     /// The resulting [Chinese] is defined as follows:
@@ -224,13 +306,13 @@ impl ChineseVec {
     ///     "分"
     /// ]);
     /// assert_eq!(basic.collect(), Chinese {
-    ///     logograms: "九点四分".to_string(),
+    ///     logograms: "九点四分".into(),
     ///     omissible: false
     /// });
     ///
     /// let empty: ChineseVec = vec![].into();
     /// assert_eq!(empty.collect(), Chinese {
-    ///     logograms: "".to_string(),
+    ///     logograms: "".into(),
     ///     omissible: true
     /// });
     ///
@@ -245,20 +327,112 @@ impl ChineseVec {
     ///     ("", "Test")
     /// ]);
     /// assert_eq!(only_omissible.collect(), Chinese {
-    ///     logograms: "零零".to_string(),
+    ///     logograms: "零零".into(),
     ///     omissible: true
     /// })
     /// ```
     pub fn collect(&self) -> Chinese {
-        Chinese {
-            logograms: self
-                .0
-                .iter()
-                .map(|item| item.logograms.as_str())
-                .collect::<Vec<_>>()
-                .join(""),
+        self.collect_composed(CompositionStyle::Compact)
+    }
 
-            omissible: self.0.is_empty() || self.0.iter().all(|item| item.omissible),
+    /// Like [collect](Self::collect), but joining the items according to
+    /// the given [CompositionStyle]; items having empty logograms are skipped,
+    /// so as not to duplicate separators.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// let chinese_vec = chinese_vec!(Variant::Simplified, [
+    ///     "苹果",
+    ///     "",
+    ///     "香蕉",
+    ///     "橙子"
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     chinese_vec.collect_composed(CompositionStyle::EnumerationComma),
+    ///     Chinese {
+    ///         logograms: "苹果、香蕉、橙子".into(),
+    ///         omissible: false
+    ///     }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     chinese_vec.collect_composed(CompositionStyle::Spaced),
+    ///     "苹果 香蕉 橙子"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     chinese_vec.collect_composed(CompositionStyle::Compact),
+    ///     chinese_vec.collect()
+    /// );
+    /// ```
+    pub fn collect_composed(&self, style: CompositionStyle) -> Chinese {
+        self.collect_with(&style.into())
+    }
+
+    /// Joins the items according to the given [CollectOptions] - supporting a separator,
+    /// a final conjunction and a fallback for when no item has logograms;
+    /// as in [collect_composed](Self::collect_composed), items having empty logograms are skipped.
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// let options = CollectOptions {
+    ///     separator: "、",
+    ///     final_conjunction: Some("和"),
+    ///     empty_fallback: Some("无")
+    /// };
+    ///
+    /// let fruits = chinese_vec!(Variant::Simplified, ["苹果", "", "香蕉", "橙子"]);
+    /// assert_eq!(fruits.collect_with(&options), Chinese {
+    ///     logograms: "苹果、香蕉和橙子".into(),
+    ///     omissible: false
+    /// });
+    ///
+    /// let pair = chinese_vec!(Variant::Simplified, ["苹果", "橙子"]);
+    /// assert_eq!(pair.collect_with(&options), "苹果和橙子");
+    ///
+    /// let single = chinese_vec!(Variant::Simplified, ["苹果"]);
+    /// assert_eq!(single.collect_with(&options), "苹果");
+    ///
+    /// let empty = chinese_vec!(Variant::Simplified, ["", ""]);
+    /// assert_eq!(empty.collect_with(&options), Chinese {
+    ///     logograms: "无".into(),
+    ///     omissible: true
+    /// });
+    ///
+    /// assert_eq!(
+    ///     fruits.collect_with(&CollectOptions::default()),
+    ///     fruits.collect()
+    /// );
+    /// ```
+    pub fn collect_with(&self, options: &CollectOptions) -> Chinese {
+        let visible_logograms: Vec<&str> = self
+            .0
+            .iter()
+            .map(|item| item.as_str())
+            .filter(|logograms| !logograms.is_empty())
+            .collect();
+
+        let omissible = self.0.is_empty() || self.0.iter().all(|item| item.omissible);
+
+        let logograms = match (visible_logograms.split_last(), options.final_conjunction) {
+            (None, _) => options.empty_fallback.unwrap_or_default().to_string(),
+
+            (Some((last, init)), Some(final_conjunction)) if !init.is_empty() => format!(
+                "{}{}{}",
+                init.join(options.separator),
+                final_conjunction,
+                last
+            ),
+
+            _ => visible_logograms.join(options.separator),
+        };
+
+        Chinese {
+            logograms: logograms.into(),
+            omissible,
         }
     }
 }
@@ -270,21 +444,21 @@ impl ChineseVec {
 ///
 /// let chinese_vec: ChineseVec = vec![
 ///     Chinese {
-///         logograms: "没".to_string(),
+///         logograms: "没".into(),
 ///         omissible: false
 ///     },
 ///     Chinese {
-///         logograms: "关".to_string(),
+///         logograms: "关".into(),
 ///         omissible: false
 ///     },
 ///     Chinese {
-///         logograms: "系".to_string(),
+///         logograms: "系".into(),
 ///         omissible: false
 ///     }
 /// ].into();
 ///
 /// assert_eq!(chinese_vec.collect(), Chinese {
-///     logograms: "没关系".to_string(),
+///     logograms: "没关系".into(),
 ///     omissible: false
 /// });
 /// ```
@@ -314,6 +488,14 @@ impl ChineseFormat for ChineseVec {
     fn to_chinese(&self, _variant: Variant) -> Chinese {
         self.collect()
     }
+
+    fn to_chinese_composed(&self, _variant: Variant, style: CompositionStyle) -> Chinese {
+        self.collect_composed(style)
+    }
+
+    fn to_chinese_parts(&self, _variant: Variant) -> ChineseParts {
+        self.0.iter().map(|item| item.logograms.clone()).collect()
+    }
 }
 
 ///Any &[ChineseVec] can be infallibly converted to a [Vec] of [Chinese].
@@ -330,12 +512,12 @@ impl ChineseFormat for ChineseVec {
 ///
 /// assert_eq!(vec_of_chinese, vec![
 ///     Chinese {
-///         logograms: "你好".to_string(),
+///         logograms: "你好".into(),
 ///         omissible: false
 ///     },
 ///
 ///     Chinese {
-///         logograms: "生日快乐".to_string(),
+///         logograms: "生日快乐".into(),
 ///         omissible: false
 ///     }
 /// ]);
@@ -0,0 +1,87 @@
+use crate::{Chinese, ChineseFormat, ChineseVec, Variant};
+
+/// Fluent builder for [ChineseVec], converting each item as soon as it is pushed,
+/// according to the stored [Variant].
+///
+/// Unlike [chinese_vec](crate::chinese_vec) and [ChineseVec::from], it takes
+/// the items by value - which makes it especially useful in loops.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let mut builder = ChineseVecBuilder::new(Variant::Simplified)
+///     .push("我")
+///     .push("有");
+///
+/// for count in [2, 3] {
+///     builder = builder.push(Count(count)).push(("个", "個"));
+/// }
+///
+/// assert_eq!(builder.build().collect(), Chinese {
+///     logograms: "我有两个三个".into(),
+///     omissible: false
+/// });
+/// ```
+///
+/// Whole sequences can be added via [extend](Self::extend):
+///
+/// ```
+/// use chinese_format::*;
+///
+/// let chinese_vec = ChineseVec::builder(Variant::Traditional)
+///     .extend([Count(2), Count(0), Count(5)])
+///     .push("個")
+///     .build();
+///
+/// assert_eq!(
+///     chinese_vec.collect_composed(CompositionStyle::EnumerationComma),
+///     "兩、零、五、個"
+/// );
+///
+/// assert_eq!(ChineseVecBuilder::new(Variant::Simplified).build().collect(), Chinese {
+///     logograms: "".into(),
+///     omissible: true
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChineseVecBuilder {
+    variant: Variant,
+    items: Vec<Chinese>,
+}
+
+impl ChineseVecBuilder {
+    /// Creates an empty builder, converting items to the given [Variant].
+    pub fn new(variant: Variant) -> Self {
+        Self {
+            variant,
+            items: vec![],
+        }
+    }
+
+    /// Converts and appends the given item.
+    pub fn push(mut self, item: impl ChineseFormat) -> Self {
+        self.items.push(item.to_chinese(self.variant));
+        self
+    }
+
+    /// Converts and appends all the given items.
+    pub fn extend<T: ChineseFormat>(mut self, items: impl IntoIterator<Item = T>) -> Self {
+        let variant = self.variant;
+
+        self.items
+            .extend(items.into_iter().map(|item| item.to_chinese(variant)));
+        self
+    }
+
+    /// Creates the [ChineseVec].
+    pub fn build(self) -> ChineseVec {
+        self.items.into()
+    }
+}
+
+impl ChineseVec {
+    /// Shortcut for [ChineseVecBuilder::new].
+    pub fn builder(variant: Variant) -> ChineseVecBuilder {
+        ChineseVecBuilder::new(variant)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{Chinese, ChineseFormat, Glyphs, Variant};
+use std::{error::Error, fmt::Display};
+
+/// Error for when a string cannot be parsed as a [Version].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(
+///     InvalidVersion("3.x".to_string()).to_string(),
+///     "Invalid version: 3.x"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidVersion(pub String);
+
+impl Display for InvalidVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid version: {}", self.0)
+    }
+}
+
+impl Error for InvalidVersion {}
+
+/// How a [Version] is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum VersionStyle {
+    /// Components separated by `点`/`點` and followed by `版` - as in `三点二点一版`.
+    #[default]
+    Spoken,
+
+    /// Components separated by `.` and preceded by `V` - as in `V三.二.一`.
+    Prefixed,
+}
+
+/// Software version - as in `三点二点一版` or `V三.二.一` - for about dialogs and changelogs.
+///
+/// It can be obtained from a pair or a triple of components, as well as
+/// from a semver-like string - optionally starting with `v` or `V` - having
+/// 2 or 3 components; pre-release and build metadata are not supported.
+///
+/// Each component is read as an integer - so `2` is always `二`.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let version = Version::from((3, 2, 1));
+/// assert_eq!(version.to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三点二点一版".into(),
+///     omissible: false
+/// });
+/// assert_eq!(version.to_chinese(Variant::Traditional), "三點二點一版");
+///
+/// let prefixed = version.with_style(VersionStyle::Prefixed);
+/// assert_eq!(prefixed.to_chinese(Variant::Simplified), "V三.二.一");
+///
+/// let parsed = Version::try_from("v10.0.12")?;
+/// assert_eq!(parsed, Version::from((10, 0, 12)));
+/// assert_eq!(parsed.to_chinese(Variant::Simplified), "十点零点十二版");
+///
+/// let short = Version::try_from("2.5")?;
+/// assert_eq!(short.minor(), 5);
+/// assert_eq!(short.patch(), None);
+/// assert_eq!(short.to_chinese(Variant::Simplified), "二点五版");
+///
+/// assert_eq!(Version::try_from("1.2.3-beta"), Err(InvalidVersion("1.2.3-beta".to_string())));
+/// assert_eq!(Version::try_from("7"), Err(InvalidVersion("7".to_string())));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    major: u32,
+    minor: u32,
+    patch: Option<u32>,
+    style: VersionStyle,
+}
+
+impl Version {
+    const BAN: &'static str = "版";
+
+    const V: &'static str = "V";
+
+    /// Sets how the version is rendered.
+    pub fn with_style(self, style: VersionStyle) -> Self {
+        Self { style, ..self }
+    }
+
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    pub fn patch(&self) -> Option<u32> {
+        self.patch
+    }
+}
+
+impl From<(u32, u32)> for Version {
+    fn from((major, minor): (u32, u32)) -> Self {
+        Self {
+            major,
+            minor,
+            patch: None,
+            style: VersionStyle::default(),
+        }
+    }
+}
+
+impl From<(u32, u32, u32)> for Version {
+    fn from((major, minor, patch): (u32, u32, u32)) -> Self {
+        Self {
+            patch: Some(patch),
+            ..Self::from((major, minor))
+        }
+    }
+}
+
+/// [Version] can be parsed from a [str], as described in its documentation.
+impl TryFrom<&str> for Version {
+    type Error = InvalidVersion;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let invalid = || InvalidVersion(value.to_string());
+
+        let components = value
+            .strip_prefix(['v', 'V'])
+            .unwrap_or(value)
+            .split('.')
+            .map(|component| component.parse::<u32>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match components[..] {
+            [major, minor] => Ok(Self::from((major, minor))),
+            [major, minor, patch] => Ok(Self::from((major, minor, patch))),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl ChineseFormat for Version {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let components: Vec<String> = [Some(self.major), Some(self.minor), self.patch]
+            .into_iter()
+            .flatten()
+            .map(|component| component.to_chinese(variant).logograms.into_owned())
+            .collect();
+
+        let logograms = match self.style {
+            VersionStyle::Spoken => format!(
+                "{}{}",
+                components.join(Glyphs::DIAN.pick(variant)),
+                Self::BAN
+            ),
+
+            VersionStyle::Prefixed => format!("{}{}", Self::V, components.join(".")),
+        };
+
+        Chinese {
+            logograms: logograms.into(),
+            omissible: false,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Weight measures.
 //!
-//! [HalfKilogram] seems especially used in Chinese, although [Kilogram] is available as well.
+//! [HalfKilogram] seems especially used in Chinese, although [Kilogram] is available as well;
+//! the market units `斤` and `两` can also be combined via [MarketWeight].
 //!
 //! ```
 //! use chinese_format::{*, weight::*};
@@ -10,7 +11,7 @@
 //! assert_eq!(
 //!     two_kg.to_chinese(Variant::Simplified),
 //!     Chinese {
-//!         logograms: "两公斤".to_string(),
+//!         logograms: "两公斤".into(),
 //!         omissible: false
 //!     }
 //! );
@@ -18,7 +19,7 @@
 //! assert_eq!(
 //!     two_kg.to_chinese(Variant::Traditional),
 //!     Chinese {
-//!         logograms: "兩公斤".to_string(),
+//!         logograms: "兩公斤".into(),
 //!         omissible: false
 //!     }
 //! );
@@ -29,7 +30,7 @@
 //! assert_eq!(
 //!     zero_hkg.to_chinese(Variant::Simplified),
 //!     Chinese {
-//!         logograms: "零斤".to_string(),
+//!         logograms: "零斤".into(),
 //!         omissible: true
 //!     }
 //! );
@@ -37,13 +38,132 @@
 //! assert_eq!(
 //!     zero_hkg.to_chinese(Variant::Traditional),
 //!     Chinese {
-//!         logograms: "零斤".to_string(),
+//!         logograms: "零斤".into(),
 //!         omissible: true
 //!     }
 //! );
+//!
+//! assert_eq!(Ton::new(3).to_chinese(Variant::Traditional), "三噸");
+//! assert_eq!(Milligram::new(1500).humanize().to_chinese(Variant::Simplified), "一点五克");
+//! assert_eq!(Kilogram::new(2500).humanize().to_chinese(Variant::Simplified), "二点五吨");
 //! ```
-use crate::define_count_measure;
+use crate::{
+    define_count_measure, impl_scaled_measure, plain_two, Chinese, ChineseFormat, CountBase,
+    Dimension, MultiUnitMeasure, MultiUnitStyle, Variant,
+};
 
-define_count_measure!(pub, HalfKilogram, "斤");
+/// The [Dimension] of the weight measures, whose base unit is [Milligram].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mass;
+
+impl Dimension for Mass {
+    const HUMANIZED_UNITS: &'static [(CountBase, (&'static str, &'static str))] = &[
+        (1_000_000_000, ("吨", "噸")),
+        (1_000_000, ("公斤", "公斤")),
+        (1_000, ("克", "克")),
+        (1, ("毫克", "毫克")),
+    ];
+}
+
+define_count_measure!(pub, Ton, ("吨", "噸"));
 
 define_count_measure!(pub, Kilogram, "公斤");
+
+define_count_measure!(pub, HalfKilogram, "斤");
+
+define_count_measure!(pub, Liang, ("两", "兩"), value_transform = plain_two);
+
+define_count_measure!(pub, Gram, "克");
+
+define_count_measure!(pub, Milligram, "毫克");
+
+impl_scaled_measure!(Mass, Ton, 1_000_000_000);
+
+impl_scaled_measure!(Mass, Kilogram, 1_000_000);
+
+impl_scaled_measure!(Mass, HalfKilogram, 500_000);
+
+impl_scaled_measure!(Mass, Liang, 50_000);
+
+impl_scaled_measure!(Mass, Gram, 1_000);
+
+impl_scaled_measure!(Mass, Milligram, 1);
+
+/// Weight in the market units `斤` and `两` - expressed as a total of [Liang],
+/// 10 of which make up a `斤`.
+///
+/// Exactly half a `斤` is read as `半`, as usual in speech;
+/// any other remainder is read via `两`, as in a [MultiUnitMeasure].
+///
+/// ```
+/// use chinese_format::{*, weight::*};
+///
+/// assert_eq!(MarketWeight(35).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "三斤半".into(),
+///     omissible: false
+/// });
+/// assert_eq!(MarketWeight(5).to_chinese(Variant::Simplified), "半斤");
+///
+/// assert_eq!(MarketWeight(32).to_chinese(Variant::Simplified), "三斤二两");
+/// assert_eq!(MarketWeight(32).to_chinese(Variant::Traditional), "三斤二兩");
+/// assert_eq!(MarketWeight(20).to_chinese(Variant::Simplified), "两斤");
+/// assert_eq!(MarketWeight(2).to_chinese(Variant::Simplified), "二两");
+///
+/// assert_eq!(MarketWeight(0).to_chinese(Variant::Simplified), Chinese {
+///     logograms: "零两".into(),
+///     omissible: true
+/// });
+/// ```
+///
+/// It can also be obtained from any other weight measure, when exactly convertible:
+///
+/// ```
+/// use chinese_format::{*, weight::*};
+///
+/// let weight: MarketWeight = Gram::new(1750).convert::<Liang>().unwrap().into();
+/// assert_eq!(weight.to_chinese(Variant::Simplified), "三斤半");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MarketWeight(pub CountBase);
+
+impl From<Liang> for MarketWeight {
+    fn from(source: Liang) -> Self {
+        Self(source.0 .0)
+    }
+}
+
+impl From<MarketWeight> for CountBase {
+    fn from(source: MarketWeight) -> Self {
+        source.0
+    }
+}
+
+const JIN: &str = "斤";
+
+const BAN: &str = "半";
+
+impl ChineseFormat for MarketWeight {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let jin = self.0 / 10;
+
+        if self.0 % 10 == 5 {
+            let logograms = if jin == 0 {
+                format!("{}{}", BAN, JIN)
+            } else {
+                format!("{}{}", HalfKilogram::new(jin).to_chinese(variant), BAN)
+            };
+
+            return Chinese {
+                logograms: logograms.into(),
+                omissible: false,
+            };
+        }
+
+        MultiUnitMeasure {
+            value: self.0,
+            units: MultiUnitMeasure::JIN_LIANG,
+            style: MultiUnitStyle::default(),
+        }
+        .to_chinese(variant)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::Chinese;
+
+/// Returns the number of terminal cells occupied by the given character:
+/// `2` for wide characters - such as CJK logograms and full-width forms -
+/// `0` for control characters and `1` otherwise.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(char_display_width('a'), 1);
+/// assert_eq!(char_display_width('中'), 2);
+/// assert_eq!(char_display_width('，'), 2);
+/// assert_eq!(char_display_width('９'), 2);
+/// assert_eq!(char_display_width('\n'), 0);
+/// ```
+pub fn char_display_width(character: char) -> usize {
+    match character as u32 {
+        0x00..=0x1F | 0x7F..=0x9F => 0,
+
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3040..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD => 2,
+
+        _ => 1,
+    }
+}
+
+/// Returns the number of terminal cells occupied by the given text,
+/// according to [char_display_width].
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(display_width(""), 0);
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("三点五个G"), 9);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_display_width).sum()
+}
+
+/// Where text is placed when padded via [pad_to_display_width].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+/// Pads the given text with spaces until it reaches the given [display_width] -
+/// leaving it unchanged if it is already wide enough.
+///
+/// With [Alignment::Center], any odd space goes to the right.
+///
+/// ```
+/// use chinese_format::*;
+///
+/// assert_eq!(pad_to_display_width("两米", 6, Alignment::Left), "两米  ");
+/// assert_eq!(pad_to_display_width("两米", 6, Alignment::Right), "  两米");
+/// assert_eq!(pad_to_display_width("两米", 7, Alignment::Center), " 两米  ");
+/// assert_eq!(pad_to_display_width("两米", 3, Alignment::Left), "两米");
+/// ```
+pub fn pad_to_display_width(text: &str, width: usize, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(display_width(text));
+
+    let (left, right) = match alignment {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+impl Chinese {
+    /// The number of terminal cells occupied by the logograms - see [display_width].
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// assert_eq!(Count(2).to_chinese(Variant::Simplified).display_width(), 2);
+    /// assert_eq!(Count(12).to_chinese(Variant::Simplified).display_width(), 4);
+    /// ```
+    pub fn display_width(&self) -> usize {
+        display_width(&self.logograms)
+    }
+
+    /// The logograms, padded via [pad_to_display_width].
+    ///
+    /// ```
+    /// use chinese_format::*;
+    ///
+    /// let rows = [Count(2), Count(300)].map(|count| {
+    ///     count
+    ///         .to_chinese(Variant::Simplified)
+    ///         .pad_to_display_width(6, Alignment::Right)
+    /// });
+    /// assert_eq!(rows, ["    两", "  三百"]);
+    /// ```
+    pub fn pad_to_display_width(&self, width: usize, alignment: Alignment) -> String {
+        pad_to_display_width(&self.logograms, width, alignment)
+    }
+}
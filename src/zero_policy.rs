@@ -0,0 +1,23 @@
+/// How the [omissible](crate::Chinese::omissible) items of a [ChineseVec](crate::ChineseVec)
+/// are handled by [apply_zero_policy](crate::ChineseVec::apply_zero_policy).
+///
+/// It generalizes the logic that composites - such as currency amounts -
+/// implement via [placeholders](crate::define_string_placeholder).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ZeroPolicy {
+    /// All the items are kept as they are.
+    #[default]
+    Keep,
+
+    /// The omissible items are removed.
+    Omit,
+
+    /// Standard `零` insertion: the omissible items at both ends are removed,
+    /// whereas each run of omissible items between non-omissible ones
+    /// is replaced by a single `零` - as in `一千零八`.
+    Gap,
+}
+
+impl ZeroPolicy {
+    pub(crate) const LING: &'static str = "零";
+}